@@ -6,7 +6,7 @@ pub use dir_entry::*;
 pub use file::*;
 
 use crate::AsyncStdGlobalRuntime;
-use arta::fs::FSRuntime;
+use arta::fs::{FSRuntime, Metadata, Permissions};
 use async_std::stream::StreamExt;
 use futures::{prelude::Future, TryFutureExt};
 use std::path::PathBuf;
@@ -138,9 +138,9 @@ impl FSRuntime for AsyncStdGlobalRuntime {
     fn metadata(
         &self,
         path: impl AsRef<std::path::Path> + Send,
-    ) -> impl Future<Output = std::io::Result<std::fs::Metadata>> + Send {
+    ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
         let path: async_std::path::PathBuf = path.as_ref().to_owned().into();
-        async_std::fs::metadata(path)
+        async_std::fs::metadata(path).map_ok(Into::into)
     }
 
     fn remove_file(
@@ -165,17 +165,21 @@ impl FSRuntime for AsyncStdGlobalRuntime {
     fn set_permissions(
         &self,
         path: impl AsRef<std::path::Path> + Send,
-        permissions: std::fs::Permissions,
+        permissions: Permissions,
     ) -> impl Future<Output = std::io::Result<()>> + Send {
         let path: async_std::path::PathBuf = path.as_ref().to_owned().into();
-        async_std::fs::set_permissions(path, permissions)
+        async move {
+            let current = async_std::fs::metadata(&path).map_ok(|metadata| metadata.permissions());
+            let permissions = permissions.into_os_permissions(current).await?;
+            async_std::fs::set_permissions(path, permissions).await
+        }
     }
 
     fn symlink_metadata(
         &self,
         path: impl AsRef<std::path::Path> + Send,
-    ) -> impl Future<Output = std::io::Result<std::fs::Metadata>> + Send {
+    ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
         let path: async_std::path::PathBuf = path.as_ref().to_owned().into();
-        async_std::fs::symlink_metadata(path)
+        async_std::fs::symlink_metadata(path).map_ok(Into::into)
     }
 }
@@ -1,10 +1,6 @@
-use arta::fs::RuntimeDirEntry;
-use futures::prelude::Future;
-use std::{
-    ffi::OsString,
-    fs::{FileType, Metadata},
-    path::PathBuf,
-};
+use arta::fs::{FileType, Metadata, RuntimeDirEntry};
+use futures::{prelude::Future, TryFutureExt as _};
+use std::{ffi::OsString, path::PathBuf};
 
 /// Async-std specific [`RuntimeDirEntry`] implementation.
 pub struct AsyncStdDirEntry {
@@ -22,11 +18,11 @@ impl RuntimeDirEntry for AsyncStdDirEntry {
     }
 
     fn file_type(&self) -> impl Future<Output = std::io::Result<FileType>> + Send {
-        self.inner.file_type()
+        self.inner.file_type().map_ok(Into::into)
     }
 
     fn metadata(&self) -> impl Future<Output = std::io::Result<Metadata>> + Send {
-        self.inner.metadata()
+        self.inner.metadata().map_ok(Into::into)
     }
 
     fn path(&self) -> PathBuf {
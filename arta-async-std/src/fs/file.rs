@@ -1,5 +1,5 @@
 use crate::AsyncStdGlobalRuntime;
-use arta::fs::RuntimeFile;
+use arta::fs::{Metadata, OpenOptions, Permissions, RuntimeFile};
 use cfg_if::cfg_if;
 use futures::{prelude::Future, AsyncRead, AsyncSeek, AsyncWrite, TryFutureExt};
 use std::pin::Pin;
@@ -116,7 +116,7 @@ impl RuntimeFile for AsyncStdFile {
 
     fn open(
         _runtime: &Self::Runtime,
-        open_options: &std::fs::OpenOptions,
+        open_options: &OpenOptions,
         path: impl AsRef<std::path::Path>,
     ) -> impl Future<Output = std::io::Result<Self>> + Send
     where
@@ -125,7 +125,7 @@ impl RuntimeFile for AsyncStdFile {
         // async_std::fs::OpenOptions has no implementation to convert
         // from std primitves so we need to transmute it.
         let open_options: async_std::fs::OpenOptions =
-            unsafe { std::mem::transmute(open_options.clone()) };
+            unsafe { std::mem::transmute(std::fs::OpenOptions::from(open_options)) };
         let path: async_std::path::PathBuf = path.as_ref().to_owned().into();
 
         open_options.open(path).map_ok(|file| Self { inner: file })
@@ -135,8 +135,8 @@ impl RuntimeFile for AsyncStdFile {
         self.inner.set_len(size)
     }
 
-    fn metadata(&self) -> impl Future<Output = std::io::Result<std::fs::Metadata>> + Send {
-        self.inner.metadata()
+    fn metadata(&self) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        self.inner.metadata().map_ok(Into::into)
     }
 
     fn sync_all(&self) -> impl Future<Output = std::io::Result<()>> + Send {
@@ -147,10 +147,12 @@ impl RuntimeFile for AsyncStdFile {
         self.inner.sync_data()
     }
 
-    fn set_permissions(
-        &self,
-        permissions: std::fs::Permissions,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        self.inner.set_permissions(permissions)
+    async fn set_permissions(&self, permissions: Permissions) -> std::io::Result<()> {
+        let current = self
+            .inner
+            .metadata()
+            .map_ok(|metadata| metadata.permissions());
+        let permissions = permissions.into_os_permissions(current).await?;
+        self.inner.set_permissions(permissions).await
     }
 }
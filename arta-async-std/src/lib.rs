@@ -5,8 +5,8 @@
 //! Add a following dependencies to your `Cargo.toml`:
 //! ```toml
 //! [dependencies]
-//! arta-async-std = "0.1.0"
-//! arta = "0.1.1"
+//! arta-async-std = "0.3.0"
+//! arta = "0.3.0"
 //! async-std = { version = "1.12.0", features = ["attributes"] }
 //! ```
 //! ## Usage
@@ -79,7 +79,8 @@ pub mod fs;
 pub mod net;
 pub mod process;
 pub mod task;
-mod time;
+pub mod time;
 
 /// Struct representing tokio global runtime usage.
+#[derive(Debug, Clone, Copy)]
 pub struct AsyncStdGlobalRuntime;
@@ -1,17 +1,53 @@
-//! Async-std specific TCP/UDP communication implementation.
+//! Async-std specific TCP/UDP and Unix domain socket communication implementation.
 mod tcp_listener;
+mod tcp_socket;
 mod tcp_stream;
 mod udp_socket;
+#[cfg(unix)]
+mod unix_datagram;
+#[cfg(unix)]
+mod unix_listener;
+#[cfg(unix)]
+mod unix_stream;
 
 use arta::net::NetRuntime;
 pub use tcp_listener::*;
+pub use tcp_socket::*;
 pub use tcp_stream::*;
 pub use udp_socket::*;
+#[cfg(unix)]
+pub use unix_datagram::*;
+#[cfg(unix)]
+pub use unix_listener::*;
+#[cfg(unix)]
+pub use unix_stream::*;
 
 use crate::AsyncStdGlobalRuntime;
+use std::{future::Future, net::SocketAddr};
 
 impl NetRuntime for AsyncStdGlobalRuntime {
     type TcpListener = AsyncStdTcpListener;
     type TcpStream = AsyncStdTcpStream;
+    type TcpSocket = AsyncStdTcpSocket;
     type UdpSocket = AsyncStdUdpSocket;
+
+    fn resolve(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> impl Future<Output = std::io::Result<Vec<SocketAddr>>> + Send {
+        // Async-std calls `getaddrinfo` on its blocking thread pool.
+        let host = host.to_owned();
+        async move {
+            let addrs = async_std::net::ToSocketAddrs::to_socket_addrs(&(host.as_str(), port));
+            Ok(addrs.await?.collect())
+        }
+    }
+}
+
+#[cfg(unix)]
+impl arta::net::UnixNetRuntime for AsyncStdGlobalRuntime {
+    type UnixListener = AsyncStdUnixListener;
+    type UnixStream = AsyncStdUnixStream;
+    type UnixDatagram = AsyncStdUnixDatagram;
 }
@@ -54,7 +54,7 @@ cfg_if! {
 
 /// Async-std specific [`RuntimeTcpListener`] implementation.
 pub struct AsyncStdTcpListener {
-    inner: async_std::net::TcpListener,
+    pub(super) inner: async_std::net::TcpListener,
 }
 
 impl RuntimeTcpListener for AsyncStdTcpListener {
@@ -0,0 +1,160 @@
+use super::{AsyncStdTcpListener, AsyncStdTcpStream};
+use crate::AsyncStdGlobalRuntime;
+use arta::net::{NetRuntime, RuntimeTcpSocket};
+use async_io::Async;
+use cfg_if::cfg_if;
+use socket2::{Domain, Protocol, Socket, Type};
+use std::net::SocketAddr;
+
+cfg_if! {
+    if #[cfg(windows)] {
+        impl std::os::windows::io::AsRawSocket for AsyncStdTcpSocket {
+            fn as_raw_socket(&self) -> std::os::windows::io::RawSocket {
+                self.inner.as_raw_socket()
+            }
+        }
+
+        impl std::os::windows::io::AsSocket for AsyncStdTcpSocket {
+            fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
+                self.inner.as_socket()
+            }
+        }
+
+        impl From<std::os::windows::io::OwnedSocket> for AsyncStdTcpSocket {
+            fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+                Self { inner: Socket::from(socket) }
+            }
+        }
+    } else if #[cfg(any(unix, target_os = "wasi"))] {
+        impl std::os::fd::AsRawFd for AsyncStdTcpSocket {
+            fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+                self.inner.as_raw_fd()
+            }
+        }
+
+        impl std::os::fd::AsFd for AsyncStdTcpSocket {
+            fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+                self.inner.as_fd()
+            }
+        }
+
+        impl From<std::os::fd::OwnedFd> for AsyncStdTcpSocket {
+            fn from(fd: std::os::fd::OwnedFd) -> Self {
+                Self { inner: Socket::from(fd) }
+            }
+        }
+    }
+}
+
+/// Async-std specific [`RuntimeTcpSocket`] implementation.
+pub struct AsyncStdTcpSocket {
+    inner: Socket,
+}
+
+impl RuntimeTcpSocket for AsyncStdTcpSocket {
+    type Runtime = AsyncStdGlobalRuntime;
+
+    fn new_v4(_runtime: &Self::Runtime) -> std::io::Result<Self> {
+        Socket::new(Domain::IPV4, Type::STREAM, Some(Protocol::TCP)).map(|inner| Self { inner })
+    }
+
+    fn new_v6(_runtime: &Self::Runtime) -> std::io::Result<Self> {
+        Socket::new(Domain::IPV6, Type::STREAM, Some(Protocol::TCP)).map(|inner| Self { inner })
+    }
+
+    fn reuseaddr(&self) -> std::io::Result<bool> {
+        self.inner.reuse_address()
+    }
+
+    fn set_reuseaddr(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.inner.set_reuse_address(is_enabled)
+    }
+
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    fn reuseport(&self) -> std::io::Result<bool> {
+        self.inner.reuse_port()
+    }
+
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    fn set_reuseport(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.inner.set_reuse_port(is_enabled)
+    }
+
+    fn send_buffer_size(&self) -> std::io::Result<u32> {
+        self.inner
+            .send_buffer_size()
+            .map(|size| u32::try_from(size).unwrap_or(u32::MAX))
+    }
+
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        self.inner.set_send_buffer_size(size as usize)
+    }
+
+    fn recv_buffer_size(&self) -> std::io::Result<u32> {
+        self.inner
+            .recv_buffer_size()
+            .map(|size| u32::try_from(size).unwrap_or(u32::MAX))
+    }
+
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        self.inner.set_recv_buffer_size(size as usize)
+    }
+
+    fn only_v6(&self) -> std::io::Result<bool> {
+        self.inner.only_v6()
+    }
+
+    fn set_only_v6(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.inner.set_only_v6(is_enabled)
+    }
+
+    fn bind(&self, addr: SocketAddr) -> std::io::Result<()> {
+        self.inner.bind(&addr.into())
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()?.as_socket().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "socket is not bound to an IP address",
+            )
+        })
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        self.inner.take_error()
+    }
+
+    async fn connect(
+        self,
+        addr: SocketAddr,
+    ) -> std::io::Result<<Self::Runtime as NetRuntime>::TcpStream> {
+        self.inner.set_nonblocking(true)?;
+        match self.inner.connect(&addr.into()) {
+            Ok(()) => {}
+            #[cfg(unix)]
+            Err(err) if err.raw_os_error() == Some(libc::EINPROGRESS) => {}
+            Err(err) if err.kind() == std::io::ErrorKind::WouldBlock => {}
+            Err(err) => return Err(err),
+        }
+
+        // The connection is established or has failed once the socket becomes writable.
+        let stream = Async::new(std::net::TcpStream::from(self.inner))?;
+        stream.writable().await?;
+        if let Some(err) = stream.get_ref().take_error()? {
+            return Err(err);
+        }
+
+        Ok(AsyncStdTcpStream {
+            inner: stream.into_inner()?.into(),
+        })
+    }
+
+    fn listen(self, backlog: u32) -> std::io::Result<<Self::Runtime as NetRuntime>::TcpListener> {
+        self.inner
+            .listen(i32::try_from(backlog).unwrap_or(i32::MAX))?;
+        Ok(AsyncStdTcpListener {
+            inner: std::net::TcpListener::from(self.inner).into(),
+        })
+    }
+}
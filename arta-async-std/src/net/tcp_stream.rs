@@ -1,11 +1,12 @@
 use crate::AsyncStdGlobalRuntime;
-use arta::net::RuntimeTcpStream;
+use arta::net::{ReuniteError, RuntimeTcpStream, TcpKeepalive, TcpStreamOptions};
 use cfg_if::cfg_if;
-use futures::{prelude::Future, AsyncRead, AsyncWrite, TryFutureExt};
+use futures::{prelude::Future, ready, AsyncRead, AsyncWrite, TryFutureExt as _};
 use socket2::SockRef;
 use std::{
-    net::SocketAddr,
+    net::{Shutdown, SocketAddr},
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
     time::Duration,
 };
@@ -63,6 +64,8 @@ pub struct AsyncStdTcpStream {
 
 impl RuntimeTcpStream for AsyncStdTcpStream {
     type Runtime = AsyncStdGlobalRuntime;
+    type OwnedReadHalf = AsyncStdTcpReadHalf;
+    type OwnedWriteHalf = AsyncStdTcpWriteHalf;
 
     fn connect(
         runtime: &Self::Runtime,
@@ -76,6 +79,36 @@ impl RuntimeTcpStream for AsyncStdTcpStream {
         })
     }
 
+    fn into_split(self) -> (Self::OwnedReadHalf, Self::OwnedWriteHalf) {
+        let inner = Arc::new(self.inner);
+        (
+            AsyncStdTcpReadHalf {
+                inner: Arc::clone(&inner),
+            },
+            AsyncStdTcpWriteHalf { inner },
+        )
+    }
+
+    fn reunite(
+        read: Self::OwnedReadHalf,
+        write: Self::OwnedWriteHalf,
+    ) -> Result<Self, ReuniteError<Self::OwnedReadHalf, Self::OwnedWriteHalf>> {
+        if !Arc::ptr_eq(&read.inner, &write.inner) {
+            return Err(ReuniteError(read, write));
+        }
+        drop(write);
+        Arc::try_unwrap(read.inner)
+            .map(|inner| Self { inner })
+            .map_err(|inner| {
+                ReuniteError(
+                    AsyncStdTcpReadHalf {
+                        inner: Arc::clone(&inner),
+                    },
+                    AsyncStdTcpWriteHalf { inner },
+                )
+            })
+    }
+
     fn local_addr(&self) -> std::io::Result<SocketAddr> {
         self.inner.local_addr()
     }
@@ -119,6 +152,77 @@ impl RuntimeTcpStream for AsyncStdTcpStream {
     }
 }
 
+impl TcpStreamOptions for AsyncStdTcpStream {
+    fn keepalive(&self) -> std::io::Result<bool> {
+        SockRef::from(self).keepalive()
+    }
+
+    fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> std::io::Result<()> {
+        match keepalive {
+            Some(keepalive) => {
+                SockRef::from(self).set_tcp_keepalive(&socket2::TcpKeepalive::try_from(keepalive)?)
+            }
+            None => SockRef::from(self).set_keepalive(false),
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn user_timeout(&self) -> std::io::Result<Option<Duration>> {
+        SockRef::from(self).tcp_user_timeout()
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn set_user_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        SockRef::from(self).set_tcp_user_timeout(timeout)
+    }
+
+    fn send_buffer_size(&self) -> std::io::Result<u32> {
+        SockRef::from(self)
+            .send_buffer_size()
+            .map(|size| u32::try_from(size).unwrap_or(u32::MAX))
+    }
+
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        SockRef::from(self).set_send_buffer_size(size as usize)
+    }
+
+    fn recv_buffer_size(&self) -> std::io::Result<u32> {
+        SockRef::from(self)
+            .recv_buffer_size()
+            .map(|size| u32::try_from(size).unwrap_or(u32::MAX))
+    }
+
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        SockRef::from(self).set_recv_buffer_size(size as usize)
+    }
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn tos(&self) -> std::io::Result<u32> {
+        SockRef::from(self).tos()
+    }
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn set_tos(&self, tos: u32) -> std::io::Result<()> {
+        SockRef::from(self).set_tos(tos)
+    }
+
+    fn shutdown(&self, how: Shutdown) -> std::io::Result<()> {
+        self.inner.shutdown(how)
+    }
+}
+
 impl AsyncRead for AsyncStdTcpStream {
     fn poll_read(
         mut self: Pin<&mut Self>,
@@ -159,6 +263,72 @@ impl AsyncWrite for AsyncStdTcpStream {
     }
 
     fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
-        async_std::io::Write::poll_close(Pin::new(&mut self.inner), cx)
+        // Async-std only flushes on close, shutdown is required for a peer to observe the end
+        // of data.
+        ready!(async_std::io::Write::poll_flush(
+            Pin::new(&mut self.inner),
+            cx
+        ))?;
+        Poll::Ready(self.inner.shutdown(Shutdown::Write))
+    }
+}
+
+/// Async-std specific owned read half of an [`AsyncStdTcpStream`].
+#[derive(Debug)]
+pub struct AsyncStdTcpReadHalf {
+    inner: Arc<async_std::net::TcpStream>,
+}
+
+impl AsyncRead for AsyncStdTcpReadHalf {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        async_std::io::Read::poll_read(Pin::new(&mut &*self.inner), cx, buf)
+    }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        async_std::io::Read::poll_read_vectored(Pin::new(&mut &*self.inner), cx, bufs)
+    }
+}
+
+/// Async-std specific owned write half of an [`AsyncStdTcpStream`].
+#[derive(Debug)]
+pub struct AsyncStdTcpWriteHalf {
+    inner: Arc<async_std::net::TcpStream>,
+}
+
+impl AsyncWrite for AsyncStdTcpWriteHalf {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        async_std::io::Write::poll_write(Pin::new(&mut &*self.inner), cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        async_std::io::Write::poll_write_vectored(Pin::new(&mut &*self.inner), cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        async_std::io::Write::poll_flush(Pin::new(&mut &*self.inner), cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        ready!(async_std::io::Write::poll_flush(
+            Pin::new(&mut &*self.inner),
+            cx
+        ))?;
+        Poll::Ready(self.inner.shutdown(Shutdown::Write))
     }
 }
@@ -0,0 +1,65 @@
+use super::AsyncStdUnixStream;
+use crate::AsyncStdGlobalRuntime;
+use arta::net::{RuntimeUnixListener, UnixNetRuntime};
+use futures::{prelude::Future, TryFutureExt as _};
+use socket2::SockRef;
+use std::os::unix::net::SocketAddr;
+
+impl std::os::fd::AsRawFd for AsyncStdUnixListener {
+    fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+impl std::os::fd::AsFd for AsyncStdUnixListener {
+    fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+        let raw_fd = std::os::fd::AsRawFd::as_raw_fd(self);
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(raw_fd) }
+    }
+}
+
+impl From<std::os::fd::OwnedFd> for AsyncStdUnixListener {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self {
+            inner: async_std::os::unix::net::UnixListener::from(
+                std::os::unix::net::UnixListener::from(fd),
+            ),
+        }
+    }
+}
+
+/// Async-std specific [`RuntimeUnixListener`] implementation.
+pub struct AsyncStdUnixListener {
+    inner: async_std::os::unix::net::UnixListener,
+}
+
+impl RuntimeUnixListener for AsyncStdUnixListener {
+    type Runtime = AsyncStdGlobalRuntime;
+
+    fn accept(
+        &self,
+    ) -> impl Future<
+        Output = std::io::Result<(<Self::Runtime as UnixNetRuntime>::UnixStream, SocketAddr)>,
+    > + Send {
+        self.inner
+            .accept()
+            .map_ok(|(stream, addr)| (AsyncStdUnixStream { inner: stream }, addr))
+    }
+
+    fn bind_addr(_runtime: &Self::Runtime, addr: &SocketAddr) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            inner: std::os::unix::net::UnixListener::bind_addr(addr)?.into(),
+        })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        SockRef::from(self).take_error()
+    }
+}
@@ -0,0 +1,144 @@
+use crate::AsyncStdGlobalRuntime;
+use arta::net::RuntimeUnixStream;
+use futures::{prelude::Future, ready, AsyncRead, AsyncWrite, TryFutureExt as _};
+use socket2::SockRef;
+use std::{
+    net::Shutdown,
+    os::unix::net::SocketAddr,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+impl std::os::fd::AsRawFd for AsyncStdUnixStream {
+    fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+impl std::os::fd::AsFd for AsyncStdUnixStream {
+    fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+        let raw_fd = std::os::fd::AsRawFd::as_raw_fd(self);
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(raw_fd) }
+    }
+}
+
+impl From<std::os::fd::OwnedFd> for AsyncStdUnixStream {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self {
+            inner: async_std::os::unix::net::UnixStream::from(
+                std::os::unix::net::UnixStream::from(fd),
+            ),
+        }
+    }
+}
+
+/// Async-std specific [`RuntimeUnixStream`] implementation.
+pub struct AsyncStdUnixStream {
+    pub(super) inner: async_std::os::unix::net::UnixStream,
+}
+
+impl RuntimeUnixStream for AsyncStdUnixStream {
+    type Runtime = AsyncStdGlobalRuntime;
+
+    fn connect(
+        _runtime: &Self::Runtime,
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        async_std::os::unix::net::UnixStream::connect(path.as_ref().to_owned())
+            .map_ok(|stream| Self { inner: stream })
+    }
+
+    fn connect_addr(
+        _runtime: &Self::Runtime,
+        addr: &SocketAddr,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        // Connecting blocks while the backlog of the listener is full.
+        let addr = addr.clone();
+        async move {
+            let stream = async_std::task::spawn_blocking(move || {
+                std::os::unix::net::UnixStream::connect_addr(&addr)
+            })
+            .await?;
+            Ok(Self {
+                inner: stream.into(),
+            })
+        }
+    }
+
+    fn pair(_runtime: &Self::Runtime) -> std::io::Result<(Self, Self)>
+    where
+        Self: Sized,
+    {
+        let (first, second) = async_std::os::unix::net::UnixStream::pair()?;
+        Ok((Self { inner: first }, Self { inner: second }))
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn peer_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.peer_addr()
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        SockRef::from(self).take_error()
+    }
+}
+
+impl AsyncRead for AsyncStdUnixStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        async_std::io::Read::poll_read(Pin::new(&mut self.inner), cx, buf)
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        async_std::io::Read::poll_read_vectored(Pin::new(&mut self.inner), cx, bufs)
+    }
+}
+
+impl AsyncWrite for AsyncStdUnixStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        async_std::io::Write::poll_write(Pin::new(&mut self.inner), cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        async_std::io::Write::poll_write_vectored(Pin::new(&mut self.inner), cx, bufs)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        async_std::io::Write::poll_flush(Pin::new(&mut self.inner), cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        // Async-std only flushes on close, shutdown is required for a peer to observe the end
+        // of data.
+        ready!(async_std::io::Write::poll_flush(
+            Pin::new(&mut self.inner),
+            cx
+        ))?;
+        Poll::Ready(self.inner.shutdown(Shutdown::Write))
+    }
+}
@@ -12,15 +12,18 @@ pub struct AsyncStdChild {
 impl RuntimeChild for AsyncStdChild {
     type Runtime = AsyncStdGlobalRuntime;
 
-    type Stdin<'a> = AsyncStdStdin<'a>
+    type Stdin<'a>
+        = AsyncStdStdin<'a>
     where
         Self: 'a;
 
-    type Stdout<'a> = AsyncStdStdout<'a>
+    type Stdout<'a>
+        = AsyncStdStdout<'a>
     where
         Self: 'a;
 
-    type Stderr<'a> = AsyncStdStderr<'a>
+    type Stderr<'a>
+        = AsyncStdStderr<'a>
     where
         Self: 'a;
 
@@ -1,6 +1,9 @@
 //! Async-std specific green thread management implementation.
 use crate::AsyncStdGlobalRuntime;
-use arta::task::{RuntimeJoinHandle, TaskRuntime};
+use arta::task::{
+    JoinError, LocalTaskRuntime, RuntimeJoinHandle, RuntimeLocalJoinHandle, TaskBuilder,
+    TaskRuntime,
+};
 use futures::{prelude::Future, FutureExt};
 use std::{
     panic::AssertUnwindSafe,
@@ -17,10 +20,10 @@ impl<T> Future for AsyncStdJoinHandle<T>
 where
     T: Send,
 {
-    type Output = std::thread::Result<T>;
+    type Output = Result<T, JoinError>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        self.inner.poll_unpin(cx)
+        self.inner.poll_unpin(cx).map_err(JoinError::panic)
     }
 }
 
@@ -28,13 +31,52 @@ impl<T> RuntimeJoinHandle<T> for AsyncStdJoinHandle<T>
 where
     T: Send + 'static,
 {
-    fn cancel(self) -> impl Future<Output = Option<std::thread::Result<T>>> + Send {
-        self.inner.cancel()
+    fn cancel(self) -> impl Future<Output = Result<T, JoinError>> + Send {
+        self.inner.cancel().map(|result| match result {
+            Some(result) => result.map_err(JoinError::panic),
+            None => Err(JoinError::cancelled()),
+        })
+    }
+}
+
+/// Async-std specific [`RuntimeLocalJoinHandle`] implementation.
+pub struct AsyncStdLocalJoinHandle<T> {
+    inner: async_std::task::JoinHandle<std::thread::Result<T>>,
+}
+
+impl<T> Future for AsyncStdLocalJoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx).map_err(JoinError::panic)
+    }
+}
+
+impl<T> RuntimeLocalJoinHandle<T> for AsyncStdLocalJoinHandle<T>
+where
+    T: 'static,
+{
+    fn cancel(self) -> impl Future<Output = Result<T, JoinError>> {
+        self.inner.cancel().map(|result| match result {
+            Some(result) => result.map_err(JoinError::panic),
+            None => Err(JoinError::cancelled()),
+        })
+    }
+}
+
+fn async_std_builder(builder: &TaskBuilder) -> async_std::task::Builder {
+    let async_std_builder = async_std::task::Builder::new();
+    match builder.get_name() {
+        Some(name) => async_std_builder.name(name.to_owned()),
+        None => async_std_builder,
     }
 }
 
 impl TaskRuntime for AsyncStdGlobalRuntime {
-    type JoinHandle<T> = AsyncStdJoinHandle<T> where T: Send + 'static;
+    type JoinHandle<T>
+        = AsyncStdJoinHandle<T>
+    where
+        T: Send + 'static;
 
     fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static) -> Self::JoinHandle<R>
     where
@@ -55,4 +97,73 @@ impl TaskRuntime for AsyncStdGlobalRuntime {
             }),
         }
     }
+
+    fn block_on<R>(&self, future: impl Future<Output = R>) -> R {
+        async_std::task::block_on(future)
+    }
+
+    fn yield_now(&self) -> impl Future<Output = ()> + Send {
+        async_std::task::yield_now()
+    }
+
+    fn spawn_with<R>(
+        &self,
+        builder: &TaskBuilder,
+        future: impl Future<Output = R> + Send + 'static,
+    ) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        AsyncStdJoinHandle {
+            inner: async_std_builder(builder)
+                .spawn(AssertUnwindSafe(future).catch_unwind())
+                .unwrap(),
+        }
+    }
+
+    /// Name is given to a task awaiting the blocking one. A task with the stack size set is
+    /// executed on a dedicated thread instead of the blocking pool, see
+    /// [`TaskBuilder::spawn_thread`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread.
+    fn spawn_blocking_with<R>(
+        &self,
+        builder: &TaskBuilder,
+        task: impl FnOnce() -> R + Send + 'static,
+    ) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        if builder.get_stack_size().is_some() {
+            return self.spawn_with(builder, builder.spawn_thread(task).unwrap());
+        }
+
+        AsyncStdJoinHandle {
+            inner: async_std_builder(builder)
+                .spawn(async_std::task::spawn_blocking(|| {
+                    std::panic::catch_unwind(AssertUnwindSafe(task))
+                }))
+                .unwrap(),
+        }
+    }
+}
+
+impl LocalTaskRuntime for AsyncStdGlobalRuntime {
+    type LocalJoinHandle<T>
+        = AsyncStdLocalJoinHandle<T>
+    where
+        T: 'static;
+
+    /// Tasks are spawned on the executor of the current thread that is driven by
+    /// [`async_std::task::block_on`].
+    fn spawn_local<R>(&self, future: impl Future<Output = R> + 'static) -> Self::LocalJoinHandle<R>
+    where
+        R: 'static,
+    {
+        AsyncStdLocalJoinHandle {
+            inner: async_std::task::spawn_local(AssertUnwindSafe(future).catch_unwind()),
+        }
+    }
 }
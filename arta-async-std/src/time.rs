@@ -1,10 +1,61 @@
+//! Async-std specific timer implementation.
 use crate::AsyncStdGlobalRuntime;
-use arta::time::TimeRuntime;
-use futures::prelude::Future;
-use std::time::Duration;
+use arta::time::{Elapsed, RuntimeSleep, TimeRuntime};
+use futures::{prelude::Future, FutureExt as _, TryFutureExt as _};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+/// Async-std specific [`RuntimeSleep`] implementation.
+#[derive(Debug)]
+pub struct AsyncStdSleep {
+    timer: async_io::Timer,
+    deadline: Instant,
+}
+
+impl Future for AsyncStdSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // A timer that has fired never completes again, so check the deadline first.
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        self.timer.poll_unpin(cx).map(|_| ())
+    }
+}
+
+impl RuntimeSleep for AsyncStdSleep {
+    fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    fn reset(&mut self, deadline: Instant) {
+        self.timer.set_at(deadline);
+        self.deadline = deadline;
+    }
+}
 
 impl TimeRuntime for AsyncStdGlobalRuntime {
-    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
-        async_std::task::sleep(duration)
+    type Sleep = AsyncStdSleep;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        AsyncStdSleep {
+            timer: async_io::Timer::at(deadline),
+            deadline,
+        }
+    }
+
+    fn timeout<F>(
+        &self,
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send
+    where
+        F: Future + Send,
+    {
+        async_std::future::timeout(duration, future).map_err(|_| Elapsed::new())
     }
 }
@@ -0,0 +1,7 @@
+use arta_async_std::AsyncStdGlobalRuntime;
+
+arta_conformance::conformance_tests! {
+    runtime: AsyncStdGlobalRuntime = AsyncStdGlobalRuntime,
+    block_on: |_runtime, future| async_std::task::block_on(future),
+    suites: [fs, local_task, net, process, task, time, unix_net],
+}
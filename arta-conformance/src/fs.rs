@@ -0,0 +1,531 @@
+//! Checks of [`FSRuntime`], [`RuntimeFile`] and [`RuntimeDirEntry`] implementations.
+//!
+//! Every check works inside its own directory created in [`std::env::temp_dir`] and removes it on
+//! success.
+
+use arta::fs::{FSRuntime, OpenOptions, RuntimeDirEntry, RuntimeFile as _};
+use futures::{AsyncReadExt as _, AsyncSeekExt as _, AsyncWriteExt as _, TryStreamExt as _};
+use std::{
+    io::{ErrorKind, SeekFrom},
+    path::{Path, PathBuf},
+    pin::pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static NEXT_DIR_ID: AtomicUsize = AtomicUsize::new(0);
+
+async fn test_dir(runtime: &impl FSRuntime, name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "arta-conformance-{}-{}-{name}",
+        std::process::id(),
+        NEXT_DIR_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    runtime.create_dir_all(&path).await.unwrap();
+    path
+}
+
+async fn open<R: FSRuntime>(
+    runtime: &R,
+    open_options: &OpenOptions,
+    path: impl AsRef<Path>,
+) -> std::io::Result<R::File> {
+    R::File::open(runtime, open_options, path).await
+}
+
+/// Checks that `write` creates a missing file.
+pub async fn write_creates_file(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "write_creates_file").await;
+    let path = dir.join("file");
+
+    runtime.write(&path, b"hello").await.unwrap();
+    assert_eq!(runtime.read(&path).await.unwrap(), b"hello");
+    assert_eq!(runtime.read_to_string(&path).await.unwrap(), "hello");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `write` replaces the whole contents of an existing file.
+pub async fn write_truncates_file(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "write_truncates_file").await;
+    let path = dir.join("file");
+
+    runtime.write(&path, b"hello world").await.unwrap();
+    runtime.write(&path, b"bye").await.unwrap();
+    assert_eq!(runtime.read_to_string(&path).await.unwrap(), "bye");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that reading a missing file fails with [`ErrorKind::NotFound`].
+pub async fn read_missing_file_fails(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "read_missing_file_fails").await;
+    let path = dir.join("missing");
+
+    assert_eq!(
+        runtime.read(&path).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+    assert_eq!(
+        runtime.metadata(&path).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+    assert_eq!(
+        runtime.symlink_metadata(&path).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that opening a file without read, write or append access fails with
+/// [`ErrorKind::InvalidInput`].
+pub async fn open_without_access_fails(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "open_without_access_fails").await;
+    let path = dir.join("file");
+    runtime.write(&path, b"").await.unwrap();
+
+    let result = open(runtime, &OpenOptions::new(), &path).await;
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(ErrorKind::InvalidInput)
+    );
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that opening an existing file with `create_new` fails with
+/// [`ErrorKind::AlreadyExists`].
+pub async fn open_create_new_fails_if_exists(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "open_create_new_fails_if_exists").await;
+    let path = dir.join("file");
+    let create_new = OpenOptions::new().write(true).create_new(true).clone();
+
+    drop(open(runtime, &create_new, &path).await.unwrap());
+    let result = open(runtime, &create_new, &path).await;
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(ErrorKind::AlreadyExists)
+    );
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that a file created with a read-only mode can be written through the created handle.
+#[cfg(any(unix, doc))]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+pub async fn open_create_read_only(runtime: &impl FSRuntime) {
+    use std::os::unix::fs::OpenOptionsExt as _;
+
+    let dir = test_dir(runtime, "open_create_read_only").await;
+    let path = dir.join("file");
+
+    let create_read_only = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o444)
+        .clone();
+    let mut file = pin!(open(runtime, &create_read_only, &path).await.unwrap());
+    file.write_all(b"hello").await.unwrap();
+    file.flush().await.unwrap();
+    assert_eq!(runtime.read(&path).await.unwrap(), b"hello");
+    assert!(runtime
+        .metadata(&path)
+        .await
+        .unwrap()
+        .permissions()
+        .readonly());
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that writes to a file opened in the append mode go to its end.
+pub async fn file_append(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "file_append").await;
+    let path = dir.join("file");
+    runtime.write(&path, b"hello").await.unwrap();
+
+    let mut file = pin!(open(runtime, OpenOptions::new().append(true), &path)
+        .await
+        .unwrap());
+    file.write_all(b" world").await.unwrap();
+    file.flush().await.unwrap();
+    assert_eq!(runtime.read_to_string(&path).await.unwrap(), "hello world");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks seeking relative to the start, the current position and the end of a file.
+pub async fn file_seek(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "file_seek").await;
+    let path = dir.join("file");
+    runtime.write(&path, b"0123456789").await.unwrap();
+
+    let mut file = pin!(open(runtime, OpenOptions::new().read(true), &path)
+        .await
+        .unwrap());
+    let mut buf = [0u8; 3];
+
+    assert_eq!(file.seek(SeekFrom::Start(2)).await.unwrap(), 2);
+    file.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"234");
+
+    assert_eq!(file.seek(SeekFrom::Current(1)).await.unwrap(), 6);
+    file.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"678");
+
+    assert_eq!(file.seek(SeekFrom::End(-2)).await.unwrap(), 8);
+    let mut rest = Vec::new();
+    file.read_to_end(&mut rest).await.unwrap();
+    assert_eq!(rest, b"89");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `set_len` truncates a file and extends it with zeroes.
+pub async fn file_set_len(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "file_set_len").await;
+    let path = dir.join("file");
+    runtime.write(&path, b"0123456789").await.unwrap();
+
+    let file = open(runtime, OpenOptions::new().write(true), &path)
+        .await
+        .unwrap();
+    file.set_len(4).await.unwrap();
+    assert_eq!(file.metadata().await.unwrap().len(), 4);
+    file.set_len(6).await.unwrap();
+    drop(file);
+    assert_eq!(runtime.read(&path).await.unwrap(), b"0123\0\0");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks metadata of a file and a directory.
+pub async fn file_metadata(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "file_metadata").await;
+    let path = dir.join("file");
+    runtime.write(&path, b"hello").await.unwrap();
+
+    let file = open(runtime, OpenOptions::new().read(true), &path)
+        .await
+        .unwrap();
+    let metadata = file.metadata().await.unwrap();
+    assert!(metadata.is_file());
+    assert!(!metadata.is_dir());
+    assert_eq!(metadata.len(), 5);
+    metadata.modified().unwrap();
+
+    let metadata = runtime.metadata(&path).await.unwrap();
+    assert!(metadata.is_file());
+    assert_eq!(metadata.len(), 5);
+
+    let metadata = runtime.metadata(&dir).await.unwrap();
+    assert!(metadata.is_dir());
+    assert!(!metadata.is_file());
+
+    drop(file);
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that written data can be synced.
+pub async fn file_sync(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "file_sync").await;
+    let path = dir.join("file");
+
+    let mut file = pin!(
+        open(runtime, OpenOptions::new().write(true).create(true), &path)
+            .await
+            .unwrap()
+    );
+    file.write_all(b"hello").await.unwrap();
+    file.flush().await.unwrap();
+    file.sync_data().await.unwrap();
+    file.sync_all().await.unwrap();
+    assert_eq!(runtime.read(&path).await.unwrap(), b"hello");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that permissions of an opened file can be changed.
+pub async fn file_set_permissions(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "file_set_permissions").await;
+    let path = dir.join("file");
+    runtime.write(&path, b"").await.unwrap();
+
+    let file = open(runtime, OpenOptions::new().read(true), &path)
+        .await
+        .unwrap();
+    let mut permissions = file.metadata().await.unwrap().permissions();
+    permissions.set_readonly(true);
+    file.set_permissions(permissions).await.unwrap();
+    assert!(file.metadata().await.unwrap().permissions().readonly());
+
+    permissions.set_readonly(false);
+    file.set_permissions(permissions).await.unwrap();
+    assert!(!runtime
+        .metadata(&path)
+        .await
+        .unwrap()
+        .permissions()
+        .readonly());
+
+    drop(file);
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks `create_dir` and `remove_dir` including their errors.
+pub async fn create_dir(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "create_dir").await;
+    let path = dir.join("dir");
+
+    runtime.create_dir(&path).await.unwrap();
+    assert!(runtime.metadata(&path).await.unwrap().is_dir());
+    assert_eq!(
+        runtime.create_dir(&path).await.unwrap_err().kind(),
+        ErrorKind::AlreadyExists
+    );
+    assert_eq!(
+        runtime
+            .create_dir(dir.join("missing").join("dir"))
+            .await
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotFound
+    );
+
+    runtime.remove_dir(&path).await.unwrap();
+    assert_eq!(
+        runtime.metadata(&path).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+    assert_eq!(
+        runtime.remove_dir(&path).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `create_dir_all` creates missing parents and succeeds if a directory exists.
+pub async fn create_dir_all(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "create_dir_all").await;
+    let path = dir.join("first").join("second").join("third");
+
+    runtime.create_dir_all(&path).await.unwrap();
+    assert!(runtime.metadata(&path).await.unwrap().is_dir());
+    runtime.create_dir_all(&path).await.unwrap();
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `remove_dir` fails with [`ErrorKind::DirectoryNotEmpty`] on a non-empty directory.
+pub async fn remove_dir_fails_if_not_empty(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "remove_dir_fails_if_not_empty").await;
+    runtime.write(dir.join("file"), b"").await.unwrap();
+
+    assert_eq!(
+        runtime.remove_dir(&dir).await.unwrap_err().kind(),
+        ErrorKind::DirectoryNotEmpty
+    );
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `remove_dir_all` removes a whole tree.
+pub async fn remove_dir_all(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "remove_dir_all").await;
+    let nested = dir.join("nested");
+    runtime.create_dir_all(nested.join("dir")).await.unwrap();
+    runtime.write(nested.join("file"), b"").await.unwrap();
+    runtime
+        .write(nested.join("dir").join("file"), b"")
+        .await
+        .unwrap();
+
+    runtime.remove_dir_all(&nested).await.unwrap();
+    assert_eq!(
+        runtime.metadata(&nested).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `read_dir` lists every entry of a directory with its type, path and metadata.
+pub async fn read_dir(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "read_dir").await;
+    runtime.write(dir.join("file"), b"hello").await.unwrap();
+    runtime.create_dir(dir.join("dir")).await.unwrap();
+
+    let mut entries: Vec<_> = runtime
+        .read_dir(&dir)
+        .await
+        .unwrap()
+        .try_collect()
+        .await
+        .unwrap();
+    entries.sort_by_key(RuntimeDirEntry::file_name);
+    assert_eq!(entries.len(), 2);
+
+    let (dir_entry, file_entry) = (&entries[0], &entries[1]);
+    assert_eq!(dir_entry.file_name(), "dir");
+    assert_eq!(dir_entry.path(), dir.join("dir"));
+    assert!(dir_entry.file_type().await.unwrap().is_dir());
+    assert!(dir_entry.metadata().await.unwrap().is_dir());
+
+    assert_eq!(file_entry.file_name(), "file");
+    assert_eq!(file_entry.path(), dir.join("file"));
+    assert!(file_entry.file_type().await.unwrap().is_file());
+    assert_eq!(file_entry.metadata().await.unwrap().len(), 5);
+
+    drop(entries);
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `rename` moves a file and replaces an existing destination.
+pub async fn rename(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "rename").await;
+    let (first, second, third) = (dir.join("first"), dir.join("second"), dir.join("third"));
+    runtime.write(&first, b"first").await.unwrap();
+    runtime.write(&third, b"third").await.unwrap();
+
+    runtime.rename(&first, &second).await.unwrap();
+    assert_eq!(
+        runtime.metadata(&first).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+    assert_eq!(runtime.read_to_string(&second).await.unwrap(), "first");
+
+    runtime.rename(&third, &second).await.unwrap();
+    assert_eq!(runtime.read_to_string(&second).await.unwrap(), "third");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `copy` copies contents and returns the number of copied bytes.
+pub async fn copy(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "copy").await;
+    let (from, to) = (dir.join("from"), dir.join("to"));
+    runtime.write(&from, b"hello").await.unwrap();
+    runtime.write(&to, b"hello world").await.unwrap();
+
+    assert_eq!(runtime.copy(&from, &to).await.unwrap(), 5);
+    assert_eq!(runtime.read_to_string(&to).await.unwrap(), "hello");
+    assert_eq!(runtime.read_to_string(&from).await.unwrap(), "hello");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that hard links share contents and outlive the original path.
+pub async fn hard_link(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "hard_link").await;
+    let (original, link) = (dir.join("original"), dir.join("link"));
+    runtime.write(&original, b"hello").await.unwrap();
+
+    runtime.hard_link(&original, &link).await.unwrap();
+    runtime.write(&link, b"bye").await.unwrap();
+    assert_eq!(runtime.read_to_string(&original).await.unwrap(), "bye");
+
+    runtime.remove_file(&original).await.unwrap();
+    assert_eq!(runtime.read_to_string(&link).await.unwrap(), "bye");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that symbolic links are followed by everything except `read_link` and
+/// `symlink_metadata`.
+#[cfg(any(unix, doc))]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+pub async fn symlink(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "symlink").await;
+    let (target, link) = (dir.join("target"), dir.join("link"));
+    runtime.write(&target, b"hello").await.unwrap();
+
+    runtime.symlink(&target, &link).await.unwrap();
+    assert_eq!(runtime.read_to_string(&link).await.unwrap(), "hello");
+    assert_eq!(runtime.read_link(&link).await.unwrap(), target);
+    assert!(runtime.metadata(&link).await.unwrap().is_file());
+    assert!(runtime.symlink_metadata(&link).await.unwrap().is_symlink());
+    assert_eq!(
+        runtime.canonicalize(&link).await.unwrap(),
+        runtime.canonicalize(&target).await.unwrap()
+    );
+
+    runtime.remove_file(&link).await.unwrap();
+    assert_eq!(runtime.read_to_string(&target).await.unwrap(), "hello");
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `canonicalize` resolves `.` and `..` components and fails on missing paths.
+pub async fn canonicalize(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "canonicalize").await;
+    runtime.create_dir(dir.join("dir")).await.unwrap();
+
+    let canonical = runtime.canonicalize(&dir).await.unwrap();
+    assert!(canonical.is_absolute());
+    assert_eq!(
+        runtime
+            .canonicalize(dir.join("dir").join(".").join(".."))
+            .await
+            .unwrap(),
+        canonical
+    );
+    assert_eq!(
+        runtime
+            .canonicalize(dir.join("missing"))
+            .await
+            .unwrap_err()
+            .kind(),
+        ErrorKind::NotFound
+    );
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that `remove_file` removes a file and refuses to remove a directory.
+pub async fn remove_file(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "remove_file").await;
+    let path = dir.join("file");
+    runtime.write(&path, b"").await.unwrap();
+
+    runtime.remove_file(&path).await.unwrap();
+    assert_eq!(
+        runtime.metadata(&path).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+    assert_eq!(
+        runtime.remove_file(&path).await.unwrap_err().kind(),
+        ErrorKind::NotFound
+    );
+    runtime.remove_file(&dir).await.unwrap_err();
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
+
+/// Checks that permissions can be changed by path.
+pub async fn set_permissions(runtime: &impl FSRuntime) {
+    let dir = test_dir(runtime, "set_permissions").await;
+    let path = dir.join("file");
+    runtime.write(&path, b"").await.unwrap();
+
+    let mut permissions = runtime.metadata(&path).await.unwrap().permissions();
+    permissions.set_readonly(true);
+    runtime.set_permissions(&path, permissions).await.unwrap();
+    assert!(runtime
+        .metadata(&path)
+        .await
+        .unwrap()
+        .permissions()
+        .readonly());
+
+    permissions.set_readonly(false);
+    runtime.set_permissions(&path, permissions).await.unwrap();
+    assert!(!runtime
+        .metadata(&path)
+        .await
+        .unwrap()
+        .permissions()
+        .readonly());
+
+    runtime.remove_dir_all(&dir).await.unwrap();
+}
@@ -0,0 +1,267 @@
+//! # arta-conformance
+//! Arta-conformance is a crate that provides a test-suite checking that an implementation of
+//! `arta` abstractions behaves the way the traits promise.
+//!
+//! Every check is a plain async function generic over a runtime, they're grouped into
+//! suites: [`fs`], [`local_task`], [`net`], [`process`], [`task`], [`time`] and [`unix_net`]. The
+//! [`conformance_tests`] macro generates a `#[test]` for each check of the requested suites.
+//!
+//! ## Installation
+//! Add a following dependencies to your `Cargo.toml`:
+//! ```toml
+//! [dev-dependencies]
+//! arta-conformance = "0.3.0"
+//! ```
+//! ## Usage
+//!
+//! ```ignore
+//! // tests/conformance.rs
+//! arta_conformance::conformance_tests! {
+//!     runtime: MyRuntime = MyRuntime::new(),
+//!     block_on: |runtime, future| runtime.block_on(future),
+//!     suites: [fs, net, process, task, time],
+//! }
+//! ```
+
+#![deny(
+    warnings,
+    clippy::correctness,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf,
+    clippy::style,
+    clippy::pedantic,
+    clippy::restriction,
+    clippy::cargo
+)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::blanket_clippy_restriction_lints,
+    clippy::missing_inline_in_public_items,
+    clippy::single_char_lifetime_names,
+    clippy::implicit_return,
+    clippy::pattern_type_mismatch,
+    clippy::question_mark_used,
+    clippy::shadow_reuse,
+    clippy::shadow_same,
+    clippy::pub_with_shorthand,
+    clippy::absolute_paths,
+    clippy::exhaustive_enums,
+    clippy::exhaustive_structs,
+    clippy::multiple_crate_versions,
+    clippy::missing_docs_in_private_items,
+    clippy::pub_use,
+    clippy::infinite_loop, // Allowed because of bug: https://github.com/rust-lang/rust-clippy/issues/12338
+    clippy::unseparated_literal_suffix,
+    clippy::self_named_module_files,
+    clippy::big_endian_bytes,
+    clippy::single_call_fn,
+    clippy::missing_trait_methods,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::print_stdout,
+    clippy::shadow_unrelated,
+    clippy::undocumented_unsafe_blocks,
+    clippy::as_conversions,
+    clippy::ref_as_ptr,
+    clippy::doc_markdown,
+    clippy::unwrap_used,
+    clippy::unreachable,
+    clippy::impl_trait_in_params,
+    clippy::missing_errors_doc,
+    clippy::std_instead_of_core,
+    clippy::std_instead_of_alloc,
+    clippy::alloc_instead_of_core,
+    clippy::min_ident_chars,
+    clippy::missing_panics_doc, // Checks are expected to panic on failure.
+    clippy::missing_assert_message
+)]
+#![forbid(unreachable_pub, missing_docs)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+pub mod fs;
+pub mod local_task;
+pub mod net;
+#[cfg(any(unix, doc))]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+pub mod process;
+pub mod task;
+pub mod time;
+#[cfg(any(unix, doc))]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+pub mod unix_net;
+
+/// Generates a `#[test]` for every check of the requested suites.
+///
+/// A fresh runtime is created by the `runtime` expression for each test and the check is run to
+/// completion by the `block_on` closure. Available suites are `fs`, `local_task`, `net`, `process`,
+/// `task`, `time` and `unix_net`, checks of `process` and `unix_net` suites and symbolic links
+/// checks are generated only on Unix.
+///
+/// ```ignore
+/// arta_conformance::conformance_tests! {
+///     runtime: TokioGlobalRuntime = TokioGlobalRuntime,
+///     block_on: |_runtime, future| tokio::runtime::Runtime::new().unwrap().block_on(future),
+///     suites: [fs, net, process, task, time],
+/// }
+/// ```
+#[macro_export]
+macro_rules! conformance_tests {
+    (
+        runtime: $runtime_ty:ty = $runtime:expr,
+        block_on: |$runtime_arg:pat_param, $future_arg:ident| $block_on:expr,
+        suites: [$($suite:ident),* $(,)?] $(,)?
+    ) => {
+        fn __conformance_runtime() -> $runtime_ty {
+            $runtime
+        }
+
+        fn __conformance_block_on<F: ::core::future::Future>(
+            $runtime_arg: &$runtime_ty,
+            $future_arg: F,
+        ) -> F::Output {
+            $block_on
+        }
+
+        $($crate::conformance_tests!(@suite $suite);)*
+    };
+    (@suite fs) => {
+        $crate::conformance_tests!(@tests fs: [
+            write_creates_file,
+            write_truncates_file,
+            read_missing_file_fails,
+            open_without_access_fails,
+            open_create_new_fails_if_exists,
+            #[cfg(unix)] open_create_read_only,
+            file_append,
+            file_seek,
+            file_set_len,
+            file_metadata,
+            file_sync,
+            file_set_permissions,
+            create_dir,
+            create_dir_all,
+            remove_dir_fails_if_not_empty,
+            remove_dir_all,
+            read_dir,
+            rename,
+            copy,
+            hard_link,
+            #[cfg(unix)] symlink,
+            canonicalize,
+            remove_file,
+            set_permissions,
+        ]);
+    };
+    (@suite local_task) => {
+        $crate::conformance_tests!(@tests local_task: [
+            spawn,
+            shared_state,
+            spawn_panic,
+            cancel,
+        ]);
+    };
+    (@suite net) => {
+        $crate::conformance_tests!(@tests net: [
+            tcp_listener_bind,
+            tcp_connect_and_accept,
+            tcp_read_write,
+            tcp_close_signals_eof,
+            tcp_shutdown_write,
+            tcp_split_and_reunite,
+            tcp_peek,
+            tcp_options,
+            tcp_keepalive,
+            tcp_connect_refused,
+            tcp_socket_options,
+            tcp_socket_bind_and_connect,
+            #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+            tcp_socket_reuseport,
+            udp_send_to_and_recv_from,
+            udp_connect_send_and_recv,
+            udp_options,
+            resolve_host_names,
+            happy_eyeballs_connect,
+        ]);
+    };
+    (@suite unix_net) => {
+        #[cfg(unix)]
+        $crate::conformance_tests!(@tests unix_net: [
+            unix_stream_connect_and_accept,
+            unix_stream_connect_missing_fails,
+            unix_stream_pair,
+            unix_datagram_send_to_and_recv_from,
+            unix_datagram_connect_send_and_recv,
+            #[cfg(target_os = "linux")] unix_abstract_namespace,
+        ]);
+    };
+    (@suite process) => {
+        #[cfg(unix)]
+        $crate::conformance_tests!(@tests process: [
+            output,
+            status,
+            env_and_current_dir,
+            env_clear,
+            piped_stdio,
+            kill,
+            spawn_missing_program_fails,
+        ]);
+    };
+    (@suite task) => {
+        $crate::conformance_tests!(@tests task: [
+            spawn,
+            spawn_panic,
+            spawn_blocking,
+            spawn_blocking_panic,
+            spawn_with_builder,
+            block_on,
+            yield_now,
+            dropped_handle_detaches,
+            cancel,
+            join_set_completion_order,
+            join_set_abort_all,
+            join_set_cancel_on_drop,
+            scope_borrows_data,
+            scope_waits_for_tasks,
+            scope_propagates_panic,
+            scope_cancel_on_drop,
+        ]);
+    };
+    (@suite time) => {
+        $crate::conformance_tests!(@tests time: [
+            sleep,
+            sleep_zero,
+            sleep_max,
+            sleep_until,
+            sleep_reset,
+            clock_advances,
+            sleeps_complete_in_deadline_order,
+            timeout_completes,
+            timeout_elapses,
+            interval_ticks,
+            interval_missed_ticks,
+            delay_queue_expires_in_order,
+            delay_queue_remove_and_reset,
+            delay_queue_insert_earlier,
+            retry_until_success,
+            retry_gives_up,
+            retry_timeout,
+            rate_limiters,
+            throttled_io,
+            scheduled_job,
+            scheduled_job_overlap,
+        ]);
+    };
+    (@tests $suite:ident: [$($(#[$attr:meta])* $test:ident),* $(,)?]) => {
+        mod $suite {
+            $(
+                $(#[$attr])*
+                #[test]
+                fn $test() {
+                    let runtime = super::__conformance_runtime();
+                    super::__conformance_block_on(&runtime, $crate::$suite::$test(&runtime));
+                }
+            )*
+        }
+    };
+}
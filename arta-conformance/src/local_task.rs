@@ -0,0 +1,62 @@
+//! Checks of [`LocalTaskRuntime`] implementations.
+//!
+//! Tasks hold [`Rc`] to check that futures and outputs are not required to be `Send`.
+
+use arta::task::{LocalTaskRuntime, RuntimeLocalJoinHandle as _};
+use futures::channel::oneshot;
+use std::{cell::RefCell, rc::Rc};
+
+const PANIC_MESSAGE: &str = "conformance panic";
+
+/// Checks that awaiting a join handle returns the output of a local task.
+pub async fn spawn(runtime: &impl LocalTaskRuntime) {
+    let value = Rc::new(42i32);
+    let handle = runtime.spawn_local(async move { value });
+    assert_eq!(*handle.await.unwrap(), 42i32);
+}
+
+/// Checks that local tasks share state of the spawning thread.
+pub async fn shared_state(runtime: &impl LocalTaskRuntime) {
+    let state = Rc::new(RefCell::new(Vec::new()));
+
+    let handles: Vec<_> = (0i32..3i32)
+        .map(|index| {
+            let state = Rc::clone(&state);
+            runtime.spawn_local(async move { state.borrow_mut().push(index) })
+        })
+        .collect();
+    for handle in handles {
+        handle.await.unwrap();
+    }
+
+    state.borrow_mut().sort_unstable();
+    assert_eq!(*state.borrow(), [0i32, 1i32, 2i32]);
+}
+
+/// Checks that a panic in a local task is caught and its payload is returned in a join error.
+#[expect(clippy::panic, reason = "the panic is the behavior being checked")]
+pub async fn spawn_panic(runtime: &impl LocalTaskRuntime) {
+    let handle = runtime.spawn_local(async { panic!("{PANIC_MESSAGE}") });
+    let err = handle.await.unwrap_err();
+    assert!(err.is_panic());
+
+    let payload = err.into_panic();
+    assert_eq!(
+        payload.downcast_ref::<String>().map(String::as_str),
+        Some(PANIC_MESSAGE)
+    );
+}
+
+/// Checks that cancellation of a pending local task returns a cancellation error only after the
+/// task is dropped.
+pub async fn cancel(runtime: &impl LocalTaskRuntime) {
+    let (sender, mut receiver) = oneshot::channel::<()>();
+
+    let handle = runtime.spawn_local(async move {
+        let _sender = Rc::new(sender);
+        futures::future::pending::<()>().await;
+    });
+    assert!(handle.cancel().await.unwrap_err().is_cancelled());
+
+    receiver.try_recv().unwrap_err();
+}
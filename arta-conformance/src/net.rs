@@ -0,0 +1,500 @@
+//! Checks of [`NetRuntime`] implementations.
+//!
+//! Sockets are bound to ephemeral ports of the IPv4 loopback interface.
+
+use arta::{
+    net::{
+        HappyEyeballsBuilder, NetRuntime, RuntimeTcpListener as _, RuntimeTcpSocket as _,
+        RuntimeTcpStream, RuntimeUdpSocket as _, TcpKeepalive, TcpStreamOptions,
+        ToSocketAddrs as _,
+    },
+    time::TimeRuntime,
+};
+use futures::{AsyncReadExt as _, AsyncWriteExt as _};
+use std::{
+    io::ErrorKind,
+    net::{Ipv4Addr, Shutdown, SocketAddr},
+    pin::pin,
+    time::Duration,
+};
+
+const LOCALHOST: SocketAddr = SocketAddr::new(std::net::IpAddr::V4(Ipv4Addr::LOCALHOST), 0);
+
+async fn connected_pair<R: NetRuntime>(runtime: &R) -> (R::TcpStream, R::TcpStream) {
+    let listener = R::TcpListener::bind(runtime, LOCALHOST).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let (client, accepted) =
+        futures::join!(R::TcpStream::connect(runtime, addr), listener.accept());
+    (client.unwrap(), accepted.unwrap().0)
+}
+
+/// Checks that a listener bound to zero port gets an ephemeral one.
+pub async fn tcp_listener_bind<R: NetRuntime>(runtime: &R) {
+    let listener = R::TcpListener::bind(runtime, LOCALHOST).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    assert_eq!(addr.ip(), LOCALHOST.ip());
+    assert_ne!(addr.port(), 0);
+
+    listener.set_ttl(100).unwrap();
+    assert_eq!(listener.ttl().unwrap(), 100);
+
+    let result = R::TcpListener::bind(runtime, addr).await;
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(ErrorKind::AddrInUse)
+    );
+}
+
+/// Checks that addresses of connected and accepted streams match each other.
+pub async fn tcp_connect_and_accept<R: NetRuntime>(runtime: &R) {
+    let listener = R::TcpListener::bind(runtime, LOCALHOST).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let (client, accepted) =
+        futures::join!(R::TcpStream::connect(runtime, addr), listener.accept());
+    let (client, (server, peer_addr)) = (client.unwrap(), accepted.unwrap());
+
+    assert_eq!(client.peer_addr().unwrap(), addr);
+    assert_eq!(client.local_addr().unwrap(), peer_addr);
+    assert_eq!(server.peer_addr().unwrap(), peer_addr);
+    assert_eq!(server.local_addr().unwrap(), addr);
+}
+
+/// Checks that data written to one end of a stream is read from the other one in both
+/// directions.
+pub async fn tcp_read_write(runtime: &impl NetRuntime) {
+    let (client, server) = connected_pair(runtime).await;
+    let (mut client, mut server) = (pin!(client), pin!(server));
+    let mut buf = [0u8; 4];
+
+    client.write_all(b"ping").await.unwrap();
+    client.flush().await.unwrap();
+    server.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"ping");
+
+    server.write_all(b"pong").await.unwrap();
+    server.flush().await.unwrap();
+    client.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"pong");
+}
+
+/// Checks that closing one end of a stream is observed as the end of data by the other one.
+pub async fn tcp_close_signals_eof(runtime: &impl NetRuntime) {
+    let (client, server) = connected_pair(runtime).await;
+    let (mut client, mut server) = (pin!(client), pin!(server));
+
+    client.write_all(b"bye").await.unwrap();
+    client.close().await.unwrap();
+
+    let mut received = Vec::new();
+    server.read_to_end(&mut received).await.unwrap();
+    assert_eq!(received, b"bye");
+}
+
+/// Checks that shutting down the write half of a stream signals the end of data to the peer
+/// while the stream is still readable.
+pub async fn tcp_shutdown_write<R>(runtime: &R)
+where
+    R: NetRuntime,
+    R::TcpStream: TcpStreamOptions,
+{
+    let (client, server) = connected_pair(runtime).await;
+    let (mut client, mut server) = (pin!(client), pin!(server));
+
+    client.write_all(b"request").await.unwrap();
+    client.shutdown(Shutdown::Write).unwrap();
+    assert!(client.write_all(b"more").await.is_err());
+
+    let mut received = Vec::new();
+    server.read_to_end(&mut received).await.unwrap();
+    assert_eq!(received, b"request");
+
+    server.write_all(b"response").await.unwrap();
+    server.close().await.unwrap();
+
+    let mut received = Vec::new();
+    client.read_to_end(&mut received).await.unwrap();
+    assert_eq!(received, b"response");
+}
+
+/// Checks that owned halves of a stream are used concurrently and reunite only with halves of
+/// the same stream.
+pub async fn tcp_split_and_reunite<R: NetRuntime>(runtime: &R) {
+    let (client, server) = connected_pair(runtime).await;
+    let (other_client, _other_server) = connected_pair(runtime).await;
+    let peer_addr = client.peer_addr().unwrap();
+
+    let (mut read, mut write) = client.into_split();
+    let (mut server_read, mut server_write) = server.into_split();
+    futures::join!(
+        async {
+            write.write_all(b"ping").await.unwrap();
+            let mut buf = [0u8; 4];
+            read.read_exact(&mut buf).await.unwrap();
+            assert_eq!(&buf, b"pong");
+        },
+        async {
+            let mut buf = [0u8; 4];
+            server_read.read_exact(&mut buf).await.unwrap();
+            assert_eq!(&buf, b"ping");
+            server_write.write_all(b"pong").await.unwrap();
+        },
+    );
+
+    let (other_read, other_write) = other_client.into_split();
+    let err = R::TcpStream::reunite(read, other_write).err().unwrap();
+    let (read, _other_write) = (err.0, err.1);
+    let err = R::TcpStream::reunite(other_read, write).err().unwrap();
+    let (_other_read, write) = (err.0, err.1);
+
+    let client = R::TcpStream::reunite(read, write).unwrap();
+    assert_eq!(client.peer_addr().unwrap(), peer_addr);
+
+    server_write.close().await.unwrap();
+    let mut client = pin!(client);
+    let mut rest = Vec::new();
+    client.read_to_end(&mut rest).await.unwrap();
+    assert!(rest.is_empty());
+}
+
+/// Checks that `peek` doesn't consume received data.
+pub async fn tcp_peek(runtime: &impl NetRuntime) {
+    let (client, server) = connected_pair(runtime).await;
+    let (mut client, mut server) = (pin!(client), pin!(server));
+
+    client.write_all(b"abc").await.unwrap();
+    client.flush().await.unwrap();
+
+    let mut buf = [0u8; 3];
+    let len = server.peek(&mut buf).await.unwrap();
+    assert!(len > 0);
+    assert_eq!(buf[..len], b"abc"[..len]);
+
+    server.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"abc");
+}
+
+/// Checks getters and setters of stream options.
+pub async fn tcp_options<R>(runtime: &R)
+where
+    R: NetRuntime,
+    R::TcpStream: TcpStreamOptions,
+{
+    let (client, _server) = connected_pair(runtime).await;
+
+    client.set_nodelay(true).unwrap();
+    assert!(client.nodelay().unwrap());
+    client.set_nodelay(false).unwrap();
+    assert!(!client.nodelay().unwrap());
+
+    client.set_ttl(100).unwrap();
+    assert_eq!(client.ttl().unwrap(), 100);
+
+    #[cfg(not(target_os = "wasi"))]
+    check_linger(&client);
+
+    // Systems may round buffer sizes up, Linux doubles them.
+    client.set_send_buffer_size(0x8000).unwrap();
+    assert!(client.send_buffer_size().unwrap() >= 0x8000);
+    client.set_recv_buffer_size(0x8000).unwrap();
+    assert!(client.recv_buffer_size().unwrap() >= 0x8000);
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    check_tos(&client);
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    check_user_timeout(&client);
+
+    assert!(client.take_error().unwrap().is_none());
+}
+
+#[cfg(not(target_os = "wasi"))]
+fn check_linger(stream: &impl RuntimeTcpStream) {
+    stream.set_linger(Some(Duration::from_secs(1))).unwrap();
+    assert_eq!(stream.linger().unwrap(), Some(Duration::from_secs(1)));
+    stream.set_linger(None).unwrap();
+    assert_eq!(stream.linger().unwrap(), None);
+}
+
+#[cfg(not(any(
+    target_os = "fuchsia",
+    target_os = "redox",
+    target_os = "solaris",
+    target_os = "illumos",
+    target_os = "haiku",
+)))]
+fn check_tos(stream: &impl TcpStreamOptions) {
+    stream.set_tos(0x10).unwrap();
+    assert_eq!(stream.tos().unwrap(), 0x10);
+}
+
+#[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+fn check_user_timeout(stream: &impl TcpStreamOptions) {
+    stream
+        .set_user_timeout(Some(Duration::from_secs(30)))
+        .unwrap();
+    assert_eq!(
+        stream.user_timeout().unwrap(),
+        Some(Duration::from_secs(30))
+    );
+    stream.set_user_timeout(None).unwrap();
+    assert_eq!(stream.user_timeout().unwrap(), None);
+}
+
+/// Checks that keepalive probes are enabled with custom parameters and disabled again.
+pub async fn tcp_keepalive<R>(runtime: &R)
+where
+    R: NetRuntime,
+    R::TcpStream: TcpStreamOptions,
+{
+    let (client, _server) = connected_pair(runtime).await;
+    assert!(!client.keepalive().unwrap());
+
+    client.set_keepalive(Some(&TcpKeepalive::new())).unwrap();
+    assert!(client.keepalive().unwrap());
+    client.set_keepalive(None).unwrap();
+    assert!(!client.keepalive().unwrap());
+
+    let mut keepalive = TcpKeepalive::new();
+    keepalive.time(Duration::from_secs(75));
+    #[cfg(any(target_os = "android", target_os = "linux", target_os = "macos"))]
+    keepalive.interval(Duration::from_secs(10)).retries(5);
+    client.set_keepalive(Some(&keepalive)).unwrap();
+    assert!(client.keepalive().unwrap());
+}
+
+/// Checks that connecting to a closed port fails with [`ErrorKind::ConnectionRefused`].
+pub async fn tcp_connect_refused<R: NetRuntime>(runtime: &R) {
+    let listener = R::TcpListener::bind(runtime, LOCALHOST).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    drop(listener);
+
+    let result = R::TcpStream::connect(runtime, addr).await;
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(ErrorKind::ConnectionRefused)
+    );
+}
+
+/// Checks getters and setters of socket options set before connecting or listening.
+#[expect(
+    clippy::unused_async,
+    reason = "all checks are awaited by `conformance_tests`"
+)]
+pub async fn tcp_socket_options<R: NetRuntime>(runtime: &R) {
+    let socket = R::TcpSocket::new_v4(runtime).unwrap();
+
+    socket.set_reuseaddr(true).unwrap();
+    assert!(socket.reuseaddr().unwrap());
+    socket.set_reuseaddr(false).unwrap();
+    assert!(!socket.reuseaddr().unwrap());
+
+    // Systems may round buffer sizes up, Linux doubles them.
+    socket.set_send_buffer_size(0x8000).unwrap();
+    assert!(socket.send_buffer_size().unwrap() >= 0x8000);
+    socket.set_recv_buffer_size(0x8000).unwrap();
+    assert!(socket.recv_buffer_size().unwrap() >= 0x8000);
+
+    assert!(socket.take_error().unwrap().is_none());
+
+    let socket = R::TcpSocket::new_v6(runtime).unwrap();
+    socket.set_only_v6(true).unwrap();
+    assert!(socket.only_v6().unwrap());
+    socket.set_only_v6(false).unwrap();
+    assert!(!socket.only_v6().unwrap());
+}
+
+/// Checks that a listener is created from a bound socket and a connection is made from a socket
+/// bound to a specific local address.
+pub async fn tcp_socket_bind_and_connect<R: NetRuntime>(runtime: &R) {
+    let socket = R::TcpSocket::new_for_addr(runtime, LOCALHOST).unwrap();
+    socket.bind(LOCALHOST).unwrap();
+    let addr = socket.local_addr().unwrap();
+    assert_eq!(addr.ip(), LOCALHOST.ip());
+    assert_ne!(addr.port(), 0);
+    let listener = socket.listen(128).unwrap();
+    assert_eq!(listener.local_addr().unwrap(), addr);
+
+    let socket = R::TcpSocket::new_v4(runtime).unwrap();
+    socket.bind(LOCALHOST).unwrap();
+    let local_addr = socket.local_addr().unwrap();
+
+    let (client, accepted) = futures::join!(socket.connect(addr), listener.accept());
+    let (client, (server, peer_addr)) = (client.unwrap(), accepted.unwrap());
+    assert_eq!(client.local_addr().unwrap(), local_addr);
+    assert_eq!(peer_addr, local_addr);
+    assert_eq!(server.local_addr().unwrap(), addr);
+}
+
+/// Checks that multiple listeners are bound to the same address with `SO_REUSEPORT` and a
+/// connection is accepted by one of them.
+#[cfg(any(all(unix, not(any(target_os = "solaris", target_os = "illumos"))), doc))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos")))))
+)]
+pub async fn tcp_socket_reuseport<R: NetRuntime>(runtime: &R) {
+    let first = R::TcpSocket::new_v4(runtime).unwrap();
+    first.set_reuseport(true).unwrap();
+    assert!(first.reuseport().unwrap());
+    first.bind(LOCALHOST).unwrap();
+    let addr = first.local_addr().unwrap();
+
+    let second = R::TcpSocket::new_v4(runtime).unwrap();
+    second.set_reuseport(true).unwrap();
+    second.bind(addr).unwrap();
+
+    let (first, second) = (first.listen(128).unwrap(), second.listen(128).unwrap());
+    let (first_accept, second_accept) = (pin!(first.accept()), pin!(second.accept()));
+    let (client, accepted) = futures::join!(
+        R::TcpStream::connect(runtime, addr),
+        futures::future::select(first_accept, second_accept),
+    );
+    let (client, (_, peer_addr)) = (client.unwrap(), accepted.factor_first().0.unwrap());
+    assert_eq!(client.local_addr().unwrap(), peer_addr);
+
+    let third = R::TcpSocket::new_v4(runtime).unwrap();
+    assert_eq!(
+        third.bind(addr).err().map(|err| err.kind()),
+        Some(ErrorKind::AddrInUse)
+    );
+}
+
+/// Checks that a datagram sent to an address is received along with the sender address.
+pub async fn udp_send_to_and_recv_from<R: NetRuntime>(runtime: &R) {
+    let first = R::UdpSocket::bind(runtime, LOCALHOST).await.unwrap();
+    let second = R::UdpSocket::bind(runtime, LOCALHOST).await.unwrap();
+    let (first_addr, second_addr) = (first.local_addr().unwrap(), second.local_addr().unwrap());
+    assert_ne!(first_addr.port(), 0);
+    assert_ne!(first_addr, second_addr);
+
+    assert_eq!(first.send_to(b"hello", second_addr).await.unwrap(), 5);
+    let mut buf = [0u8; 16];
+    let (len, addr) = second.recv_from(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"hello");
+    assert_eq!(addr, first_addr);
+}
+
+/// Checks that connected sockets exchange datagrams with `send` and `recv`.
+pub async fn udp_connect_send_and_recv<R: NetRuntime>(runtime: &R) {
+    let first = R::UdpSocket::bind(runtime, LOCALHOST).await.unwrap();
+    let second = R::UdpSocket::bind(runtime, LOCALHOST).await.unwrap();
+    first.send(b"hello").await.unwrap_err();
+
+    first.connect(second.local_addr().unwrap()).await.unwrap();
+    second.connect(first.local_addr().unwrap()).await.unwrap();
+
+    let mut buf = [0u8; 16];
+    assert_eq!(first.send(b"hello").await.unwrap(), 5);
+    let len = second.recv(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"hello");
+
+    assert_eq!(second.send(b"bye").await.unwrap(), 3);
+    let len = first.recv(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"bye");
+}
+
+/// Checks getters and setters of datagram socket options.
+pub async fn udp_options<R: NetRuntime>(runtime: &R) {
+    let socket = R::UdpSocket::bind(runtime, LOCALHOST).await.unwrap();
+
+    socket.set_broadcast(true).unwrap();
+    assert!(socket.broadcast().unwrap());
+    socket.set_broadcast(false).unwrap();
+    assert!(!socket.broadcast().unwrap());
+
+    socket.set_ttl(100).unwrap();
+    assert_eq!(socket.ttl().unwrap(), 100);
+
+    socket.set_multicast_loop_v4(false).unwrap();
+    assert!(!socket.multicast_loop_v4().unwrap());
+    socket.set_multicast_ttl_v4(8).unwrap();
+    assert_eq!(socket.multicast_ttl_v4().unwrap(), 8);
+
+    assert!(socket.take_error().unwrap().is_none());
+}
+
+/// Checks that host names and IP literals written as strings are resolved to socket addresses.
+///
+/// Expects `localhost` to resolve to `127.0.0.1` among other loopback addresses.
+pub async fn resolve_host_names<R: NetRuntime>(runtime: &R) {
+    let listener = R::TcpListener::bind(runtime, "127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+
+    let addrs: Vec<_> = ("localhost", addr.port())
+        .to_socket_addrs(runtime)
+        .await
+        .unwrap()
+        .collect();
+    assert!(addrs.contains(&addr));
+    assert!(addrs
+        .iter()
+        .all(|resolved| resolved.ip().is_loopback() && resolved.port() == addr.port()));
+
+    let (client, accepted) = futures::join!(
+        R::TcpStream::connect(runtime, format!("localhost:{}", addr.port())),
+        listener.accept()
+    );
+    let (client, (_, peer_addr)) = (client.unwrap(), accepted.unwrap());
+    assert_eq!(client.local_addr().unwrap(), peer_addr);
+
+    for invalid in ["localhost", "localhost:http"] {
+        let result = invalid.to_socket_addrs(runtime).await;
+        assert_eq!(
+            result.err().map(|err| err.kind()),
+            Some(ErrorKind::InvalidInput),
+            "{invalid}"
+        );
+    }
+}
+
+/// Checks that a Happy Eyeballs connect skips unreachable and refused addresses without waiting
+/// for them to time out.
+///
+/// The unreachable address is from a range reserved for documentation, it's expected to be
+/// either blackholed or rejected immediately.
+pub async fn happy_eyeballs_connect<R: NetRuntime + TimeRuntime>(runtime: &R) {
+    let listener = R::TcpListener::bind(runtime, LOCALHOST).await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let closed_addr = {
+        let closed_listener = R::TcpListener::bind(runtime, LOCALHOST).await.unwrap();
+        closed_listener.local_addr().unwrap()
+    };
+    let unreachable_addr = SocketAddr::new(Ipv4Addr::new(192, 0, 2, 1).into(), addr.port());
+
+    let mut builder = HappyEyeballsBuilder::new();
+    builder.attempt_delay(Duration::from_millis(50));
+
+    let start = runtime.now();
+    let addrs = [unreachable_addr, closed_addr, addr];
+    let (client, accepted) = futures::join!(
+        builder.connect(runtime, addrs.as_slice()),
+        listener.accept()
+    );
+    let (client, (_, peer_addr)) = (client.unwrap(), accepted.unwrap());
+    assert_eq!(client.peer_addr().unwrap(), addr);
+    assert_eq!(client.local_addr().unwrap(), peer_addr);
+    assert!(runtime.now().duration_since(start) < Duration::from_secs(5));
+
+    // The next attempt starts once the previous one fails even if the delay never elapses.
+    builder.attempt_delay(Duration::MAX);
+    let addrs = [closed_addr, addr];
+    let (client, accepted) = futures::join!(
+        builder.connect(runtime, addrs.as_slice()),
+        listener.accept()
+    );
+    let (client, (_, peer_addr)) = (client.unwrap(), accepted.unwrap());
+    assert_eq!(client.local_addr().unwrap(), peer_addr);
+
+    let result = builder.connect(runtime, [closed_addr].as_slice()).await;
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(ErrorKind::ConnectionRefused)
+    );
+}
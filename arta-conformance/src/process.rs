@@ -0,0 +1,104 @@
+//! Checks of [`ProcessRuntime`] implementations.
+//!
+//! Checks run programs that are available on any Unix system like `sh` and `cat`.
+
+use arta::process::{ProcessRuntime, RuntimeChild, RuntimeCommand as _};
+use futures::AsyncWriteExt as _;
+use std::{io::ErrorKind, pin::pin, process::Stdio};
+
+fn shell<R: ProcessRuntime>(runtime: &R, script: &str) -> R::Command {
+    let mut command = runtime.command("/bin/sh");
+    command.arg("-c").arg(script);
+    command
+}
+
+async fn write_stdin(child: &mut impl RuntimeChild, data: &[u8]) {
+    let mut stdin = pin!(child.stdin().unwrap());
+    stdin.write_all(data).await.unwrap();
+    stdin.flush().await.unwrap();
+}
+
+/// Checks that `output` captures stdout, stderr and the exit status.
+pub async fn output<R: ProcessRuntime>(runtime: &R) {
+    let output = shell(runtime, "echo out; echo err >&2; exit 3")
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.stdout, b"out\n");
+    assert_eq!(output.stderr, b"err\n");
+    assert_eq!(output.status.code(), Some(3i32));
+}
+
+/// Checks that `status` returns the exit status of a program.
+pub async fn status<R: ProcessRuntime>(runtime: &R) {
+    assert!(shell(runtime, "exit 0").status().await.unwrap().success());
+    assert_eq!(
+        shell(runtime, "exit 1").status().await.unwrap().code(),
+        Some(1i32)
+    );
+}
+
+/// Checks that environment variables and the working directory are passed to a program.
+pub async fn env_and_current_dir<R: ProcessRuntime>(runtime: &R) {
+    let dir = std::env::temp_dir().canonicalize().unwrap();
+    let output = shell(runtime, "echo \"$FIRST ${SECOND:-unset}\"; pwd -P")
+        .env("FIRST", "first")
+        .envs([("SECOND", "second")].into_iter())
+        .env_remove("SECOND")
+        .current_dir(&dir)
+        .output()
+        .await
+        .unwrap();
+
+    let expected = format!("first unset\n{}\n", dir.display());
+    assert_eq!(String::from_utf8(output.stdout).unwrap(), expected);
+}
+
+/// Checks that `env_clear` removes inherited environment variables.
+pub async fn env_clear<R: ProcessRuntime>(runtime: &R) {
+    let output = shell(runtime, "echo \"${HOME:-unset}\"")
+        .env_clear()
+        .output()
+        .await
+        .unwrap();
+    assert_eq!(output.stdout, b"unset\n");
+}
+
+/// Checks that data written to piped stdin of a spawned child is read from its piped stdout.
+pub async fn piped_stdio<R: ProcessRuntime>(runtime: &R) {
+    let mut child = runtime
+        .command("cat")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    assert_ne!(child.id(), 0);
+
+    write_stdin(&mut child, b"hello").await;
+    let output = child.output().await.unwrap();
+    assert_eq!(output.stdout, b"hello");
+    assert!(output.status.success());
+}
+
+/// Checks that a killed child exits unsuccessfully.
+pub async fn kill<R: ProcessRuntime>(runtime: &R) {
+    let mut child = runtime.command("sleep").arg("10").spawn().unwrap();
+    assert!(child.try_status().unwrap().is_none());
+
+    child.kill().unwrap();
+    assert!(!child.status().await.unwrap().success());
+    assert!(child.try_status().unwrap().is_some());
+}
+
+/// Checks that spawning a missing program fails with [`ErrorKind::NotFound`].
+#[expect(
+    clippy::unused_async,
+    reason = "all checks are awaited by `conformance_tests`"
+)]
+pub async fn spawn_missing_program_fails<R: ProcessRuntime>(runtime: &R) {
+    let result = runtime.command("arta-conformance-missing-program").spawn();
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(ErrorKind::NotFound)
+    );
+}
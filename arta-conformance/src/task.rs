@@ -0,0 +1,269 @@
+//! Checks of [`TaskRuntime`] implementations.
+
+use arta::task::{JoinSet, RuntimeJoinHandle as _, TaskBuilder, TaskRuntime};
+use futures::{channel::oneshot, FutureExt as _};
+use std::{
+    panic::AssertUnwindSafe,
+    sync::{
+        atomic::{AtomicBool, AtomicI32, Ordering},
+        Arc,
+    },
+};
+
+const PANIC_MESSAGE: &str = "conformance panic";
+
+/// Checks that awaiting a join handle returns the output of a task.
+pub async fn spawn(runtime: &impl TaskRuntime) {
+    let handle = runtime.spawn(async { 42i32 });
+    assert_eq!(handle.await.unwrap(), 42i32);
+}
+
+/// Checks that a panic in a task is caught and its payload is returned in a join error.
+#[expect(clippy::panic, reason = "the panic is the behavior being checked")]
+pub async fn spawn_panic(runtime: &impl TaskRuntime) {
+    let handle = runtime.spawn(async { panic!("{PANIC_MESSAGE}") });
+    let err = handle.await.unwrap_err();
+    assert!(err.is_panic());
+    assert!(!err.is_cancelled());
+
+    let payload = err.into_panic();
+    assert_eq!(
+        payload.downcast_ref::<String>().map(String::as_str),
+        Some(PANIC_MESSAGE)
+    );
+}
+
+/// Checks that awaiting a join handle of a blocking task returns its output.
+pub async fn spawn_blocking(runtime: &impl TaskRuntime) {
+    let handle = runtime.spawn_blocking(|| 42i32);
+    assert_eq!(handle.await.unwrap(), 42i32);
+}
+
+/// Checks that a panic in a blocking task is caught and its payload is returned in a join error.
+#[expect(clippy::panic, reason = "the panic is the behavior being checked")]
+pub async fn spawn_blocking_panic(runtime: &impl TaskRuntime) {
+    let handle = runtime.spawn_blocking(|| panic!("{PANIC_MESSAGE}"));
+    let err = handle.await.unwrap_err();
+    assert!(err.is_panic());
+    assert!(!err.is_cancelled());
+
+    let payload = err.into_panic();
+    assert_eq!(
+        payload.downcast_ref::<String>().map(String::as_str),
+        Some(PANIC_MESSAGE)
+    );
+}
+
+/// Checks that tasks spawned with a builder are executed regardless of supported options and
+/// panics of blocking ones are caught.
+#[expect(clippy::panic, reason = "the panic is the behavior being checked")]
+pub async fn spawn_with_builder(runtime: &impl TaskRuntime) {
+    let mut builder = TaskBuilder::new();
+    builder.name("conformance").stack_size(4 * 1024 * 1024);
+    assert_eq!(builder.get_name(), Some("conformance"));
+
+    let handle = builder.spawn(runtime, async { 42i32 });
+    assert_eq!(handle.await.unwrap(), 42i32);
+
+    let handle = builder.spawn_blocking(runtime, || 42i32);
+    assert_eq!(handle.await.unwrap(), 42i32);
+
+    let handle = builder.spawn_blocking(runtime, || -> i32 { panic!("{PANIC_MESSAGE}") });
+    assert!(handle.await.unwrap_err().is_panic());
+}
+
+/// Checks that `block_on` drives a future to completion on a thread outside of the runtime.
+pub async fn block_on(runtime: &impl TaskRuntime) {
+    let output = std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                runtime.block_on(async {
+                    runtime.yield_now().await;
+                    runtime.spawn(async { 42i32 }).await.unwrap()
+                })
+            })
+            .join()
+            .unwrap()
+    });
+    assert_eq!(output, 42i32);
+    runtime.yield_now().await;
+}
+
+/// Checks that `yield_now` lets other tasks run, even on a single-threaded runtime.
+pub async fn yield_now(runtime: &impl TaskRuntime) {
+    let is_completed = Arc::new(AtomicBool::new(false));
+    let handle = runtime.spawn({
+        let is_completed = Arc::clone(&is_completed);
+        async move { is_completed.store(true, Ordering::SeqCst) }
+    });
+
+    while !is_completed.load(Ordering::SeqCst) {
+        runtime.yield_now().await;
+    }
+    handle.await.unwrap();
+}
+
+/// Checks that a task keeps running after its join handle is dropped.
+pub async fn dropped_handle_detaches(runtime: &impl TaskRuntime) {
+    let (start_sender, start_receiver) = oneshot::channel::<()>();
+    let (output_sender, output_receiver) = oneshot::channel();
+
+    drop(runtime.spawn(async move {
+        start_receiver.await.unwrap();
+        output_sender.send(42i32).unwrap();
+    }));
+    start_sender.send(()).unwrap();
+
+    assert_eq!(output_receiver.await, Ok(42i32));
+}
+
+/// Checks that cancellation of a pending task returns a cancellation error only after the task
+/// is dropped.
+pub async fn cancel(runtime: &impl TaskRuntime) {
+    let (sender, mut receiver) = oneshot::channel::<()>();
+
+    let handle = runtime.spawn(async move {
+        let _sender = sender;
+        futures::future::pending::<()>().await;
+    });
+    let err = handle.cancel().await.unwrap_err();
+    assert!(err.is_cancelled());
+    assert!(!err.is_panic());
+
+    receiver.try_recv().unwrap_err();
+}
+
+/// Checks that a join set returns outputs of tasks in the order they complete.
+pub async fn join_set_completion_order<R: TaskRuntime>(runtime: &R) {
+    let mut join_set = JoinSet::<R, _>::new();
+    let mut senders = Vec::new();
+    for index in 0i32..3i32 {
+        let (sender, receiver) = oneshot::channel::<()>();
+        senders.push(Some(sender));
+        join_set.spawn(runtime, async move {
+            receiver.await.unwrap();
+            index
+        });
+    }
+    assert_eq!(join_set.len(), 3);
+
+    for index in [2i32, 0i32, 1i32] {
+        let sender = senders[usize::try_from(index).unwrap()].take().unwrap();
+        sender.send(()).unwrap();
+        assert_eq!(join_set.join_next().await.unwrap().unwrap(), index);
+    }
+    assert!(join_set.is_empty());
+    assert!(join_set.join_next().await.is_none());
+}
+
+/// Checks that aborted tasks of a join set are returned with a cancellation error.
+pub async fn join_set_abort_all<R: TaskRuntime>(runtime: &R) {
+    let mut join_set = JoinSet::<R, ()>::new();
+    let (sender, receiver) = oneshot::channel::<()>();
+    join_set.spawn(runtime, async move {
+        let _sender = sender;
+        futures::future::pending::<()>().await;
+    });
+    join_set.spawn(runtime, futures::future::pending());
+
+    join_set.abort_all();
+    for _ in 0i32..2i32 {
+        let err = join_set.join_next().await.unwrap().unwrap_err();
+        assert!(err.is_cancelled());
+    }
+    assert!(join_set.is_empty());
+    receiver.await.unwrap_err();
+}
+
+/// Checks that dropping a join set cancels its remaining tasks.
+pub async fn join_set_cancel_on_drop<R: TaskRuntime>(runtime: &R) {
+    let mut join_set = JoinSet::<R, ()>::new();
+    let (sender, receiver) = oneshot::channel::<()>();
+    join_set.spawn(runtime, async move {
+        let _sender = sender;
+        futures::future::pending::<()>().await;
+    });
+
+    drop(join_set);
+    receiver.await.unwrap_err();
+}
+
+/// Checks that tasks spawned within a scope borrow data of the enclosing stack frame.
+pub async fn scope_borrows_data(runtime: &impl TaskRuntime) {
+    let mut values = vec![1i32, 2i32, 3i32];
+    let sum = AtomicI32::new(0i32);
+
+    let output = runtime
+        .scope(|scope| {
+            let (values, sum) = (&values, &sum);
+            async move {
+                let handles: Vec<_> = values
+                    .iter()
+                    .map(|value| {
+                        scope.spawn(async move { sum.fetch_add(*value, Ordering::SeqCst) })
+                    })
+                    .collect();
+                for handle in handles {
+                    handle.await.unwrap();
+                }
+                values.len()
+            }
+        })
+        .await;
+    assert_eq!(output, 3);
+    assert_eq!(sum.load(Ordering::SeqCst), 6i32);
+
+    values.push(4i32);
+}
+
+/// Checks that a scope completes only after all tasks spawned within it, including nested ones.
+pub async fn scope_waits_for_tasks(runtime: &impl TaskRuntime) {
+    let completed = AtomicI32::new(0i32);
+
+    runtime
+        .scope(|scope| {
+            let completed = &completed;
+            async move {
+                let nested_scope = scope.clone();
+                drop(scope.spawn(async move {
+                    drop(nested_scope.spawn(async move {
+                        completed.fetch_add(1i32, Ordering::SeqCst);
+                    }));
+                    completed.fetch_add(1i32, Ordering::SeqCst);
+                }));
+            }
+        })
+        .await;
+    assert_eq!(completed.load(Ordering::SeqCst), 2i32);
+}
+
+/// Checks that a panic of a task spawned within a scope propagates to the scope.
+#[expect(clippy::panic, reason = "the panic is the behavior being checked")]
+pub async fn scope_propagates_panic(runtime: &impl TaskRuntime) {
+    let scope = runtime.scope(|scope| async move {
+        drop(scope.spawn(async { panic!("{PANIC_MESSAGE}") }));
+    });
+    let payload = AssertUnwindSafe(scope).catch_unwind().await.unwrap_err();
+    assert_eq!(
+        payload.downcast_ref::<String>().map(String::as_str),
+        Some(PANIC_MESSAGE)
+    );
+}
+
+/// Checks that dropping a scope cancels tasks spawned within it.
+pub async fn scope_cancel_on_drop(runtime: &impl TaskRuntime) {
+    let (sender, receiver) = oneshot::channel::<()>();
+
+    let mut scope = Box::pin(runtime.scope(|scope| async move {
+        scope
+            .spawn(async move {
+                let _sender = sender;
+                futures::future::pending::<()>().await;
+            })
+            .await
+    }));
+    assert!(futures::poll!(scope.as_mut()).is_pending());
+
+    drop(scope);
+    receiver.await.unwrap_err();
+}
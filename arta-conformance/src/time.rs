@@ -0,0 +1,458 @@
+//! Checks of [`TimeRuntime`] implementations.
+
+use arta::{
+    task::TaskRuntime,
+    time::{
+        DelayQueue, Gcra, JobBuilder, JobHandle, MissedTickBehavior, OverlapPolicy, RateLimiter,
+        RetryBuilder, RuntimeSleep as _, Schedule, Throttled, TimeRuntime, TokenBucket,
+    },
+};
+use futures::{
+    channel::{mpsc, oneshot},
+    io::Cursor,
+    AsyncReadExt as _, AsyncWriteExt as _, FutureExt as _, StreamExt as _,
+};
+use std::{io::ErrorKind, panic::AssertUnwindSafe, sync::Mutex, time::Duration};
+
+/// Checks that a sleep completes.
+pub async fn sleep(runtime: &impl TimeRuntime) {
+    runtime.sleep(Duration::from_millis(10)).await;
+}
+
+/// Checks that a sleep for zero duration completes.
+pub async fn sleep_zero(runtime: &impl TimeRuntime) {
+    runtime.sleep(Duration::ZERO).await;
+}
+
+/// Checks that a sleep for [`Duration::MAX`] saturates its deadline instead of overflowing.
+pub async fn sleep_max(runtime: &impl TimeRuntime) {
+    let sleep = runtime.sleep(Duration::MAX);
+    assert!(sleep.deadline() > runtime.now());
+
+    let output = runtime.timeout(Duration::MAX, async { 42i32 }).await;
+    assert_eq!(output, Ok(42i32));
+}
+
+/// Checks that a sleep until a deadline completes no earlier than the deadline of the runtime's
+/// clock.
+pub async fn sleep_until(runtime: &impl TimeRuntime) {
+    let deadline = runtime.now() + Duration::from_millis(10);
+    runtime.sleep_until(deadline).await;
+    assert!(runtime.now() >= deadline);
+
+    runtime.sleep_until(runtime.now()).await;
+}
+
+/// Checks that resetting a sleep moves its deadline, both before and after it completes.
+pub async fn sleep_reset(runtime: &impl TimeRuntime) {
+    let mut sleep = runtime.sleep(Duration::from_secs(30));
+
+    let deadline = runtime.now() + Duration::from_millis(10);
+    sleep.reset(deadline);
+    assert_eq!(sleep.deadline(), deadline);
+    (&mut sleep).await;
+    assert!(runtime.now() >= deadline);
+
+    let deadline = runtime.now() + Duration::from_millis(10);
+    sleep.reset(deadline);
+    assert_eq!(sleep.deadline(), deadline);
+    (&mut sleep).await;
+    assert!(runtime.now() >= deadline);
+}
+
+/// Checks that the runtime's clocks advance at least by the duration of a sleep.
+pub async fn clock_advances(runtime: &impl TimeRuntime) {
+    let duration = Duration::from_millis(10);
+    let start = runtime.now();
+    let system_start = runtime.system_now();
+    runtime.sleep(duration).await;
+
+    assert!(runtime.now().duration_since(start) >= duration);
+    assert!(runtime.system_now().duration_since(system_start).unwrap() >= duration);
+}
+
+/// Checks that concurrent sleeps complete in order of their deadlines.
+pub async fn sleeps_complete_in_deadline_order(runtime: &impl TimeRuntime) {
+    let completed = Mutex::new(Vec::new());
+    let sleep = |millis| {
+        let completed = &completed;
+        async move {
+            runtime.sleep(Duration::from_millis(millis)).await;
+            completed.lock().unwrap().push(millis);
+        }
+    };
+
+    futures::join!(sleep(60), sleep(20), sleep(40));
+    assert_eq!(completed.into_inner().unwrap(), [20, 40, 60]);
+}
+
+/// Checks that a timeout returns the output of a future that completes in time.
+pub async fn timeout_completes(runtime: &impl TimeRuntime) {
+    let output = runtime
+        .timeout(Duration::from_secs(30), async { 42i32 })
+        .await;
+    assert_eq!(output, Ok(42i32));
+
+    let deadline = runtime.now() + Duration::from_secs(30);
+    let output = runtime.timeout_at(deadline, async { 42i32 }).await;
+    assert_eq!(output, Ok(42i32));
+}
+
+/// Checks that a timeout of a future that doesn't complete in time elapses with an error
+/// convertible to [`ErrorKind::TimedOut`].
+pub async fn timeout_elapses(runtime: &impl TimeRuntime) {
+    let pending = futures::future::pending::<()>();
+    let err = runtime
+        .timeout(Duration::from_millis(10), pending)
+        .await
+        .unwrap_err();
+    assert_eq!(std::io::Error::from(err).kind(), ErrorKind::TimedOut);
+
+    let deadline = runtime.now() + Duration::from_millis(10);
+    let pending = futures::future::pending::<()>();
+    runtime.timeout_at(deadline, pending).await.unwrap_err();
+
+    let past_deadline = runtime.now();
+    let pending = futures::future::pending::<()>();
+    runtime
+        .timeout_at(past_deadline, pending)
+        .await
+        .unwrap_err();
+}
+
+/// Checks that an interval ticks immediately and then once per period, yielding scheduled
+/// instants, and that a zero period is rejected.
+pub async fn interval_ticks(runtime: &impl TimeRuntime) {
+    let period = Duration::from_millis(20);
+    let start = runtime.now();
+    let ticks: Vec<_> = runtime.interval(period).take(3).collect().await;
+
+    assert_eq!(ticks.len(), 3);
+    assert!(ticks[0] >= start);
+    assert_eq!(ticks[1], ticks[0] + period);
+    assert_eq!(ticks[2], ticks[1] + period);
+
+    let start = runtime.now() + period;
+    let mut interval = runtime.interval_at(start, period);
+    assert_eq!(interval.tick().await, start);
+    assert!(runtime.now() >= start);
+
+    let zero_period = std::panic::catch_unwind(AssertUnwindSafe(|| {
+        drop(runtime.interval(Duration::ZERO));
+    }));
+    assert!(zero_period.is_err());
+}
+
+/// Checks that ticks missed by a busy consumer are handled according to
+/// [`MissedTickBehavior`].
+pub async fn interval_missed_ticks(runtime: &impl TimeRuntime) {
+    let period = Duration::from_millis(20);
+    let busy = period * 7 / 2;
+
+    let mut interval = runtime.interval(period);
+    let start = interval.tick().await;
+    runtime.sleep(busy).await;
+    assert_eq!(interval.tick().await, start + period);
+    assert_eq!(interval.tick().await, start + period * 2);
+    assert_eq!(interval.tick().await, start + period * 3);
+
+    let mut interval = runtime.interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    let start = interval.tick().await;
+    runtime.sleep(busy).await;
+    assert_eq!(interval.tick().await, start + period);
+    assert!(interval.tick().await >= start + busy + period);
+
+    let mut interval = runtime.interval(period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let start = interval.tick().await;
+    runtime.sleep(busy).await;
+    assert_eq!(interval.tick().await, start + period);
+    // How many ticks are skipped depends on how late the runtime wakes up, the schedule is kept.
+    let skipped = interval.tick().await.duration_since(start);
+    assert!(skipped > busy);
+    assert_eq!(skipped.as_nanos().checked_rem(period.as_nanos()), Some(0));
+}
+
+/// Checks that a delay queue returns items in the order of their deadlines, not before them.
+pub async fn delay_queue_expires_in_order(runtime: &impl TimeRuntime) {
+    let mut queue = DelayQueue::new(runtime);
+    let start = runtime.now();
+    for millis in [60, 20, 40] {
+        queue.insert(millis, Duration::from_millis(millis));
+    }
+    assert_eq!(queue.len(), 3);
+
+    let mut expired = Vec::new();
+    while let Some(item) = queue.next().await {
+        assert!(runtime.now() >= item.deadline());
+        expired.push(item.into_inner());
+    }
+    assert_eq!(expired, [20, 40, 60]);
+    assert!(runtime.now().duration_since(start) >= Duration::from_millis(60));
+}
+
+/// Checks that items of a delay queue can be removed and their deadlines can be moved, and that
+/// timeouts overflowing the clock saturate.
+pub async fn delay_queue_remove_and_reset(runtime: &impl TimeRuntime) {
+    let mut queue = DelayQueue::new(runtime);
+    let first = queue.insert(1i32, Duration::from_millis(20));
+    let second = queue.insert(2i32, Duration::from_millis(40));
+    let third = queue.insert(3i32, Duration::MAX);
+
+    assert_eq!(queue.remove(second), Some(2i32));
+    assert_eq!(queue.remove(second), None);
+    assert!(queue.reset(third, Duration::from_millis(10)));
+
+    let expired = queue.next().await.unwrap();
+    assert_eq!((expired.key(), expired.into_inner()), (third, 3i32));
+    assert!(!queue.reset(third, Duration::from_millis(10)));
+
+    assert!(queue.reset(first, Duration::MAX));
+    let deadline = runtime.now() + Duration::from_millis(10);
+    assert!(queue.reset_at(first, deadline));
+    assert_eq!(queue.deadline(first), Some(deadline));
+    let expired = queue.next().await.unwrap();
+    assert_eq!((expired.key(), expired.deadline()), (first, deadline));
+
+    assert!(queue.is_empty());
+    assert!(queue.next().await.is_none());
+}
+
+/// Checks that inserting an item with an earlier deadline into a delay queue that is already
+/// waiting wakes it in time.
+pub async fn delay_queue_insert_earlier(runtime: &impl TimeRuntime) {
+    let mut queue = DelayQueue::new(runtime);
+    let start = runtime.now();
+    queue.insert(1i32, Duration::from_secs(30));
+    assert!(futures::poll!(queue.next()).is_pending());
+
+    queue.insert(2i32, Duration::from_millis(10));
+    assert_eq!(queue.next().await.unwrap().into_inner(), 2i32);
+    assert!(runtime.now().duration_since(start) < Duration::from_secs(30));
+}
+
+/// Checks that an operation is retried with exponentially growing delays until it succeeds.
+pub async fn retry_until_success(runtime: &impl TimeRuntime) {
+    let start = runtime.now();
+    let mut attempts = 0i32;
+    let output = RetryBuilder::new()
+        .initial_delay(Duration::from_millis(10))
+        .jitter(false)
+        .run(runtime, || {
+            attempts += 1i32;
+            let attempt = attempts;
+            async move {
+                if attempt < 3i32 {
+                    Err(attempt)
+                } else {
+                    Ok(attempt)
+                }
+            }
+        })
+        .await;
+
+    assert_eq!(output, Ok(3i32));
+    assert!(runtime.now().duration_since(start) >= Duration::from_millis(30));
+}
+
+/// Checks that retrying stops once the maximum number of attempts is reached or an error is not
+/// retryable, returning the last error.
+pub async fn retry_gives_up(runtime: &impl TimeRuntime) {
+    let mut builder = RetryBuilder::new();
+    builder
+        .initial_delay(Duration::from_millis(1))
+        .max_attempts(3);
+
+    let mut attempts = 0i32;
+    let output: Result<(), i32> = builder
+        .run(runtime, || {
+            attempts += 1i32;
+            futures::future::ready(Err(attempts))
+        })
+        .await;
+    assert_eq!(output, Err(3i32));
+
+    let mut attempts = 0i32;
+    let output: Result<(), i32> = builder
+        .run_if(
+            runtime,
+            || {
+                attempts += 1i32;
+                futures::future::ready(Err(attempts))
+            },
+            |&err| err < 2i32,
+        )
+        .await;
+    assert_eq!(output, Err(2i32));
+}
+
+/// Checks that no retry is made past the overall time limit, even if its delay overflows the
+/// clock.
+pub async fn retry_timeout(runtime: &impl TimeRuntime) {
+    let start = runtime.now();
+    let mut attempts = 0i32;
+    let output: Result<(), i32> = RetryBuilder::new()
+        .initial_delay(Duration::from_millis(100))
+        .multiplier(1)
+        .jitter(false)
+        .timeout(Duration::from_millis(250))
+        .run(runtime, || {
+            attempts += 1i32;
+            futures::future::ready(Err(attempts))
+        })
+        .await;
+
+    // Retries are due at 100ms and 200ms, the limit leaves room for late timers.
+    assert_eq!(output, Err(3i32));
+    assert!(runtime.now().duration_since(start) < Duration::from_millis(250));
+
+    // A delay overflowing the clock ends past any limit.
+    let output: Result<(), i32> = RetryBuilder::new()
+        .initial_delay(Duration::MAX)
+        .max_delay(Duration::MAX)
+        .jitter(false)
+        .timeout(Duration::from_secs(30))
+        .run(runtime, || futures::future::ready(Err(1i32)))
+        .await;
+    assert_eq!(output, Err(1i32));
+}
+
+/// Checks that rate limiters hand out a burst of permits at once and then wait for a steady
+/// rate, including after borrowing permits beyond capacity.
+pub async fn rate_limiters(runtime: &(impl TimeRuntime + Sync)) {
+    async fn check(runtime: &(impl TimeRuntime + Sync), limiter: impl RateLimiter) {
+        let interval = Duration::from_millis(10);
+        let start = runtime.now();
+        for _ in 0i32..3i32 {
+            assert!(limiter.try_acquire(runtime, 1));
+        }
+        assert!(!limiter.try_acquire(runtime, 1));
+
+        limiter.acquire(runtime, 1).await;
+        assert!(runtime.now().duration_since(start) >= interval);
+
+        let start = runtime.now();
+        limiter.acquire(runtime, 5).await;
+        limiter.acquire(runtime, 1).await;
+        assert!(runtime.now().duration_since(start) >= interval * 5);
+    }
+
+    check(runtime, TokenBucket::new(100, Duration::from_secs(1), 3)).await;
+    check(runtime, Gcra::new(100, Duration::from_secs(1), 3)).await;
+}
+
+/// Checks that a throttled I/O object transfers data intact no faster than its byte rate.
+pub async fn throttled_io(runtime: &impl TimeRuntime) {
+    let data: Vec<u8> = (0u8..50u8).collect();
+    let limiter = TokenBucket::new(1000, Duration::from_secs(1), 10);
+    // The last chunk of 10 bytes is charged after it's transferred.
+    let min_duration = Duration::from_millis(30);
+
+    let start = runtime.now();
+    let mut writer = Throttled::new(runtime, &limiter, Cursor::new(Vec::new()));
+    writer.write_all(&data).await.unwrap();
+    assert!(runtime.now().duration_since(start) >= min_duration);
+    assert_eq!(writer.into_inner().into_inner(), data);
+
+    let limiter = Gcra::new(1000, Duration::from_secs(1), 10);
+    let start = runtime.now();
+    let mut reader = Throttled::new(runtime, &limiter, Cursor::new(data.clone()));
+    let mut read = Vec::new();
+    reader.read_to_end(&mut read).await.unwrap();
+    assert!(runtime.now().duration_since(start) >= min_duration);
+    assert_eq!(read, data);
+}
+
+/// Checks that a recurring job runs on its schedule until it's cancelled through its handle.
+pub async fn scheduled_job<RT>(runtime: &RT)
+where
+    RT: TaskRuntime + TimeRuntime + Clone + 'static,
+{
+    let period = Duration::from_millis(20);
+    let (sender, mut receiver) = mpsc::unbounded();
+    let start = runtime.now();
+    let handle = JobBuilder::new(Schedule::Every(period)).spawn(runtime, move || {
+        let sender = sender.clone();
+        async move { drop(sender.unbounded_send(())) }
+    });
+
+    for _ in 0i32..3i32 {
+        receiver.next().await.unwrap();
+    }
+    assert!(runtime.now().duration_since(start) >= period * 3);
+
+    handle.cancel();
+    assert!(handle.await.unwrap_err().is_cancelled());
+    // The job is dropped together with the last sender.
+    while receiver.next().await.is_some() {}
+}
+
+/// Checks that runs of a recurring job that are due while a previous run is in progress are
+/// handled according to [`OverlapPolicy`].
+///
+/// Only the order of runs is checked since real clocks can't bound when runs start. Exact
+/// numbers of runs are checked on a simulated clock by `arta-sim`.
+pub async fn scheduled_job_overlap<RT>(runtime: &RT)
+where
+    RT: TaskRuntime + TimeRuntime + Clone + 'static,
+{
+    /// Spawns a job whose runs are blocked until the returned gate is opened, the returned
+    /// receiver is notified of every started run.
+    fn spawn_blocked<RT>(
+        runtime: &RT,
+        overlap: OverlapPolicy,
+    ) -> (
+        JobHandle<RT>,
+        oneshot::Sender<()>,
+        mpsc::UnboundedReceiver<()>,
+    )
+    where
+        RT: TaskRuntime + TimeRuntime + Clone + 'static,
+    {
+        let (open, gate) = oneshot::channel::<()>();
+        let gate = gate.shared();
+        let (sender, receiver) = mpsc::unbounded();
+        let handle = JobBuilder::new(Schedule::Every(PERIOD))
+            .overlap(overlap)
+            .spawn(runtime, move || {
+                drop(sender.unbounded_send(()));
+                let gate = gate.clone();
+                async move { gate.await.unwrap() }
+            });
+        (handle, open, receiver)
+    }
+
+    const PERIOD: Duration = Duration::from_millis(20);
+    const RUN_TIMEOUT: Duration = Duration::from_secs(30);
+
+    // Runs due while the first one is blocked are dropped, the job keeps running afterwards.
+    let (handle, open, mut started) = spawn_blocked(runtime, OverlapPolicy::Skip);
+    started.next().await.unwrap();
+    runtime.sleep(PERIOD * 3).await;
+    started.try_next().unwrap_err();
+    open.send(()).unwrap();
+    runtime.timeout(RUN_TIMEOUT, started.next()).await.unwrap();
+    handle.cancel();
+    assert!(handle.await.unwrap_err().is_cancelled());
+
+    // Runs due while the first one is blocked start one after another once it completes.
+    let (handle, open, mut started) = spawn_blocked(runtime, OverlapPolicy::Queue);
+    started.next().await.unwrap();
+    runtime.sleep(PERIOD * 3).await;
+    started.try_next().unwrap_err();
+    open.send(()).unwrap();
+    for _ in 0i32..2i32 {
+        runtime.timeout(RUN_TIMEOUT, started.next()).await.unwrap();
+    }
+    handle.cancel();
+    assert!(handle.await.unwrap_err().is_cancelled());
+
+    // Runs start while the first one is still blocked.
+    let (handle, _open, mut started) = spawn_blocked(runtime, OverlapPolicy::Concurrent);
+    for _ in 0i32..3i32 {
+        runtime.timeout(RUN_TIMEOUT, started.next()).await.unwrap();
+    }
+    handle.cancel();
+    assert!(handle.await.unwrap_err().is_cancelled());
+}
@@ -0,0 +1,196 @@
+//! Checks of [`UnixNetRuntime`] implementations.
+//!
+//! Every check binds its sockets inside its own directory created in [`std::env::temp_dir`] and
+//! removes it on success.
+
+use arta::net::{
+    RuntimeUnixDatagram as _, RuntimeUnixListener as _, RuntimeUnixStream as _, UnixNetRuntime,
+};
+use futures::{AsyncReadExt as _, AsyncWriteExt as _};
+use std::{
+    io::ErrorKind,
+    path::PathBuf,
+    pin::pin,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static NEXT_DIR_ID: AtomicUsize = AtomicUsize::new(0);
+
+fn test_dir(name: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!(
+        "arta-conformance-{}-{}-{name}",
+        std::process::id(),
+        NEXT_DIR_ID.fetch_add(1, Ordering::Relaxed)
+    ));
+    std::fs::create_dir_all(&path).unwrap();
+    path
+}
+
+async fn write_and_read<R: UnixNetRuntime>(first: R::UnixStream, second: R::UnixStream) {
+    let (mut first, mut second) = (pin!(first), pin!(second));
+
+    first.write_all(b"ping").await.unwrap();
+    let mut buf = [0; 4];
+    second.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"ping");
+
+    second.write_all(b"pong").await.unwrap();
+    first.read_exact(&mut buf).await.unwrap();
+    assert_eq!(&buf, b"pong");
+
+    first.close().await.unwrap();
+    let mut rest = Vec::new();
+    second.read_to_end(&mut rest).await.unwrap();
+    assert!(rest.is_empty());
+}
+
+/// Checks that a stream connects to a listener bound to a path and exchanges data with the
+/// accepted one.
+pub async fn unix_stream_connect_and_accept<R: UnixNetRuntime>(runtime: &R) {
+    let dir = test_dir("unix_stream_connect_and_accept");
+    let path = dir.join("socket");
+
+    let listener = R::UnixListener::bind(runtime, &path).unwrap();
+    assert_eq!(listener.local_addr().unwrap().as_pathname(), Some(&*path));
+
+    let result = R::UnixListener::bind(runtime, &path);
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(ErrorKind::AddrInUse)
+    );
+
+    let (client, accepted) =
+        futures::join!(R::UnixStream::connect(runtime, &path), listener.accept());
+    let (client, (server, _)) = (client.unwrap(), accepted.unwrap());
+    assert_eq!(client.peer_addr().unwrap().as_pathname(), Some(&*path));
+    assert_eq!(server.local_addr().unwrap().as_pathname(), Some(&*path));
+    assert!(client.take_error().unwrap().is_none());
+
+    write_and_read::<R>(client, server).await;
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Checks that connecting to a path nobody listens on fails.
+pub async fn unix_stream_connect_missing_fails<R: UnixNetRuntime>(runtime: &R) {
+    let dir = test_dir("unix_stream_connect_missing_fails");
+
+    let result = R::UnixStream::connect(runtime, dir.join("socket")).await;
+    assert_eq!(
+        result.err().map(|err| err.kind()),
+        Some(ErrorKind::NotFound)
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Checks that both ends of a stream pair are connected to each other and are unnamed.
+pub async fn unix_stream_pair<R: UnixNetRuntime>(runtime: &R) {
+    let (first, second) = R::UnixStream::pair(runtime).unwrap();
+    assert!(first.local_addr().unwrap().is_unnamed());
+    assert!(second.peer_addr().unwrap().is_unnamed());
+
+    write_and_read::<R>(first, second).await;
+}
+
+/// Checks that datagrams sent to a path or to a socket address are received along with the
+/// sender's address.
+pub async fn unix_datagram_send_to_and_recv_from<R: UnixNetRuntime>(runtime: &R) {
+    let dir = test_dir("unix_datagram_send_to_and_recv_from");
+    let (first_path, second_path) = (dir.join("first"), dir.join("second"));
+
+    let first = R::UnixDatagram::bind(runtime, &first_path).unwrap();
+    let second = R::UnixDatagram::bind(runtime, &second_path).unwrap();
+    assert_eq!(
+        first.local_addr().unwrap().as_pathname(),
+        Some(&*first_path)
+    );
+
+    assert_eq!(first.send_to(b"ping", &second_path).await.unwrap(), 4);
+    let mut buf = [0; 8];
+    let (len, addr) = second.recv_from(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"ping");
+    assert_eq!(addr.as_pathname(), Some(&*first_path));
+
+    assert_eq!(second.send_to_addr(b"pong", &addr).await.unwrap(), 4);
+    let (len, addr) = first.recv_from(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"pong");
+    assert_eq!(addr.as_pathname(), Some(&*second_path));
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Checks that connected and paired datagram sockets exchange data with `send` and `recv`.
+pub async fn unix_datagram_connect_send_and_recv<R: UnixNetRuntime>(runtime: &R) {
+    let dir = test_dir("unix_datagram_connect_send_and_recv");
+    let path = dir.join("socket");
+
+    let server = R::UnixDatagram::bind(runtime, &path).unwrap();
+    let client = R::UnixDatagram::unbound(runtime).unwrap();
+    assert!(client.local_addr().unwrap().is_unnamed());
+    client.connect(&path).unwrap();
+    assert_eq!(client.peer_addr().unwrap().as_pathname(), Some(&*path));
+
+    assert_eq!(client.send(b"ping").await.unwrap(), 4);
+    let mut buf = [0; 8];
+    let len = server.recv(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"ping");
+
+    let (first, second) = R::UnixDatagram::pair(runtime).unwrap();
+    assert_eq!(first.send(b"pong").await.unwrap(), 4);
+    let len = second.recv(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"pong");
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+/// Checks that sockets are bound, connected and send datagrams to addresses in the abstract
+/// namespace.
+#[cfg(any(target_os = "linux", doc))]
+#[cfg_attr(docsrs, doc(cfg(target_os = "linux")))]
+pub async fn unix_abstract_namespace<R: UnixNetRuntime>(runtime: &R) {
+    use std::os::{linux::net::SocketAddrExt as _, unix::net::SocketAddr};
+
+    let name = format!(
+        "arta-conformance-{}-{}-abstract",
+        std::process::id(),
+        NEXT_DIR_ID.fetch_add(1, Ordering::Relaxed)
+    );
+    let addr = SocketAddr::from_abstract_name(&name).unwrap();
+
+    let listener = R::UnixListener::bind_addr(runtime, &addr).unwrap();
+    assert_eq!(
+        listener.local_addr().unwrap().as_abstract_name(),
+        Some(name.as_bytes())
+    );
+
+    let (client, accepted) = futures::join!(
+        R::UnixStream::connect_addr(runtime, &addr),
+        listener.accept()
+    );
+    let (client, (server, _)) = (client.unwrap(), accepted.unwrap());
+    assert_eq!(
+        client.peer_addr().unwrap().as_abstract_name(),
+        Some(name.as_bytes())
+    );
+    write_and_read::<R>(client, server).await;
+
+    let datagram_name = format!("{name}-datagram");
+    let datagram_addr = SocketAddr::from_abstract_name(&datagram_name).unwrap();
+    let server = R::UnixDatagram::bind_addr(runtime, &datagram_addr).unwrap();
+    let client = R::UnixDatagram::unbound(runtime).unwrap();
+    client.connect_addr(&datagram_addr).unwrap();
+
+    assert_eq!(client.send(b"ping").await.unwrap(), 4);
+    let mut buf = [0; 8];
+    let len = server.recv(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"ping");
+
+    let sender = R::UnixDatagram::unbound(runtime).unwrap();
+    assert_eq!(
+        sender.send_to_addr(b"pong", &datagram_addr).await.unwrap(),
+        4
+    );
+    let len = server.recv(&mut buf).await.unwrap();
+    assert_eq!(&buf[..len], b"pong");
+}
@@ -0,0 +1,51 @@
+use crate::tree::{Inode, Tree};
+use arta::fs::{FileType, Metadata, RuntimeDirEntry};
+use futures::prelude::Future;
+use std::{
+    ffi::OsString,
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+/// In-memory [`RuntimeDirEntry`] implementation.
+pub struct MemFsDirEntry {
+    pub(crate) tree: Arc<Mutex<Tree>>,
+    pub(crate) ino: u64,
+    pub(crate) file_name: OsString,
+    pub(crate) path: PathBuf,
+}
+
+impl RuntimeDirEntry for MemFsDirEntry {
+    #[cfg(unix)]
+    fn ino(&self) -> u64 {
+        self.ino
+    }
+
+    fn file_name(&self) -> OsString {
+        self.file_name.clone()
+    }
+
+    fn file_type(&self) -> impl Future<Output = std::io::Result<FileType>> + Send {
+        std::future::ready(
+            self.tree
+                .lock()
+                .unwrap()
+                .inode(self.ino)
+                .map(Inode::file_type),
+        )
+    }
+
+    fn metadata(&self) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        std::future::ready(
+            self.tree
+                .lock()
+                .unwrap()
+                .inode(self.ino)
+                .map(Inode::metadata),
+        )
+    }
+
+    fn path(&self) -> PathBuf {
+        self.path.clone()
+    }
+}
@@ -0,0 +1,229 @@
+use crate::{
+    tree::{Node, Tree},
+    MemFsRuntime,
+};
+use arta::fs::{Metadata, OpenOptions, Permissions, RuntimeFile};
+use futures::{AsyncRead, AsyncSeek, AsyncWrite};
+use std::{
+    future::Future,
+    path::Path,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll},
+    time::SystemTime,
+};
+
+/// In-memory [`RuntimeFile`] implementation.
+///
+/// Files have no OS descriptor, so they don't implement
+/// [`RuntimeOsFile`](arta::fs::RuntimeOsFile).
+pub struct MemFsFile {
+    tree: Arc<Mutex<Tree>>,
+    ino: u64,
+    position: u64,
+    is_readable: bool,
+    is_writable: bool,
+    is_append: bool,
+}
+
+impl MemFsFile {
+    fn tree(&self) -> MutexGuard<'_, Tree> {
+        self.tree.lock().unwrap()
+    }
+
+    fn not_writable() -> std::io::Error {
+        std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            "file is not opened for writing",
+        )
+    }
+
+    fn metadata_priv(&self) -> std::io::Result<Metadata> {
+        Ok(self.tree().inode(self.ino)?.metadata())
+    }
+
+    fn set_len_priv(&self, size: u64) -> std::io::Result<()> {
+        if !self.is_writable {
+            return Err(Self::not_writable());
+        }
+
+        let size = usize::try_from(size)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?;
+        let mut tree = self.tree();
+        let inode = tree.inode_mut(self.ino)?;
+        let Node::File(content) = &mut inode.node else {
+            return Err(std::io::ErrorKind::IsADirectory.into());
+        };
+        content.resize(size, 0);
+        inode.touch();
+
+        Ok(())
+    }
+}
+
+impl Drop for MemFsFile {
+    fn drop(&mut self) {
+        self.tree().release(self.ino);
+    }
+}
+
+impl AsyncRead for MemFsFile {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        if !this.is_readable {
+            return Poll::Ready(Err(std::io::Error::new(
+                std::io::ErrorKind::PermissionDenied,
+                "file is not opened for reading",
+            )));
+        }
+
+        let mut tree = this.tree.lock().unwrap();
+        let inode = tree.inode_mut(this.ino)?;
+        let Node::File(content) = &inode.node else {
+            return Poll::Ready(Err(std::io::ErrorKind::IsADirectory.into()));
+        };
+
+        let start = usize::try_from(this.position)
+            .unwrap_or(usize::MAX)
+            .min(content.len());
+        let len = buf.len().min(content.len() - start);
+        buf[..len].copy_from_slice(&content[start..start + len]);
+        inode.accessed = SystemTime::now();
+        this.position += u64::try_from(len).unwrap_or(u64::MAX);
+
+        Poll::Ready(Ok(len))
+    }
+}
+
+impl AsyncWrite for MemFsFile {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        if !this.is_writable {
+            return Poll::Ready(Err(Self::not_writable()));
+        }
+
+        let mut tree = this.tree.lock().unwrap();
+        let inode = tree.inode_mut(this.ino)?;
+        let Node::File(content) = &mut inode.node else {
+            return Poll::Ready(Err(std::io::ErrorKind::IsADirectory.into()));
+        };
+
+        let start = if this.is_append {
+            content.len()
+        } else {
+            usize::try_from(this.position)
+                .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidInput, err))?
+        };
+        let end = start + buf.len();
+        if content.len() < end {
+            content.resize(end, 0);
+        }
+        content[start..end].copy_from_slice(buf);
+        inode.touch();
+        this.position = u64::try_from(end).unwrap_or(u64::MAX);
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl AsyncSeek for MemFsFile {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        pos: std::io::SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        let this = self.get_mut();
+        let (base, offset) = match pos {
+            std::io::SeekFrom::Start(position) => {
+                this.position = position;
+                return Poll::Ready(Ok(position));
+            }
+            std::io::SeekFrom::End(offset) => (this.metadata_priv()?.len(), offset),
+            std::io::SeekFrom::Current(offset) => (this.position, offset),
+        };
+
+        if let Some(position) = base.checked_add_signed(offset) {
+            this.position = position;
+            Poll::Ready(Ok(position))
+        } else {
+            Poll::Ready(Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            )))
+        }
+    }
+}
+
+impl RuntimeFile for MemFsFile {
+    type Runtime = MemFsRuntime;
+
+    fn open(
+        runtime: &Self::Runtime,
+        open_options: &OpenOptions,
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        let open = || {
+            let mut tree = runtime.tree();
+            let ino = tree.open(path.as_ref(), open_options)?;
+            tree.acquire(ino)?;
+
+            Ok(Self {
+                tree: Arc::clone(&runtime.tree),
+                ino,
+                position: 0,
+                is_readable: open_options.is_read(),
+                is_writable: open_options.is_write(),
+                is_append: open_options.is_append(),
+            })
+        };
+
+        std::future::ready(open())
+    }
+
+    fn set_len(&self, size: u64) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.set_len_priv(size))
+    }
+
+    fn metadata(&self) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        std::future::ready(self.metadata_priv())
+    }
+
+    fn sync_all(&self) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(Ok(()))
+    }
+
+    fn sync_data(&self) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(Ok(()))
+    }
+
+    fn set_permissions(
+        &self,
+        permissions: Permissions,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        let set_permissions = || {
+            self.tree().inode_mut(self.ino)?.permissions = permissions;
+            Ok(())
+        };
+
+        std::future::ready(set_permissions())
+    }
+}
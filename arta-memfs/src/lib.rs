@@ -0,0 +1,299 @@
+//! # arta-memfs
+//! Arta-memfs is a crate that provides an in-memory implementation of `arta` filesystem
+//! abstractions.
+//!
+//! Files, directories, symbolic links and hard links live entirely in memory, so tests of code
+//! generic over [`FSRuntime`] don't touch the disk and don't interfere with each other.
+//!
+//! ## Installation
+//! Add a following dependencies to your `Cargo.toml`:
+//! ```toml
+//! [dev-dependencies]
+//! arta-memfs = "0.3.0"
+//! arta = "0.3.0"
+//! ```
+//! ## Usage
+//!
+//! ```ignore
+//! #[test]
+//! fn config_is_saved() {
+//!     futures::executor::block_on(async {
+//!         // Every `MemFsRuntime` has its own empty filesystem.
+//!         let runtime = MemFsRuntime::new();
+//!         save_config(&runtime, "/etc/app.toml").await.unwrap();
+//!         assert!(runtime.metadata("/etc/app.toml").await.unwrap().is_file());
+//!     });
+//! }
+//! ```
+
+#![deny(
+    warnings,
+    clippy::correctness,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf,
+    clippy::style,
+    clippy::pedantic,
+    clippy::restriction,
+    clippy::cargo
+)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::blanket_clippy_restriction_lints,
+    clippy::missing_inline_in_public_items,
+    clippy::single_char_lifetime_names,
+    clippy::implicit_return,
+    clippy::pattern_type_mismatch,
+    clippy::question_mark_used,
+    clippy::shadow_reuse,
+    clippy::shadow_same,
+    clippy::pub_with_shorthand,
+    clippy::absolute_paths,
+    clippy::exhaustive_enums,
+    clippy::exhaustive_structs,
+    clippy::multiple_crate_versions,
+    clippy::missing_docs_in_private_items,
+    clippy::pub_use,
+    clippy::infinite_loop, // Allowed because of bug: https://github.com/rust-lang/rust-clippy/issues/12338
+    clippy::unseparated_literal_suffix,
+    clippy::self_named_module_files,
+    clippy::big_endian_bytes,
+    clippy::single_call_fn,
+    clippy::missing_trait_methods,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::print_stdout,
+    clippy::shadow_unrelated,
+    clippy::undocumented_unsafe_blocks,
+    clippy::as_conversions,
+    clippy::ref_as_ptr,
+    clippy::doc_markdown,
+    clippy::unwrap_used,
+    clippy::unreachable,
+    clippy::impl_trait_in_params,
+    clippy::missing_errors_doc,
+    clippy::std_instead_of_core,
+    clippy::std_instead_of_alloc,
+    clippy::alloc_instead_of_core,
+    clippy::min_ident_chars
+)]
+#![forbid(unreachable_pub, missing_docs)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod dir_entry;
+mod file;
+mod tree;
+
+pub use dir_entry::*;
+pub use file::*;
+
+use arta::fs::{FSRuntime, Metadata, Permissions};
+use futures::prelude::Stream;
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, MutexGuard},
+};
+use tree::{Inode, Tree};
+
+/// In-memory filesystem.
+///
+/// Cloned handles share the same filesystem. Relative paths are resolved from the root
+/// directory and all operations complete immediately, so the runtime can be used from any
+/// executor.
+#[derive(Clone)]
+pub struct MemFsRuntime {
+    tree: Arc<Mutex<Tree>>,
+}
+
+impl MemFsRuntime {
+    /// Creates an empty filesystem containing only the root directory.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tree: Arc::new(Mutex::new(Tree::new())),
+        }
+    }
+
+    fn tree(&self) -> MutexGuard<'_, Tree> {
+        self.tree.lock().unwrap()
+    }
+}
+
+impl Default for MemFsRuntime {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FSRuntime for MemFsRuntime {
+    type File = MemFsFile;
+    type DirEntry = MemFsDirEntry;
+
+    fn canonicalize(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<PathBuf>> + Send {
+        std::future::ready(
+            self.tree()
+                .walk(path.as_ref(), true)
+                .map(|walk| walk.path()),
+        )
+    }
+
+    fn copy(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<u64>> + Send {
+        std::future::ready(self.tree().copy(from.as_ref(), to.as_ref()))
+    }
+
+    fn create_dir(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().create_dir(path.as_ref()))
+    }
+
+    fn create_dir_all(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().create_dir_all(path.as_ref()))
+    }
+
+    fn remove_dir(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().remove_dir(path.as_ref()))
+    }
+
+    fn remove_dir_all(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().remove_dir_all(path.as_ref()))
+    }
+
+    fn read_dir(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<
+        Output = std::io::Result<impl Stream<Item = std::io::Result<Self::DirEntry>> + Send>,
+    > + Send {
+        let path = path.as_ref();
+        let entries = self.tree().read_dir(path).map(|entries| {
+            let entries: Vec<_> = entries
+                .into_iter()
+                .map(|(file_name, ino)| {
+                    Ok(MemFsDirEntry {
+                        tree: Arc::clone(&self.tree),
+                        ino,
+                        path: path.join(&file_name),
+                        file_name,
+                    })
+                })
+                .collect();
+
+            futures::stream::iter(entries)
+        });
+
+        std::future::ready(entries)
+    }
+
+    fn read_link(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<PathBuf>> + Send {
+        std::future::ready(self.tree().read_link(path.as_ref()))
+    }
+
+    #[cfg(unix)]
+    fn symlink(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().symlink(from.as_ref(), to.as_ref()))
+    }
+
+    #[cfg(windows)]
+    fn symlink_dir(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().symlink(from.as_ref(), to.as_ref()))
+    }
+
+    #[cfg(windows)]
+    fn symlink_file(
+        &self,
+        from: impl AsRef<Path>,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().symlink(from.as_ref(), to.as_ref()))
+    }
+
+    fn hard_link(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().hard_link(from.as_ref(), to.as_ref()))
+    }
+
+    fn metadata(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        let tree = self.tree();
+        std::future::ready(
+            tree.walk(path.as_ref(), true)
+                .and_then(|walk| tree.inode(walk.ino))
+                .map(Inode::metadata),
+        )
+    }
+
+    fn remove_file(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().remove_file(path.as_ref()))
+    }
+
+    fn rename(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        std::future::ready(self.tree().rename(from.as_ref(), to.as_ref()))
+    }
+
+    fn set_permissions(
+        &self,
+        path: impl AsRef<Path> + Send,
+        permissions: Permissions,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        let mut tree = self.tree();
+        std::future::ready(
+            tree.walk(path.as_ref(), true)
+                .and_then(|walk| tree.inode_mut(walk.ino))
+                .map(|inode| inode.permissions = permissions),
+        )
+    }
+
+    fn symlink_metadata(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        let tree = self.tree();
+        std::future::ready(
+            tree.walk(path.as_ref(), false)
+                .and_then(|walk| tree.inode(walk.ino))
+                .map(Inode::metadata),
+        )
+    }
+}
@@ -0,0 +1,587 @@
+use arta::fs::{FileType, Metadata, OpenOptions, Permissions};
+use cfg_if::cfg_if;
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    ffi::OsString,
+    path::{Component, Path, PathBuf},
+    time::SystemTime,
+};
+
+const ROOT_INO: u64 = 1;
+/// Same limit as Linux uses before failing with `ELOOP`.
+const MAX_SYMLINK_HOPS: usize = 40;
+
+cfg_if! {
+    if #[cfg(unix)] {
+        use std::os::unix::fs::PermissionsExt as _;
+
+        fn default_permissions(file_type: FileType) -> Permissions {
+            Permissions::from_mode(match file_type {
+                FileType::Dir => 0o755,
+                FileType::Symlink => 0o777,
+                FileType::File | FileType::Other => 0o644,
+            })
+        }
+
+        fn create_permissions(open_options: &OpenOptions) -> Permissions {
+            // Emulates the common umask of `0o022`.
+            Permissions::from_mode(open_options.unix_mode() & !0o022)
+        }
+    } else {
+        const fn default_permissions(_file_type: FileType) -> Permissions {
+            Permissions::new(false)
+        }
+
+        const fn create_permissions(_open_options: &OpenOptions) -> Permissions {
+            default_permissions(FileType::File)
+        }
+    }
+}
+
+pub(crate) enum Node {
+    File(Vec<u8>),
+    Dir(BTreeMap<OsString, u64>),
+    Symlink(PathBuf),
+}
+
+pub(crate) struct Inode {
+    pub(crate) node: Node,
+    pub(crate) permissions: Permissions,
+    pub(crate) created: SystemTime,
+    pub(crate) modified: SystemTime,
+    pub(crate) accessed: SystemTime,
+    /// Number of directory entries pointing at this inode.
+    links: u64,
+    /// Number of open files pointing at this inode.
+    handles: u64,
+}
+
+impl Inode {
+    fn new(node: Node, permissions: Permissions) -> Self {
+        let now = SystemTime::now();
+        Self {
+            node,
+            permissions,
+            created: now,
+            modified: now,
+            accessed: now,
+            links: 0,
+            handles: 0,
+        }
+    }
+
+    pub(crate) const fn file_type(&self) -> FileType {
+        match self.node {
+            Node::File(_) => FileType::File,
+            Node::Dir(_) => FileType::Dir,
+            Node::Symlink(_) => FileType::Symlink,
+        }
+    }
+
+    pub(crate) fn metadata(&self) -> Metadata {
+        let len = match &self.node {
+            Node::File(content) => content.len(),
+            Node::Dir(_) => 0,
+            Node::Symlink(target) => target.as_os_str().len(),
+        };
+
+        let mut metadata = Metadata::new(
+            self.file_type(),
+            u64::try_from(len).unwrap_or(u64::MAX),
+            self.permissions,
+        );
+        metadata
+            .set_created(self.created)
+            .set_modified(self.modified)
+            .set_accessed(self.accessed);
+        metadata
+    }
+
+    pub(crate) fn touch(&mut self) {
+        self.modified = SystemTime::now();
+    }
+}
+
+enum Step {
+    Root,
+    Parent,
+    Name(OsString),
+}
+
+fn path_steps(path: &Path) -> impl DoubleEndedIterator<Item = Step> + '_ {
+    path.components().filter_map(|component| match component {
+        Component::Prefix(_) | Component::RootDir => Some(Step::Root),
+        Component::CurDir => None,
+        Component::ParentDir => Some(Step::Parent),
+        Component::Normal(name) => Some(Step::Name(name.to_owned())),
+    })
+}
+
+/// Result of resolving a path: the inode it points at and the chain of directories leading to
+/// it.
+pub(crate) struct Walk {
+    pub(crate) ino: u64,
+    ancestors: Vec<u64>,
+    names: Vec<OsString>,
+}
+
+impl Walk {
+    const fn root() -> Self {
+        Self {
+            ino: ROOT_INO,
+            ancestors: Vec::new(),
+            names: Vec::new(),
+        }
+    }
+
+    fn push(&mut self, ino: u64, name: OsString) {
+        self.ancestors.push(self.ino);
+        self.names.push(name);
+        self.ino = ino;
+    }
+
+    fn pop(&mut self) {
+        if let Some(parent) = self.ancestors.pop() {
+            self.names.pop();
+            self.ino = parent;
+        }
+    }
+
+    /// Returns the canonical path of the resolved inode.
+    pub(crate) fn path(&self) -> PathBuf {
+        let mut path = PathBuf::from("/");
+        path.extend(&self.names);
+        path
+    }
+
+    fn contains(&self, ino: u64) -> bool {
+        self.ino == ino || self.ancestors.contains(&ino)
+    }
+}
+
+/// In-memory filesystem tree. Relative paths are resolved from the root directory.
+pub(crate) struct Tree {
+    inodes: HashMap<u64, Inode>,
+    next_ino: u64,
+}
+
+impl Tree {
+    pub(crate) fn new() -> Self {
+        let mut root = Inode::new(
+            Node::Dir(BTreeMap::new()),
+            default_permissions(FileType::Dir),
+        );
+        root.links = 1;
+
+        Self {
+            inodes: HashMap::from([(ROOT_INO, root)]),
+            next_ino: ROOT_INO + 1,
+        }
+    }
+
+    pub(crate) fn inode(&self, ino: u64) -> std::io::Result<&Inode> {
+        self.inodes
+            .get(&ino)
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into())
+    }
+
+    pub(crate) fn inode_mut(&mut self, ino: u64) -> std::io::Result<&mut Inode> {
+        self.inodes
+            .get_mut(&ino)
+            .ok_or_else(|| std::io::ErrorKind::NotFound.into())
+    }
+
+    fn dir(&self, ino: u64) -> std::io::Result<&BTreeMap<OsString, u64>> {
+        match &self.inode(ino)?.node {
+            Node::Dir(entries) => Ok(entries),
+            Node::File(_) | Node::Symlink(_) => Err(std::io::ErrorKind::NotADirectory.into()),
+        }
+    }
+
+    fn dir_mut(&mut self, ino: u64) -> std::io::Result<&mut BTreeMap<OsString, u64>> {
+        match &mut self.inode_mut(ino)?.node {
+            Node::Dir(entries) => Ok(entries),
+            Node::File(_) | Node::Symlink(_) => Err(std::io::ErrorKind::NotADirectory.into()),
+        }
+    }
+
+    fn lookup(&self, dir: u64, name: &OsString) -> std::io::Result<Option<u64>> {
+        Ok(self.dir(dir)?.get(name).copied())
+    }
+
+    fn walk_steps(
+        &self,
+        steps: impl Iterator<Item = Step>,
+        is_following_last: bool,
+    ) -> std::io::Result<Walk> {
+        let mut pending: VecDeque<Step> = steps.collect();
+        let mut walk = Walk::root();
+        let mut hops = 0usize;
+
+        while let Some(step) = pending.pop_front() {
+            match step {
+                Step::Root => walk = Walk::root(),
+                Step::Parent => walk.pop(),
+                Step::Name(name) => {
+                    let ino = self
+                        .lookup(walk.ino, &name)?
+                        .ok_or(std::io::ErrorKind::NotFound)?;
+
+                    if let Node::Symlink(target) = &self.inode(ino)?.node {
+                        if !pending.is_empty() || is_following_last {
+                            hops += 1;
+                            if hops > MAX_SYMLINK_HOPS {
+                                return Err(std::io::Error::other(
+                                    "too many levels of symbolic links",
+                                ));
+                            }
+
+                            for step in path_steps(target).rev() {
+                                pending.push_front(step);
+                            }
+                            continue;
+                        }
+                    }
+
+                    walk.push(ino, name);
+                }
+            }
+        }
+
+        Ok(walk)
+    }
+
+    /// Resolves a path, following a symlink in the last component only if `is_following_last`
+    /// is `true`.
+    pub(crate) fn walk(&self, path: &Path, is_following_last: bool) -> std::io::Result<Walk> {
+        if path.as_os_str().is_empty() {
+            return Err(std::io::ErrorKind::NotFound.into());
+        }
+
+        self.walk_steps(path_steps(path), is_following_last)
+    }
+
+    /// Resolves a parent directory of a path and returns it along with the last component.
+    pub(crate) fn walk_parent(&self, path: &Path) -> std::io::Result<(Walk, OsString)> {
+        let name = path.file_name().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "path doesn't end with a file name",
+            )
+        })?;
+        let parent = self.walk_steps(path.parent().map(path_steps).into_iter().flatten(), true)?;
+        self.dir(parent.ino)?;
+
+        Ok((parent, name.to_owned()))
+    }
+
+    fn insert(&mut self, node: Node, permissions: Permissions) -> u64 {
+        let ino = self.next_ino;
+        self.next_ino += 1;
+        self.inodes.insert(ino, Inode::new(node, permissions));
+
+        ino
+    }
+
+    fn link(&mut self, dir: u64, name: OsString, ino: u64) -> std::io::Result<()> {
+        self.dir_mut(dir)?.insert(name, ino);
+        self.inode_mut(dir)?.touch();
+        self.inode_mut(ino)?.links += 1;
+
+        Ok(())
+    }
+
+    fn unlink(&mut self, dir: u64, name: &OsString) -> std::io::Result<()> {
+        let ino = self
+            .dir_mut(dir)?
+            .remove(name)
+            .ok_or(std::io::ErrorKind::NotFound)?;
+        self.inode_mut(dir)?.touch();
+
+        let inode = self.inode_mut(ino)?;
+        inode.links -= 1;
+        if inode.links == 0 && inode.handles == 0 {
+            self.inodes.remove(&ino);
+        }
+
+        Ok(())
+    }
+
+    fn unlink_all(&mut self, dir: u64, name: &OsString) -> std::io::Result<()> {
+        if let Some(ino) = self.lookup(dir, name)? {
+            if let Node::Dir(entries) = &self.inode(ino)?.node {
+                let names: Vec<OsString> = entries.keys().cloned().collect();
+                for child in &names {
+                    self.unlink_all(ino, child)?;
+                }
+            }
+        }
+
+        self.unlink(dir, name)
+    }
+
+    /// Marks an inode as referenced by an open file, so it outlives its last link.
+    pub(crate) fn acquire(&mut self, ino: u64) -> std::io::Result<()> {
+        self.inode_mut(ino)?.handles += 1;
+        Ok(())
+    }
+
+    pub(crate) fn release(&mut self, ino: u64) {
+        if let Some(inode) = self.inodes.get_mut(&ino) {
+            inode.handles -= 1;
+            if inode.links == 0 && inode.handles == 0 {
+                self.inodes.remove(&ino);
+            }
+        }
+    }
+
+    /// Opens a file following semantics of [`std::fs::OpenOptions::open`] on Unix and returns
+    /// its inode.
+    pub(crate) fn open(&mut self, path: &Path, open_options: &OpenOptions) -> std::io::Result<u64> {
+        if !open_options.is_read() && !open_options.is_write() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "must specify at least one of read, write or append access",
+            ));
+        }
+        if !open_options.is_write()
+            && (open_options.is_truncate()
+                || open_options.is_create()
+                || open_options.is_create_new())
+        {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "creating or truncating a file requires write or append access",
+            ));
+        }
+        if open_options.is_append() && open_options.is_truncate() && !open_options.is_create_new() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "truncating a file in the append mode is not supported",
+            ));
+        }
+
+        let (ino, is_created) = if open_options.is_create() || open_options.is_create_new() {
+            let (parent, name) = self.walk_parent(path)?;
+            match self.lookup(parent.ino, &name)? {
+                Some(_) if open_options.is_create_new() => {
+                    return Err(std::io::ErrorKind::AlreadyExists.into());
+                }
+                Some(_) => (self.walk(path, true)?.ino, false),
+                None => {
+                    let ino = self.insert(Node::File(Vec::new()), create_permissions(open_options));
+                    self.link(parent.ino, name, ino)?;
+                    (ino, true)
+                }
+            }
+        } else {
+            (self.walk(path, true)?.ino, false)
+        };
+
+        let inode = self.inode_mut(ino)?;
+        if open_options.is_write() {
+            if inode.file_type().is_dir() {
+                return Err(std::io::ErrorKind::IsADirectory.into());
+            }
+            // Like on OS filesystems, the file that is created read-only can still be written
+            // through the handle that created it.
+            if inode.permissions.readonly() && !is_created {
+                return Err(std::io::ErrorKind::PermissionDenied.into());
+            }
+        }
+        if open_options.is_truncate() {
+            if let Node::File(content) = &mut inode.node {
+                content.clear();
+                inode.touch();
+            }
+        }
+
+        Ok(ino)
+    }
+
+    pub(crate) fn copy(&mut self, from: &Path, to: &Path) -> std::io::Result<u64> {
+        let source = self.inode(self.walk(from, true)?.ino)?;
+        let Node::File(content) = &source.node else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "the source path is neither a regular file nor a symlink to a regular file",
+            ));
+        };
+        let content = content.clone();
+        let permissions = source.permissions;
+
+        let ino = self.open(
+            to,
+            OpenOptions::new().write(true).create(true).truncate(true),
+        )?;
+        let destination = self.inode_mut(ino)?;
+        let len = u64::try_from(content.len()).unwrap_or(u64::MAX);
+        destination.node = Node::File(content);
+        destination.permissions = permissions;
+        destination.touch();
+
+        Ok(len)
+    }
+
+    pub(crate) fn create_dir(&mut self, path: &Path) -> std::io::Result<()> {
+        let (parent, name) = self.walk_parent(path)?;
+        if self.lookup(parent.ino, &name)?.is_some() {
+            return Err(std::io::ErrorKind::AlreadyExists.into());
+        }
+
+        let ino = self.insert(
+            Node::Dir(BTreeMap::new()),
+            default_permissions(FileType::Dir),
+        );
+        self.link(parent.ino, name, ino)
+    }
+
+    pub(crate) fn create_dir_all(&mut self, path: &Path) -> std::io::Result<()> {
+        let mut ancestors: Vec<&Path> = path.ancestors().collect();
+        ancestors.reverse();
+
+        for ancestor in ancestors {
+            if ancestor.file_name().is_none() {
+                continue;
+            }
+
+            match self.walk(ancestor, true) {
+                Ok(walk) => {
+                    self.dir(walk.ino)?;
+                }
+                Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                    self.create_dir(ancestor)?;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn remove_dir(&mut self, path: &Path) -> std::io::Result<()> {
+        let (parent, name) = self.walk_parent(path)?;
+        let ino = self
+            .lookup(parent.ino, &name)?
+            .ok_or(std::io::ErrorKind::NotFound)?;
+        if !self.dir(ino)?.is_empty() {
+            return Err(std::io::ErrorKind::DirectoryNotEmpty.into());
+        }
+
+        self.unlink(parent.ino, &name)
+    }
+
+    pub(crate) fn remove_dir_all(&mut self, path: &Path) -> std::io::Result<()> {
+        let (parent, name) = self.walk_parent(path)?;
+        let ino = self
+            .lookup(parent.ino, &name)?
+            .ok_or(std::io::ErrorKind::NotFound)?;
+        if self.inode(ino)?.file_type().is_file() {
+            return Err(std::io::ErrorKind::NotADirectory.into());
+        }
+
+        self.unlink_all(parent.ino, &name)
+    }
+
+    /// Returns names and inodes of entries in a directory.
+    pub(crate) fn read_dir(&mut self, path: &Path) -> std::io::Result<Vec<(OsString, u64)>> {
+        let ino = self.walk(path, true)?.ino;
+        let entries = self
+            .dir(ino)?
+            .iter()
+            .map(|(name, ino)| (name.clone(), *ino))
+            .collect();
+        self.inode_mut(ino)?.accessed = SystemTime::now();
+
+        Ok(entries)
+    }
+
+    pub(crate) fn read_link(&self, path: &Path) -> std::io::Result<PathBuf> {
+        match &self.inode(self.walk(path, false)?.ino)?.node {
+            Node::Symlink(target) => Ok(target.clone()),
+            Node::File(_) | Node::Dir(_) => Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "path is not a symbolic link",
+            )),
+        }
+    }
+
+    pub(crate) fn symlink(&mut self, target: &Path, path: &Path) -> std::io::Result<()> {
+        let (parent, name) = self.walk_parent(path)?;
+        if self.lookup(parent.ino, &name)?.is_some() {
+            return Err(std::io::ErrorKind::AlreadyExists.into());
+        }
+
+        let ino = self.insert(
+            Node::Symlink(target.to_owned()),
+            default_permissions(FileType::Symlink),
+        );
+        self.link(parent.ino, name, ino)
+    }
+
+    pub(crate) fn hard_link(&mut self, from: &Path, to: &Path) -> std::io::Result<()> {
+        let ino = self.walk(from, false)?.ino;
+        if self.inode(ino)?.file_type().is_dir() {
+            return Err(std::io::ErrorKind::PermissionDenied.into());
+        }
+
+        let (parent, name) = self.walk_parent(to)?;
+        if self.lookup(parent.ino, &name)?.is_some() {
+            return Err(std::io::ErrorKind::AlreadyExists.into());
+        }
+
+        self.link(parent.ino, name, ino)
+    }
+
+    pub(crate) fn remove_file(&mut self, path: &Path) -> std::io::Result<()> {
+        let (parent, name) = self.walk_parent(path)?;
+        let ino = self
+            .lookup(parent.ino, &name)?
+            .ok_or(std::io::ErrorKind::NotFound)?;
+        if self.inode(ino)?.file_type().is_dir() {
+            return Err(std::io::ErrorKind::IsADirectory.into());
+        }
+
+        self.unlink(parent.ino, &name)
+    }
+
+    pub(crate) fn rename(&mut self, from: &Path, to: &Path) -> std::io::Result<()> {
+        let (from_parent, from_name) = self.walk_parent(from)?;
+        let ino = self
+            .lookup(from_parent.ino, &from_name)?
+            .ok_or(std::io::ErrorKind::NotFound)?;
+        let (to_parent, to_name) = self.walk_parent(to)?;
+        let is_dir = self.inode(ino)?.file_type().is_dir();
+
+        if is_dir && to_parent.contains(ino) {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "directory can't be moved into itself",
+            ));
+        }
+
+        if let Some(replaced) = self.lookup(to_parent.ino, &to_name)? {
+            if replaced == ino {
+                return Ok(());
+            }
+
+            match (is_dir, &self.inode(replaced)?.node) {
+                (true, Node::Dir(entries)) if !entries.is_empty() => {
+                    return Err(std::io::ErrorKind::DirectoryNotEmpty.into());
+                }
+                (true, Node::File(_) | Node::Symlink(_)) => {
+                    return Err(std::io::ErrorKind::NotADirectory.into());
+                }
+                (false, Node::Dir(_)) => {
+                    return Err(std::io::ErrorKind::IsADirectory.into());
+                }
+                _ => {}
+            }
+
+            self.unlink(to_parent.ino, &to_name)?;
+        }
+
+        self.link(to_parent.ino, to_name, ino)?;
+        self.unlink(from_parent.ino, &from_name)
+    }
+}
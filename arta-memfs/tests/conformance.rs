@@ -0,0 +1,7 @@
+use arta_memfs::MemFsRuntime;
+
+arta_conformance::conformance_tests! {
+    runtime: MemFsRuntime = MemFsRuntime::new(),
+    block_on: |_runtime, future| futures::executor::block_on(future),
+    suites: [fs],
+}
@@ -0,0 +1,35 @@
+use async_task::{Runnable, Task};
+use futures::Future;
+use std::sync::{Arc, Mutex};
+
+/// Queue of tasks ready to be polled. Tasks are picked in random order derived from the
+/// simulation seed so different seeds explore different interleavings.
+#[derive(Default)]
+pub(crate) struct Executor {
+    queue: Arc<Mutex<Vec<Runnable>>>,
+}
+
+impl Executor {
+    pub(crate) fn spawn<F>(&self, future: F) -> Task<F::Output>
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let queue = Arc::clone(&self.queue);
+        let (runnable, task) =
+            async_task::spawn(future, move |runnable| queue.lock().unwrap().push(runnable));
+        runnable.schedule();
+
+        task
+    }
+
+    pub(crate) fn pop(&self, rng: &mut fastrand::Rng) -> Option<Runnable> {
+        let mut queue = self.queue.lock().unwrap();
+        if queue.is_empty() {
+            None
+        } else {
+            let index = rng.usize(..queue.len());
+            Some(queue.swap_remove(index))
+        }
+    }
+}
@@ -0,0 +1,382 @@
+//! # arta-sim
+//! Arta-sim is a crate that provides a deterministic simulation implementation of `arta`
+//! abstractions.
+//!
+//! Tasks are executed on a single thread in an order derived from a seed, timers advance a
+//! virtual clock instead of waiting and networking goes through an in-process network with
+//! configurable latency, packet loss and partitions. Running the same code with the same seed
+//! always produces the same execution, so a failing run can be replayed from its seed.
+//!
+//! ## Installation
+//! Add a following dependencies to your `Cargo.toml`:
+//! ```toml
+//! [dev-dependencies]
+//! arta-sim = "0.3.0"
+//! arta = "0.3.0"
+//! ```
+//! ## Usage
+//!
+//! ```ignore
+//! #[test]
+//! fn protocol_survives_partition() {
+//!     let runtime = SimRuntimeBuilder::new().seed(42).packet_loss(0.1).build();
+//!     runtime.block_on(async {
+//!         // Call methods on `runtime` or pass it into code generic over arta traits.
+//!         // Example:
+//!         runtime.sleep(Duration::from_secs(60)).await; // Returns immediately.
+//!     });
+//! }
+//! ```
+
+#![deny(
+    warnings,
+    clippy::correctness,
+    clippy::suspicious,
+    clippy::complexity,
+    clippy::perf,
+    clippy::style,
+    clippy::pedantic,
+    clippy::restriction,
+    clippy::cargo
+)]
+#![allow(
+    clippy::module_name_repetitions,
+    clippy::blanket_clippy_restriction_lints,
+    clippy::missing_inline_in_public_items,
+    clippy::single_char_lifetime_names,
+    clippy::implicit_return,
+    clippy::pattern_type_mismatch,
+    clippy::question_mark_used,
+    clippy::shadow_reuse,
+    clippy::shadow_same,
+    clippy::pub_with_shorthand,
+    clippy::absolute_paths,
+    clippy::exhaustive_enums,
+    clippy::exhaustive_structs,
+    clippy::multiple_crate_versions,
+    clippy::missing_docs_in_private_items,
+    clippy::pub_use,
+    clippy::infinite_loop, // Allowed because of bug: https://github.com/rust-lang/rust-clippy/issues/12338
+    clippy::unseparated_literal_suffix,
+    clippy::self_named_module_files,
+    clippy::big_endian_bytes,
+    clippy::single_call_fn,
+    clippy::missing_trait_methods,
+    clippy::arithmetic_side_effects,
+    clippy::indexing_slicing,
+    clippy::print_stdout,
+    clippy::shadow_unrelated,
+    clippy::undocumented_unsafe_blocks,
+    clippy::as_conversions,
+    clippy::ref_as_ptr,
+    clippy::doc_markdown,
+    clippy::unwrap_used,
+    clippy::unreachable,
+    clippy::impl_trait_in_params,
+    clippy::missing_errors_doc,
+    clippy::std_instead_of_core,
+    clippy::std_instead_of_alloc,
+    clippy::alloc_instead_of_core,
+    clippy::min_ident_chars
+)]
+#![forbid(unreachable_pub, missing_docs)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod executor;
+pub mod net;
+pub mod task;
+pub mod time;
+
+use executor::Executor;
+use futures::Future;
+use net::Network;
+use std::{
+    cell::Cell,
+    net::{IpAddr, Ipv4Addr},
+    pin::pin,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex, MutexGuard,
+    },
+    task::{Context, Poll, Wake, Waker},
+    time::{Duration, Instant, SystemTime},
+};
+use time::Clock;
+
+thread_local! {
+    static IS_INSIDE_SIMULATION: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Builder for [`SimRuntime`] configuration.
+pub struct SimRuntimeBuilder {
+    seed: u64,
+    min_latency: Duration,
+    max_latency: Duration,
+    packet_loss: f64,
+    system_time: SystemTime,
+}
+
+impl SimRuntimeBuilder {
+    /// Creates a builder with seed `0`, fixed 1ms network latency, no packet loss and wall-clock
+    /// time starting at [`SystemTime::UNIX_EPOCH`].
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            seed: 0,
+            min_latency: Duration::from_millis(1),
+            max_latency: Duration::from_millis(1),
+            packet_loss: 0.0,
+            system_time: SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    /// Sets the seed that determines task scheduling order, latencies and packet loss.
+    pub fn seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the bounds of network latency. Every packet is delayed by a duration uniformly
+    /// picked from `min..=max`.
+    pub fn latency(&mut self, min: Duration, max: Duration) -> &mut Self {
+        self.min_latency = min;
+        self.max_latency = max.max(min);
+        self
+    }
+
+    /// Sets the probability in range `0.0..=1.0` of a UDP datagram being dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `probability` is NaN or outside of `0.0..=1.0`.
+    pub fn packet_loss(&mut self, probability: f64) -> &mut Self {
+        assert_probability(probability);
+        self.packet_loss = probability;
+        self
+    }
+
+    /// Sets the wall-clock time at the simulation start. Wall-clock time advances together with
+    /// the virtual clock.
+    pub fn system_time(&mut self, time: SystemTime) -> &mut Self {
+        self.system_time = time;
+        self
+    }
+
+    /// Creates a [`SimRuntime`] with the configured parameters.
+    #[must_use]
+    pub fn build(&self) -> SimRuntime {
+        SimRuntime {
+            shared: Arc::new(Shared {
+                seed: self.seed,
+                executor: Executor::default(),
+                clock: Mutex::new(Clock::default()),
+                start: Instant::now(),
+                system_start: self.system_time,
+                rng: Mutex::new(fastrand::Rng::with_seed(self.seed)),
+                network: Mutex::new(Network::new(
+                    self.min_latency,
+                    self.max_latency,
+                    self.packet_loss,
+                )),
+            }),
+            ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+        }
+    }
+}
+
+impl Default for SimRuntimeBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub(crate) struct Shared {
+    seed: u64,
+    executor: Executor,
+    clock: Mutex<Clock>,
+    start: Instant,
+    system_start: SystemTime,
+    rng: Mutex<fastrand::Rng>,
+    network: Mutex<Network>,
+}
+
+/// Handle to a deterministic simulation.
+///
+/// Each handle acts as a simulated host with its own IP address, sockets created through it are
+/// bound to that address. Handles created with [`SimRuntime::node`] share the same scheduler, clock
+/// and network.
+#[derive(Clone)]
+pub struct SimRuntime {
+    shared: Arc<Shared>,
+    ip: IpAddr,
+}
+
+impl SimRuntime {
+    /// Creates a simulation with default configuration and the specified seed.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        SimRuntimeBuilder::new().seed(seed).build()
+    }
+
+    /// Returns the seed this simulation was created with.
+    #[must_use]
+    pub fn seed(&self) -> u64 {
+        self.shared.seed
+    }
+
+    /// Returns the IP address of the simulated host this handle represents.
+    #[must_use]
+    pub const fn ip(&self) -> IpAddr {
+        self.ip
+    }
+
+    /// Returns a handle to another simulated host with the specified IP address.
+    #[must_use]
+    pub fn node(&self, ip: IpAddr) -> Self {
+        Self {
+            shared: Arc::clone(&self.shared),
+            ip,
+        }
+    }
+
+    /// Returns the amount of virtual time passed since the simulation start.
+    #[must_use]
+    pub fn elapsed(&self) -> Duration {
+        self.clock().now()
+    }
+
+    /// Prevents any packets from being delivered between hosts with `first` and `second`
+    /// addresses until [`SimRuntime::heal`] is called.
+    ///
+    /// Connection attempts fail with [`std::io::ErrorKind::TimedOut`], writes into established
+    /// TCP connections reset them and UDP datagrams are silently dropped.
+    pub fn partition(&self, first: IpAddr, second: IpAddr) {
+        self.network().partition(first, second);
+    }
+
+    /// Restores connectivity between hosts with `first` and `second` addresses.
+    pub fn heal(&self, first: IpAddr, second: IpAddr) {
+        self.network().heal(first, second);
+    }
+
+    /// Restores connectivity between all hosts.
+    pub fn heal_all(&self) {
+        self.network().heal_all();
+    }
+
+    /// Sets the bounds of network latency. Every packet sent after this call is delayed by a
+    /// duration uniformly picked from `min..=max`.
+    pub fn set_latency(&self, min: Duration, max: Duration) {
+        self.network().set_latency(min, max);
+    }
+
+    /// Sets the probability in range `0.0..=1.0` of a UDP datagram being dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `probability` is NaN or outside of `0.0..=1.0`.
+    pub fn set_packet_loss(&self, probability: f64) {
+        assert_probability(probability);
+        self.network().set_packet_loss(probability);
+    }
+
+    /// Registers an address the simulated resolver returns for a host name, a name may have
+    /// several addresses. `localhost` resolves to `127.0.0.1` by default.
+    pub fn add_host(&self, name: &str, ip: IpAddr) {
+        self.network().add_host(name, ip);
+    }
+
+    /// Implementation of [`TaskRuntime::block_on`](arta::task::TaskRuntime::block_on).
+    pub(crate) fn run<F>(&self, future: F) -> F::Output
+    where
+        F: Future,
+    {
+        struct SimulationGuard;
+
+        impl Drop for SimulationGuard {
+            fn drop(&mut self) {
+                IS_INSIDE_SIMULATION.set(false);
+            }
+        }
+
+        assert!(
+            !IS_INSIDE_SIMULATION.replace(true),
+            "`SimRuntime::block_on` can't be called from inside of a simulation"
+        );
+        let _guard = SimulationGuard;
+
+        let main_waker = Arc::new(MainWaker {
+            is_woken: AtomicBool::new(true),
+        });
+        let waker = Waker::from(Arc::clone(&main_waker));
+        let mut cx = Context::from_waker(&waker);
+        let mut future = pin!(future);
+
+        loop {
+            if main_waker.is_woken.swap(false, Ordering::AcqRel) {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                    return output;
+                }
+                continue;
+            }
+
+            let runnable = {
+                let mut rng = self.shared.rng.lock().unwrap();
+                self.shared.executor.pop(&mut rng)
+            };
+            if let Some(runnable) = runnable {
+                runnable.run();
+                continue;
+            }
+
+            let wakers = self.clock().advance();
+            assert!(
+                !wakers.is_empty(),
+                "simulation with seed {} deadlocked: no task can make progress",
+                self.shared.seed
+            );
+            wakers.into_iter().for_each(Waker::wake);
+        }
+    }
+
+    pub(crate) fn clock(&self) -> MutexGuard<'_, Clock> {
+        self.shared.clock.lock().unwrap()
+    }
+
+    pub(crate) fn network(&self) -> MutexGuard<'_, Network> {
+        self.shared.network.lock().unwrap()
+    }
+
+    pub(crate) fn random_latency(&self) -> Duration {
+        let (min, max) = self.network().latency();
+        let min = u64::try_from(min.as_nanos()).unwrap_or(u64::MAX);
+        let max = u64::try_from(max.as_nanos()).unwrap_or(u64::MAX);
+
+        Duration::from_nanos(self.shared.rng.lock().unwrap().u64(min..=max))
+    }
+
+    pub(crate) fn random_bool(&self, probability: f64) -> bool {
+        self.shared.rng.lock().unwrap().f64() < probability
+    }
+}
+
+fn assert_probability(probability: f64) {
+    assert!(
+        (0.0f64..=1.0f64).contains(&probability),
+        "packet loss probability must be in range 0.0..=1.0, got {probability}"
+    );
+}
+
+struct MainWaker {
+    is_woken: AtomicBool,
+}
+
+impl Wake for MainWaker {
+    fn wake(self: Arc<Self>) {
+        self.is_woken.store(true, Ordering::Release);
+    }
+
+    fn wake_by_ref(self: &Arc<Self>) {
+        self.is_woken.store(true, Ordering::Release);
+    }
+}
@@ -0,0 +1,186 @@
+//! Simulated TCP/UDP communication implementation.
+//!
+//! Sockets don't touch the OS network stack, bytes are routed between simulated hosts created
+//! with [`SimRuntime::node`] and host names are resolved to addresses registered with
+//! [`SimRuntime::add_host`]. Simulated sockets have no OS descriptor, so they don't implement
+//! [`OsSocket`](arta::net::OsSocket).
+mod tcp_listener;
+mod tcp_socket;
+mod tcp_stream;
+mod udp_socket;
+
+pub use tcp_listener::*;
+pub use tcp_socket::*;
+pub use tcp_stream::*;
+pub use udp_socket::*;
+
+use crate::SimRuntime;
+use arta::net::NetRuntime;
+use std::{
+    collections::{HashMap, HashSet},
+    future::Future,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::{Mutex, Weak},
+    time::Duration,
+};
+
+const EPHEMERAL_PORTS_START: u16 = 49152;
+
+/// State of the in-process network shared by all simulated hosts.
+pub(crate) struct Network {
+    min_latency: Duration,
+    max_latency: Duration,
+    packet_loss: f64,
+    partitions: HashSet<(IpAddr, IpAddr)>,
+    hosts: HashMap<String, Vec<IpAddr>>,
+    listeners: HashMap<SocketAddr, Weak<Mutex<ListenerState>>>,
+    udp_sockets: HashMap<SocketAddr, Weak<Mutex<UdpInbox>>>,
+    next_port: u16,
+}
+
+impl Network {
+    pub(crate) fn new(min_latency: Duration, max_latency: Duration, packet_loss: f64) -> Self {
+        Self {
+            min_latency,
+            max_latency,
+            packet_loss,
+            partitions: HashSet::new(),
+            hosts: HashMap::from([(
+                "localhost".to_owned(),
+                vec![IpAddr::V4(Ipv4Addr::LOCALHOST)],
+            )]),
+            listeners: HashMap::new(),
+            udp_sockets: HashMap::new(),
+            next_port: EPHEMERAL_PORTS_START,
+        }
+    }
+
+    pub(crate) const fn latency(&self) -> (Duration, Duration) {
+        (self.min_latency, self.max_latency)
+    }
+
+    const fn packet_loss(&self) -> f64 {
+        self.packet_loss
+    }
+
+    fn is_partitioned(&self, first: IpAddr, second: IpAddr) -> bool {
+        first != second && self.partitions.contains(&ordered_pair(first, second))
+    }
+
+    fn listener(&self, addr: SocketAddr) -> Option<std::sync::Arc<Mutex<ListenerState>>> {
+        self.listeners.get(&addr).and_then(Weak::upgrade)
+    }
+
+    fn udp_socket(&self, addr: SocketAddr) -> Option<std::sync::Arc<Mutex<UdpInbox>>> {
+        self.udp_sockets.get(&addr).and_then(Weak::upgrade)
+    }
+
+    pub(crate) fn partition(&mut self, first: IpAddr, second: IpAddr) {
+        self.partitions.insert(ordered_pair(first, second));
+    }
+
+    pub(crate) fn heal(&mut self, first: IpAddr, second: IpAddr) {
+        self.partitions.remove(&ordered_pair(first, second));
+    }
+
+    pub(crate) fn heal_all(&mut self) {
+        self.partitions.clear();
+    }
+
+    pub(crate) fn set_latency(&mut self, min: Duration, max: Duration) {
+        self.min_latency = min;
+        self.max_latency = max.max(min);
+    }
+
+    pub(crate) fn set_packet_loss(&mut self, probability: f64) {
+        self.packet_loss = probability;
+    }
+
+    pub(crate) fn add_host(&mut self, name: &str, ip: IpAddr) {
+        self.hosts
+            .entry(name.to_ascii_lowercase())
+            .or_default()
+            .push(ip);
+    }
+
+    fn resolve(&self, host: &str, port: u16) -> std::io::Result<Vec<SocketAddr>> {
+        self.hosts
+            .get(&host.to_ascii_lowercase())
+            .map(|ips| ips.iter().map(|&ip| SocketAddr::new(ip, port)).collect())
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::NotFound,
+                    format!("host `{host}` is not known to the simulated network"),
+                )
+            })
+    }
+
+    /// Resolves an address requested for binding on host with `host_ip`, allocating an
+    /// ephemeral port if `addr` has zero port.
+    fn local_addr(
+        &mut self,
+        host_ip: IpAddr,
+        addr: SocketAddr,
+        is_used: impl Fn(&Self, SocketAddr) -> bool,
+    ) -> std::io::Result<SocketAddr> {
+        let ip = if addr.ip().is_unspecified() {
+            host_ip
+        } else if addr.ip() == host_ip || addr.ip().is_loopback() {
+            addr.ip()
+        } else {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AddrNotAvailable,
+                "address doesn't belong to the simulated host",
+            ));
+        };
+
+        if addr.port() != 0 {
+            let addr = SocketAddr::new(ip, addr.port());
+            return if is_used(self, addr) {
+                Err(std::io::ErrorKind::AddrInUse.into())
+            } else {
+                Ok(addr)
+            };
+        }
+
+        for _ in EPHEMERAL_PORTS_START..=u16::MAX {
+            let addr = SocketAddr::new(ip, self.next_port);
+            self.next_port = self
+                .next_port
+                .checked_add(1)
+                .unwrap_or(EPHEMERAL_PORTS_START);
+
+            if !is_used(self, addr) {
+                return Ok(addr);
+            }
+        }
+
+        Err(std::io::Error::new(
+            std::io::ErrorKind::AddrInUse,
+            "no ephemeral port available",
+        ))
+    }
+}
+
+fn ordered_pair(first: IpAddr, second: IpAddr) -> (IpAddr, IpAddr) {
+    if first <= second {
+        (first, second)
+    } else {
+        (second, first)
+    }
+}
+
+impl NetRuntime for SimRuntime {
+    type TcpListener = SimTcpListener;
+    type TcpStream = SimTcpStream;
+    type TcpSocket = SimTcpSocket;
+    type UdpSocket = SimUdpSocket;
+
+    fn resolve(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> impl Future<Output = std::io::Result<Vec<SocketAddr>>> + Send {
+        std::future::ready(self.network().resolve(host, port))
+    }
+}
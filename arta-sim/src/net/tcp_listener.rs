@@ -0,0 +1,172 @@
+use super::{Network, SimTcpStream};
+use crate::{time::SimSleep, SimRuntime};
+use arta::net::{NetRuntime, RuntimeTcpListener, ToSocketAddrs};
+use futures::prelude::Future;
+use std::{
+    collections::VecDeque,
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicU32, Ordering},
+        Arc, Mutex,
+    },
+    task::{Poll, Waker},
+};
+
+/// Connections waiting to be accepted.
+#[derive(Default)]
+pub(crate) struct ListenerState {
+    backlog: VecDeque<(SimTcpStream, SocketAddr)>,
+    acceptors: Vec<Waker>,
+    /// Whether listeners sharing the address with `SO_REUSEPORT` also share this state.
+    is_reuse_port: bool,
+}
+
+/// Simulation specific [`RuntimeTcpListener`] implementation.
+pub struct SimTcpListener {
+    runtime: SimRuntime,
+    local_addr: SocketAddr,
+    state: Arc<Mutex<ListenerState>>,
+    ttl: AtomicU32,
+}
+
+impl Drop for SimTcpListener {
+    fn drop(&mut self) {
+        let mut network = self.runtime.network();
+        if Arc::strong_count(&self.state) == 1 {
+            network.listeners.remove(&self.local_addr);
+        }
+    }
+}
+
+impl RuntimeTcpListener for SimTcpListener {
+    type Runtime = SimRuntime;
+
+    fn accept(
+        &self,
+    ) -> impl Future<Output = std::io::Result<(<Self::Runtime as NetRuntime>::TcpStream, SocketAddr)>>
+           + Send {
+        futures::future::poll_fn(|cx| {
+            let mut state = self.state.lock().unwrap();
+            if let Some(connection) = state.backlog.pop_front() {
+                Poll::Ready(Ok(connection))
+            } else {
+                if !state
+                    .acceptors
+                    .iter()
+                    .any(|acceptor| acceptor.will_wake(cx.waker()))
+                {
+                    state.acceptors.push(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        })
+    }
+
+    fn bind(
+        runtime: &Self::Runtime,
+        addr: impl ToSocketAddrs<Self::Runtime>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        addr.for_each_resolved_addr_until_success(runtime, move |addr| async move {
+            listen(runtime, addr, false)
+        })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        Ok(self.local_addr)
+    }
+
+    fn ttl(&self) -> std::io::Result<u32> {
+        Ok(self.ttl.load(Ordering::Relaxed))
+    }
+
+    fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+        self.ttl.store(ttl, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// Creates a listener bound to `addr`. Listeners bound to the same address with
+/// `is_reuse_port` set share a single queue of incoming connections.
+pub(super) fn listen(
+    runtime: &SimRuntime,
+    addr: SocketAddr,
+    is_reuse_port: bool,
+) -> std::io::Result<SimTcpListener> {
+    // An ephemeral port is never shared with existing listeners.
+    let is_reuse_port = is_reuse_port && addr.port() != 0;
+
+    let mut network = runtime.network();
+    let local_addr = network.local_addr(runtime.ip, addr, |network, addr| {
+        is_listener_conflicting(network, addr, is_reuse_port)
+    })?;
+
+    let state = if let Some(state) = network.listener(local_addr) {
+        state
+    } else {
+        let state = Arc::new(Mutex::new(ListenerState {
+            is_reuse_port,
+            ..ListenerState::default()
+        }));
+        network.listeners.insert(local_addr, Arc::downgrade(&state));
+        state
+    };
+
+    Ok(SimTcpListener {
+        runtime: runtime.clone(),
+        local_addr,
+        state,
+        ttl: AtomicU32::new(64),
+    })
+}
+
+/// Returns whether a listener bound to `addr` prevents binding another socket to it.
+pub(super) fn is_listener_conflicting(
+    network: &Network,
+    addr: SocketAddr,
+    is_reuse_port: bool,
+) -> bool {
+    network
+        .listener(addr)
+        .is_some_and(|state| !is_reuse_port || !state.lock().unwrap().is_reuse_port)
+}
+
+/// Performs a simulated TCP handshake from `local_addr` with a listener bound to `addr`.
+pub(super) async fn connect(
+    runtime: &SimRuntime,
+    local_addr: SocketAddr,
+    addr: SocketAddr,
+) -> std::io::Result<SimTcpStream> {
+    SimSleep::until(runtime, runtime.elapsed() + runtime.random_latency()).await;
+
+    let (state, local_addr) = {
+        let mut network = runtime.network();
+        if network.is_partitioned(runtime.ip, addr.ip()) {
+            return Err(std::io::ErrorKind::TimedOut.into());
+        }
+
+        let state = network
+            .listener(addr)
+            .ok_or(std::io::ErrorKind::ConnectionRefused)?;
+        let local_addr = network.local_addr(runtime.ip, local_addr, |network, addr| {
+            network.listener(addr).is_some()
+        })?;
+
+        (state, local_addr)
+    };
+
+    let peer_runtime = runtime.node(addr.ip());
+    let (stream, peer_stream) = SimTcpStream::pair(runtime, &peer_runtime, local_addr, addr);
+    {
+        let mut state = state.lock().unwrap();
+        state.backlog.push_back((peer_stream, local_addr));
+        for acceptor in state.acceptors.drain(..) {
+            acceptor.wake();
+        }
+    }
+
+    SimSleep::until(runtime, runtime.elapsed() + runtime.random_latency()).await;
+    Ok(stream)
+}
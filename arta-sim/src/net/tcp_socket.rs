@@ -0,0 +1,175 @@
+use super::tcp_listener;
+use crate::SimRuntime;
+use arta::net::{NetRuntime, RuntimeTcpSocket};
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::{Mutex, MutexGuard},
+};
+
+struct SocketOptions {
+    local_addr: Option<SocketAddr>,
+    is_reuse_addr: bool,
+    is_reuse_port: bool,
+    send_buffer_size: u32,
+    recv_buffer_size: u32,
+    is_only_v6: bool,
+}
+
+/// Simulation specific [`RuntimeTcpSocket`] implementation.
+///
+/// Buffer sizes are only stored, the backlog of a listener is not limited. Listeners bound to
+/// the same address with `SO_REUSEPORT` accept connections from a shared queue.
+pub struct SimTcpSocket {
+    runtime: SimRuntime,
+    is_ipv6: bool,
+    options: Mutex<SocketOptions>,
+}
+
+impl SimTcpSocket {
+    fn new(runtime: &SimRuntime, is_ipv6: bool) -> Self {
+        Self {
+            runtime: runtime.clone(),
+            is_ipv6,
+            options: Mutex::new(SocketOptions {
+                local_addr: None,
+                is_reuse_addr: false,
+                is_reuse_port: false,
+                send_buffer_size: 0x0001_0000,
+                recv_buffer_size: 0x0001_0000,
+                is_only_v6: false,
+            }),
+        }
+    }
+
+    fn options(&self) -> MutexGuard<'_, SocketOptions> {
+        self.options.lock().unwrap()
+    }
+
+    fn unspecified_addr(&self) -> SocketAddr {
+        if self.is_ipv6 {
+            SocketAddr::new(IpAddr::V6(Ipv6Addr::UNSPECIFIED), 0)
+        } else {
+            SocketAddr::new(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)
+        }
+    }
+
+    fn check_ipv6(&self) -> std::io::Result<()> {
+        if self.is_ipv6 {
+            Ok(())
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "option is only valid for IPv6 sockets",
+            ))
+        }
+    }
+}
+
+impl RuntimeTcpSocket for SimTcpSocket {
+    type Runtime = SimRuntime;
+
+    fn new_v4(runtime: &Self::Runtime) -> std::io::Result<Self> {
+        Ok(Self::new(runtime, false))
+    }
+
+    fn new_v6(runtime: &Self::Runtime) -> std::io::Result<Self> {
+        Ok(Self::new(runtime, true))
+    }
+
+    fn reuseaddr(&self) -> std::io::Result<bool> {
+        Ok(self.options().is_reuse_addr)
+    }
+
+    fn set_reuseaddr(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.options().is_reuse_addr = is_enabled;
+        Ok(())
+    }
+
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    fn reuseport(&self) -> std::io::Result<bool> {
+        Ok(self.options().is_reuse_port)
+    }
+
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    fn set_reuseport(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.options().is_reuse_port = is_enabled;
+        Ok(())
+    }
+
+    fn send_buffer_size(&self) -> std::io::Result<u32> {
+        Ok(self.options().send_buffer_size)
+    }
+
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        self.options().send_buffer_size = size;
+        Ok(())
+    }
+
+    fn recv_buffer_size(&self) -> std::io::Result<u32> {
+        Ok(self.options().recv_buffer_size)
+    }
+
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        self.options().recv_buffer_size = size;
+        Ok(())
+    }
+
+    fn only_v6(&self) -> std::io::Result<bool> {
+        self.check_ipv6()?;
+        Ok(self.options().is_only_v6)
+    }
+
+    fn set_only_v6(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.check_ipv6()?;
+        self.options().is_only_v6 = is_enabled;
+        Ok(())
+    }
+
+    fn bind(&self, addr: SocketAddr) -> std::io::Result<()> {
+        let mut options = self.options();
+        if options.local_addr.is_some() || addr.is_ipv6() != self.is_ipv6 {
+            return Err(std::io::ErrorKind::InvalidInput.into());
+        }
+
+        // Binding to an ephemeral port never shares it with existing listeners.
+        let is_reuse_port = options.is_reuse_port && addr.port() != 0;
+        let local_addr =
+            self.runtime
+                .network()
+                .local_addr(self.runtime.ip, addr, |network, addr| {
+                    tcp_listener::is_listener_conflicting(network, addr, is_reuse_port)
+                })?;
+        options.local_addr = Some(local_addr);
+        Ok(())
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        Ok(self
+            .options()
+            .local_addr
+            .unwrap_or_else(|| self.unspecified_addr()))
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        Ok(None)
+    }
+
+    async fn connect(
+        self,
+        addr: SocketAddr,
+    ) -> std::io::Result<<Self::Runtime as NetRuntime>::TcpStream> {
+        let local_addr = self
+            .options()
+            .local_addr
+            .unwrap_or_else(|| SocketAddr::new(self.runtime.ip, 0));
+        tcp_listener::connect(&self.runtime, local_addr, addr).await
+    }
+
+    fn listen(self, _backlog: u32) -> std::io::Result<<Self::Runtime as NetRuntime>::TcpListener> {
+        let options = self.options();
+        let addr = options
+            .local_addr
+            .unwrap_or_else(|| self.unspecified_addr());
+        tcp_listener::listen(&self.runtime, addr, options.is_reuse_port)
+    }
+}
@@ -0,0 +1,491 @@
+use crate::{time::SimSleep, SimRuntime};
+use arta::net::{ReuniteError, RuntimeTcpStream, TcpKeepalive, TcpStreamOptions, ToSocketAddrs};
+use futures::{prelude::Future, AsyncRead, AsyncWrite};
+use std::{
+    collections::VecDeque,
+    fmt,
+    net::{Shutdown, SocketAddr},
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Context, Poll, Waker},
+    time::Duration,
+};
+
+/// One direction of a simulated TCP connection.
+#[derive(Default)]
+pub(super) struct Pipe {
+    /// Written chunks along with the virtual time they arrive at the reader.
+    in_flight: VecDeque<(Duration, Vec<u8>)>,
+    readable: VecDeque<u8>,
+    is_write_closed: bool,
+    is_read_closed: bool,
+    is_reset: bool,
+    reader: Option<Waker>,
+}
+
+impl Pipe {
+    fn deliver(&mut self, now: Duration) {
+        while self
+            .in_flight
+            .front()
+            .is_some_and(|(arrival, _)| *arrival <= now)
+        {
+            if let Some((_, chunk)) = self.in_flight.pop_front() {
+                self.readable.extend(chunk);
+            }
+        }
+    }
+
+    fn wake_reader(&mut self) {
+        if let Some(waker) = self.reader.take() {
+            waker.wake();
+        }
+    }
+}
+
+struct TcpOptions {
+    linger: Option<Duration>,
+    is_nodelay: bool,
+    ttl: u32,
+    keepalive: Option<TcpKeepalive>,
+    user_timeout: Option<Duration>,
+    send_buffer_size: u32,
+    recv_buffer_size: u32,
+    tos: u32,
+}
+
+/// Simulation specific [`RuntimeTcpStream`] implementation.
+///
+/// Keepalive, user timeout, buffer size and type-of-service options are only stored. Shutting
+/// down the read half makes subsequent reads return the end of the stream and writes of the
+/// peer fail.
+pub struct SimTcpStream {
+    runtime: SimRuntime,
+    local_addr: SocketAddr,
+    peer_addr: SocketAddr,
+    read: Arc<Mutex<Pipe>>,
+    write: Arc<Mutex<Pipe>>,
+    read_timer: Mutex<Option<SimSleep>>,
+    options: Mutex<TcpOptions>,
+}
+
+impl SimTcpStream {
+    /// Creates both ends of a connection between `local_addr` on the `runtime` host and
+    /// `peer_addr` on the `peer_runtime` host.
+    pub(super) fn pair(
+        runtime: &SimRuntime,
+        peer_runtime: &SimRuntime,
+        local_addr: SocketAddr,
+        peer_addr: SocketAddr,
+    ) -> (Self, Self) {
+        let outgoing = Arc::new(Mutex::new(Pipe::default()));
+        let incoming = Arc::new(Mutex::new(Pipe::default()));
+
+        (
+            Self::new(
+                runtime.clone(),
+                local_addr,
+                peer_addr,
+                Arc::clone(&incoming),
+                Arc::clone(&outgoing),
+            ),
+            Self::new(
+                peer_runtime.clone(),
+                peer_addr,
+                local_addr,
+                outgoing,
+                incoming,
+            ),
+        )
+    }
+
+    fn new(
+        runtime: SimRuntime,
+        local_addr: SocketAddr,
+        peer_addr: SocketAddr,
+        read: Arc<Mutex<Pipe>>,
+        write: Arc<Mutex<Pipe>>,
+    ) -> Self {
+        Self {
+            runtime,
+            local_addr,
+            peer_addr,
+            read,
+            write,
+            read_timer: Mutex::new(None),
+            options: Mutex::new(TcpOptions {
+                linger: None,
+                is_nodelay: false,
+                ttl: 64,
+                keepalive: None,
+                user_timeout: None,
+                send_buffer_size: 0x0001_0000,
+                recv_buffer_size: 0x0001_0000,
+                tos: 0,
+            }),
+        }
+    }
+
+    fn poll_read_priv(
+        &self,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+        is_peek: bool,
+    ) -> Poll<std::io::Result<usize>> {
+        loop {
+            let next_arrival = {
+                let mut pipe = self.read.lock().unwrap();
+                pipe.deliver(self.runtime.elapsed());
+
+                if pipe.is_reset {
+                    return Poll::Ready(Err(std::io::ErrorKind::ConnectionReset.into()));
+                }
+
+                if pipe.is_read_closed {
+                    return Poll::Ready(Ok(0));
+                }
+
+                if !pipe.readable.is_empty() {
+                    let len = buf.len().min(pipe.readable.len());
+                    for (dst, src) in buf.iter_mut().zip(pipe.readable.iter()) {
+                        *dst = *src;
+                    }
+                    if !is_peek {
+                        pipe.readable.drain(..len);
+                    }
+
+                    return Poll::Ready(Ok(len));
+                }
+
+                if pipe.is_write_closed && pipe.in_flight.is_empty() {
+                    return Poll::Ready(Ok(0));
+                }
+
+                pipe.reader = Some(cx.waker().clone());
+                pipe.in_flight.front().map(|(arrival, _)| *arrival)
+            };
+
+            let Some(arrival) = next_arrival else {
+                return Poll::Pending;
+            };
+
+            let mut timer = self.read_timer.lock().unwrap();
+            let timer = timer.insert(SimSleep::until(&self.runtime, arrival));
+            if Pin::new(timer).poll(cx).is_pending() {
+                return Poll::Pending;
+            }
+        }
+    }
+
+    fn poll_write_priv(&self, buf: &[u8]) -> Poll<std::io::Result<usize>> {
+        let is_partitioned = self
+            .runtime
+            .network()
+            .is_partitioned(self.local_addr.ip(), self.peer_addr.ip());
+        if is_partitioned {
+            self.reset();
+            return Poll::Ready(Err(std::io::ErrorKind::ConnectionReset.into()));
+        }
+
+        let latency = self.runtime.random_latency();
+        let mut pipe = self.write.lock().unwrap();
+        if pipe.is_reset {
+            return Poll::Ready(Err(std::io::ErrorKind::ConnectionReset.into()));
+        }
+        if pipe.is_write_closed || pipe.is_read_closed {
+            return Poll::Ready(Err(std::io::ErrorKind::BrokenPipe.into()));
+        }
+
+        // Chunks never overtake each other to preserve stream ordering.
+        let arrival = pipe
+            .in_flight
+            .back()
+            .map_or(Duration::ZERO, |(arrival, _)| *arrival)
+            .max(self.runtime.elapsed() + latency);
+        pipe.in_flight.push_back((arrival, buf.to_vec()));
+        pipe.wake_reader();
+
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn close_write(&self) {
+        let mut pipe = self.write.lock().unwrap();
+        pipe.is_write_closed = true;
+        pipe.wake_reader();
+    }
+
+    fn close_read(&self) {
+        let mut pipe = self.read.lock().unwrap();
+        pipe.is_read_closed = true;
+        pipe.wake_reader();
+    }
+
+    fn options(&self) -> MutexGuard<'_, TcpOptions> {
+        self.options.lock().unwrap()
+    }
+
+    fn reset(&self) {
+        for pipe in [&self.read, &self.write] {
+            let mut pipe = pipe.lock().unwrap();
+            pipe.is_reset = true;
+            pipe.wake_reader();
+        }
+    }
+}
+
+impl Drop for SimTcpStream {
+    fn drop(&mut self) {
+        self.close_write();
+        self.close_read();
+    }
+}
+
+impl RuntimeTcpStream for SimTcpStream {
+    type Runtime = SimRuntime;
+    type OwnedReadHalf = SimTcpReadHalf;
+    type OwnedWriteHalf = SimTcpWriteHalf;
+
+    fn connect(
+        runtime: &Self::Runtime,
+        addr: impl ToSocketAddrs<Self::Runtime>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        addr.for_each_resolved_addr_until_success(runtime, |addr| {
+            super::tcp_listener::connect(runtime, SocketAddr::new(runtime.ip, 0), addr)
+        })
+    }
+
+    fn into_split(self) -> (Self::OwnedReadHalf, Self::OwnedWriteHalf) {
+        let inner = Arc::new(self);
+        (
+            SimTcpReadHalf {
+                inner: Arc::clone(&inner),
+            },
+            SimTcpWriteHalf { inner },
+        )
+    }
+
+    fn reunite(
+        read: Self::OwnedReadHalf,
+        write: Self::OwnedWriteHalf,
+    ) -> Result<Self, ReuniteError<Self::OwnedReadHalf, Self::OwnedWriteHalf>> {
+        if !Arc::ptr_eq(&read.inner, &write.inner) {
+            return Err(ReuniteError(read, write));
+        }
+        drop(write);
+        Arc::try_unwrap(read.inner).map_err(|inner| {
+            ReuniteError(
+                SimTcpReadHalf {
+                    inner: Arc::clone(&inner),
+                },
+                SimTcpWriteHalf { inner },
+            )
+        })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        Ok(self.local_addr)
+    }
+
+    fn peer_addr(&self) -> std::io::Result<SocketAddr> {
+        Ok(self.peer_addr)
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    fn linger(&self) -> std::io::Result<Option<Duration>> {
+        Ok(self.options().linger)
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    fn set_linger(&self, linger: Option<Duration>) -> std::io::Result<()> {
+        self.options().linger = linger;
+        Ok(())
+    }
+
+    fn nodelay(&self) -> std::io::Result<bool> {
+        Ok(self.options().is_nodelay)
+    }
+
+    fn set_nodelay(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.options().is_nodelay = is_enabled;
+        Ok(())
+    }
+
+    fn ttl(&self) -> std::io::Result<u32> {
+        Ok(self.options().ttl)
+    }
+
+    fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+        self.options().ttl = ttl;
+        Ok(())
+    }
+
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        futures::future::poll_fn(move |cx| self.poll_read_priv(cx, buf, true))
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        Ok(None)
+    }
+}
+
+impl TcpStreamOptions for SimTcpStream {
+    fn keepalive(&self) -> std::io::Result<bool> {
+        Ok(self.options().keepalive.is_some())
+    }
+
+    fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> std::io::Result<()> {
+        self.options().keepalive = keepalive.copied();
+        Ok(())
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn user_timeout(&self) -> std::io::Result<Option<Duration>> {
+        Ok(self.options().user_timeout)
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn set_user_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        self.options().user_timeout = timeout;
+        Ok(())
+    }
+
+    fn send_buffer_size(&self) -> std::io::Result<u32> {
+        Ok(self.options().send_buffer_size)
+    }
+
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        self.options().send_buffer_size = size;
+        Ok(())
+    }
+
+    fn recv_buffer_size(&self) -> std::io::Result<u32> {
+        Ok(self.options().recv_buffer_size)
+    }
+
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        self.options().recv_buffer_size = size;
+        Ok(())
+    }
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn tos(&self) -> std::io::Result<u32> {
+        Ok(self.options().tos)
+    }
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn set_tos(&self, tos: u32) -> std::io::Result<()> {
+        self.options().tos = tos;
+        Ok(())
+    }
+
+    fn shutdown(&self, how: Shutdown) -> std::io::Result<()> {
+        if matches!(how, Shutdown::Read | Shutdown::Both) {
+            self.close_read();
+        }
+        if matches!(how, Shutdown::Write | Shutdown::Both) {
+            self.close_write();
+        }
+        Ok(())
+    }
+}
+
+impl AsyncRead for SimTcpStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.poll_read_priv(cx, buf, false)
+    }
+}
+
+impl AsyncWrite for SimTcpStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.poll_write_priv(buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.close_write();
+        Poll::Ready(Ok(()))
+    }
+}
+
+/// Simulation specific owned read half of a [`SimTcpStream`].
+pub struct SimTcpReadHalf {
+    inner: Arc<SimTcpStream>,
+}
+
+impl fmt::Debug for SimTcpReadHalf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimTcpReadHalf")
+            .field("local_addr", &self.inner.local_addr)
+            .field("peer_addr", &self.inner.peer_addr)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AsyncRead for SimTcpReadHalf {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.inner.poll_read_priv(cx, buf, false)
+    }
+}
+
+/// Simulation specific owned write half of a [`SimTcpStream`].
+pub struct SimTcpWriteHalf {
+    inner: Arc<SimTcpStream>,
+}
+
+impl fmt::Debug for SimTcpWriteHalf {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimTcpWriteHalf")
+            .field("local_addr", &self.inner.local_addr)
+            .field("peer_addr", &self.inner.peer_addr)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AsyncWrite for SimTcpWriteHalf {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        self.inner.poll_write_priv(buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        self.inner.close_write();
+        Poll::Ready(Ok(()))
+    }
+}
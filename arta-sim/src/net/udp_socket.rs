@@ -0,0 +1,283 @@
+use crate::{time::SimSleep, SimRuntime};
+use arta::{
+    net::{RuntimeUdpSocket, ToSocketAddrs},
+    task::TaskRuntime as _,
+};
+use futures::prelude::Future;
+use std::{
+    collections::{HashSet, VecDeque},
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::{Arc, Mutex, MutexGuard},
+    task::{Poll, Waker},
+};
+
+/// Datagrams delivered to a socket.
+#[derive(Default)]
+pub(crate) struct UdpInbox {
+    datagrams: VecDeque<(Vec<u8>, SocketAddr)>,
+    peer_addr: Option<SocketAddr>,
+    receiver: Option<Waker>,
+}
+
+struct UdpOptions {
+    is_broadcast: bool,
+    is_multicast_loop_v4: bool,
+    is_multicast_loop_v6: bool,
+    multicast_ttl_v4: u32,
+    ttl: u32,
+    multicast_groups_v4: HashSet<(Ipv4Addr, Ipv4Addr)>,
+    multicast_groups_v6: HashSet<(Ipv6Addr, u32)>,
+}
+
+/// Simulation specific [`RuntimeUdpSocket`] implementation.
+///
+/// Socket options are stored but don't affect delivery, multicast and broadcast datagrams are
+/// not routed.
+pub struct SimUdpSocket {
+    runtime: SimRuntime,
+    local_addr: SocketAddr,
+    inbox: Arc<Mutex<UdpInbox>>,
+    options: Mutex<UdpOptions>,
+}
+
+impl SimUdpSocket {
+    fn inbox(&self) -> MutexGuard<'_, UdpInbox> {
+        self.inbox.lock().unwrap()
+    }
+
+    fn options(&self) -> MutexGuard<'_, UdpOptions> {
+        self.options.lock().unwrap()
+    }
+
+    fn send_datagram(&self, buf: &[u8], addr: SocketAddr) {
+        let is_dropped = {
+            let network = self.runtime.network();
+            let packet_loss = network.packet_loss();
+            network.is_partitioned(self.local_addr.ip(), addr.ip())
+                || self.runtime.random_bool(packet_loss)
+        };
+        if is_dropped {
+            return;
+        }
+
+        let runtime = self.runtime.clone();
+        let arrival = runtime.elapsed() + runtime.random_latency();
+        let datagram = buf.to_vec();
+        let source = self.local_addr;
+
+        drop(self.runtime.spawn(async move {
+            SimSleep::until(&runtime, arrival).await;
+
+            let Some(inbox) = runtime.network().udp_socket(addr) else {
+                return;
+            };
+            let mut inbox = inbox.lock().unwrap();
+            if inbox.peer_addr.is_none_or(|peer_addr| peer_addr == source) {
+                inbox.datagrams.push_back((datagram, source));
+                if let Some(receiver) = inbox.receiver.take() {
+                    receiver.wake();
+                }
+            }
+        }));
+    }
+}
+
+impl Drop for SimUdpSocket {
+    fn drop(&mut self) {
+        self.runtime.network().udp_sockets.remove(&self.local_addr);
+    }
+}
+
+impl RuntimeUdpSocket for SimUdpSocket {
+    type Runtime = SimRuntime;
+
+    fn bind(
+        runtime: &Self::Runtime,
+        addrs: impl ToSocketAddrs<Self::Runtime>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        addrs.for_each_resolved_addr_until_success(runtime, move |addr| async move {
+            let mut network = runtime.network();
+            let local_addr = network.local_addr(runtime.ip, addr, |network, addr| {
+                network.udp_socket(addr).is_some()
+            })?;
+
+            let inbox = Arc::new(Mutex::new(UdpInbox::default()));
+            network
+                .udp_sockets
+                .insert(local_addr, Arc::downgrade(&inbox));
+
+            Ok(Self {
+                runtime: runtime.clone(),
+                local_addr,
+                inbox,
+                options: Mutex::new(UdpOptions {
+                    is_broadcast: false,
+                    is_multicast_loop_v4: true,
+                    is_multicast_loop_v6: true,
+                    multicast_ttl_v4: 1,
+                    ttl: 64,
+                    multicast_groups_v4: HashSet::new(),
+                    multicast_groups_v6: HashSet::new(),
+                }),
+            })
+        })
+    }
+
+    fn connect(
+        &self,
+        addrs: impl ToSocketAddrs<Self::Runtime>,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        addrs.for_each_resolved_addr_until_success(&self.runtime, |addr| {
+            let mut inbox = self.inbox();
+            inbox.peer_addr = Some(addr);
+            inbox.datagrams.retain(|(_, source)| *source == addr);
+
+            std::future::ready(Ok(()))
+        })
+    }
+
+    fn send(&self, buf: &[u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        let peer_addr = self.inbox().peer_addr;
+        std::future::ready(
+            peer_addr
+                .map(|addr| {
+                    self.send_datagram(buf, addr);
+                    buf.len()
+                })
+                .ok_or_else(|| std::io::ErrorKind::NotConnected.into()),
+        )
+    }
+
+    async fn send_to(
+        &self,
+        buf: &[u8],
+        addrs: impl ToSocketAddrs<Self::Runtime>,
+    ) -> std::io::Result<usize> {
+        if let Some(addr) = addrs.to_socket_addrs(&self.runtime).await?.next() {
+            self.send_datagram(buf, addr);
+            Ok(buf.len())
+        } else {
+            Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                "no address was resolved",
+            ))
+        }
+    }
+
+    async fn recv(&self, buf: &mut [u8]) -> std::io::Result<usize> {
+        self.recv_from(buf).await.map(|(len, _)| len)
+    }
+
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = std::io::Result<(usize, SocketAddr)>> + Send {
+        futures::future::poll_fn(|cx| {
+            let mut inbox = self.inbox();
+            if let Some((datagram, source)) = inbox.datagrams.pop_front() {
+                // Like with OS sockets, excess bytes of a datagram are discarded.
+                let len = buf.len().min(datagram.len());
+                buf[..len].copy_from_slice(&datagram[..len]);
+
+                Poll::Ready(Ok((len, source)))
+            } else {
+                inbox.receiver = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        Ok(self.local_addr)
+    }
+
+    fn set_broadcast(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.options().is_broadcast = is_enabled;
+        Ok(())
+    }
+
+    fn broadcast(&self) -> std::io::Result<bool> {
+        Ok(self.options().is_broadcast)
+    }
+
+    fn join_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> std::io::Result<()> {
+        self.options()
+            .multicast_groups_v4
+            .insert((multiaddr, interface));
+        Ok(())
+    }
+
+    fn leave_multicast_v4(&self, multiaddr: Ipv4Addr, interface: Ipv4Addr) -> std::io::Result<()> {
+        if self
+            .options()
+            .multicast_groups_v4
+            .remove(&(multiaddr, interface))
+        {
+            Ok(())
+        } else {
+            Err(std::io::ErrorKind::AddrNotAvailable.into())
+        }
+    }
+
+    fn set_multicast_loop_v4(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.options().is_multicast_loop_v4 = is_enabled;
+        Ok(())
+    }
+
+    fn multicast_loop_v4(&self) -> std::io::Result<bool> {
+        Ok(self.options().is_multicast_loop_v4)
+    }
+
+    fn set_multicast_ttl_v4(&self, ttl: u32) -> std::io::Result<()> {
+        self.options().multicast_ttl_v4 = ttl;
+        Ok(())
+    }
+
+    fn multicast_ttl_v4(&self) -> std::io::Result<u32> {
+        Ok(self.options().multicast_ttl_v4)
+    }
+
+    fn join_multicast_v6(&self, multiaddr: Ipv6Addr, interface: u32) -> std::io::Result<()> {
+        self.options()
+            .multicast_groups_v6
+            .insert((multiaddr, interface));
+        Ok(())
+    }
+
+    fn leave_multicast_v6(&self, multiaddr: Ipv6Addr, interface: u32) -> std::io::Result<()> {
+        if self
+            .options()
+            .multicast_groups_v6
+            .remove(&(multiaddr, interface))
+        {
+            Ok(())
+        } else {
+            Err(std::io::ErrorKind::AddrNotAvailable.into())
+        }
+    }
+
+    fn set_multicast_loop_v6(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.options().is_multicast_loop_v6 = is_enabled;
+        Ok(())
+    }
+
+    fn multicast_loop_v6(&self) -> std::io::Result<bool> {
+        Ok(self.options().is_multicast_loop_v6)
+    }
+
+    fn ttl(&self) -> std::io::Result<u32> {
+        Ok(self.options().ttl)
+    }
+
+    fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+        self.options().ttl = ttl;
+        Ok(())
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        Ok(None)
+    }
+}
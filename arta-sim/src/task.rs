@@ -0,0 +1,133 @@
+//! Simulated green thread management implementation.
+use crate::SimRuntime;
+use arta::task::{JoinError, RuntimeJoinHandle, TaskBuilder, TaskRuntime};
+use futures::{prelude::Future, FutureExt as _};
+use std::{
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Simulation specific [`RuntimeJoinHandle`] implementation.
+pub struct SimJoinHandle<T> {
+    // Task is cancelled when its handle is dropped so handle is kept in `Option` to detach
+    // the task on drop instead, matching other runtimes.
+    inner: Option<async_task::Task<std::thread::Result<T>>>,
+}
+
+impl<T> Future for SimJoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Handle is taken only by `cancel` and `drop` which consume it.
+        self.inner
+            .as_mut()
+            .unwrap()
+            .poll_unpin(cx)
+            .map_err(JoinError::panic)
+    }
+}
+
+impl<T> Drop for SimJoinHandle<T> {
+    fn drop(&mut self) {
+        if let Some(task) = self.inner.take() {
+            task.detach();
+        }
+    }
+}
+
+impl<T> RuntimeJoinHandle<T> for SimJoinHandle<T>
+where
+    T: Send + 'static,
+{
+    fn cancel(mut self) -> impl Future<Output = Result<T, JoinError>> + Send {
+        self.inner
+            .take()
+            .unwrap()
+            .cancel()
+            .map(|result| match result {
+                Some(result) => result.map_err(JoinError::panic),
+                None => Err(JoinError::cancelled()),
+            })
+    }
+}
+
+impl TaskRuntime for SimRuntime {
+    type JoinHandle<T>
+        = SimJoinHandle<T>
+    where
+        T: Send + 'static;
+
+    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        SimJoinHandle {
+            inner: Some(
+                self.shared
+                    .executor
+                    .spawn(AssertUnwindSafe(future).catch_unwind()),
+            ),
+        }
+    }
+
+    /// Blocking tasks are executed on the simulation thread, blocking every other task until
+    /// they complete.
+    fn spawn_blocking<R>(&self, task: impl FnOnce() -> R + Send + 'static) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        SimJoinHandle {
+            inner: Some(
+                self.shared
+                    .executor
+                    .spawn(async { std::panic::catch_unwind(AssertUnwindSafe(task)) }),
+            ),
+        }
+    }
+
+    /// Runs a future to completion, executing spawned tasks and advancing the virtual clock
+    /// whenever there is no task ready to make progress.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called from inside of a simulation or if the future can never complete
+    /// because every task waits for something other than a timer.
+    fn block_on<R>(&self, future: impl Future<Output = R>) -> R {
+        self.run(future)
+    }
+
+    fn yield_now(&self) -> impl Future<Output = ()> + Send {
+        // Future passed to `block_on` is polled before any task once woken, so it's woken by a
+        // task to let other ready tasks run first.
+        let runtime = self.clone();
+        let mut is_yielded = false;
+        futures::future::poll_fn(move |cx| {
+            if is_yielded {
+                Poll::Ready(())
+            } else {
+                is_yielded = true;
+                let waker = cx.waker().clone();
+                runtime
+                    .shared
+                    .executor
+                    .spawn(async move { waker.wake() })
+                    .detach();
+                Poll::Pending
+            }
+        })
+    }
+
+    /// Options are ignored, blocking tasks are executed on the simulation thread like ones
+    /// spawned with `spawn_blocking` to keep the simulation deterministic.
+    fn spawn_blocking_with<R>(
+        &self,
+        _builder: &TaskBuilder,
+        task: impl FnOnce() -> R + Send + 'static,
+    ) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        self.spawn_blocking(task)
+    }
+}
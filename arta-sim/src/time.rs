@@ -0,0 +1,143 @@
+//! Simulated timer implementation.
+use crate::{Shared, SimRuntime};
+use arta::time::{RuntimeSleep, TimeRuntime};
+use futures::Future;
+use std::{
+    collections::BTreeMap,
+    fmt,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll, Waker},
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Virtual clock of a simulation.
+#[derive(Default)]
+pub(crate) struct Clock {
+    now: Duration,
+    timers: BTreeMap<(Duration, u64), Waker>,
+    next_timer_id: u64,
+}
+
+impl Clock {
+    pub(crate) const fn now(&self) -> Duration {
+        self.now
+    }
+
+    /// Registers a waker to be woken once the clock reaches `deadline`. Returns a key that can
+    /// be used to remove the timer.
+    pub(crate) fn register(&mut self, deadline: Duration, waker: Waker) -> (Duration, u64) {
+        let key = (deadline, self.next_timer_id);
+        self.next_timer_id += 1;
+        self.timers.insert(key, waker);
+
+        key
+    }
+
+    /// Removes a timer and returns its waker.
+    pub(crate) fn remove(&mut self, key: (Duration, u64)) -> Option<Waker> {
+        self.timers.remove(&key)
+    }
+
+    /// Moves the clock to the nearest timer deadline and returns wakers of all timers that
+    /// expired.
+    pub(crate) fn advance(&mut self) -> Vec<Waker> {
+        let Some((&(deadline, _), _)) = self.timers.first_key_value() else {
+            return Vec::new();
+        };
+        self.now = self.now.max(deadline);
+
+        let pending = self.timers.split_off(&(self.now, u64::MAX));
+        std::mem::replace(&mut self.timers, pending)
+            .into_values()
+            .collect()
+    }
+}
+
+/// Future that completes once the virtual clock reaches its deadline.
+pub struct SimSleep {
+    shared: Arc<Shared>,
+    deadline: Duration,
+    timer: Option<(Duration, u64)>,
+}
+
+impl SimSleep {
+    pub(crate) fn until(runtime: &SimRuntime, deadline: Duration) -> Self {
+        Self {
+            shared: Arc::clone(&runtime.shared),
+            deadline,
+            timer: None,
+        }
+    }
+}
+
+impl Future for SimSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let shared = Arc::clone(&self.shared);
+        let mut clock = shared.clock.lock().unwrap();
+        if let Some(timer) = self.timer.take() {
+            clock.remove(timer);
+        }
+
+        if clock.now() >= self.deadline {
+            Poll::Ready(())
+        } else {
+            self.timer = Some(clock.register(self.deadline, cx.waker().clone()));
+            Poll::Pending
+        }
+    }
+}
+
+impl RuntimeSleep for SimSleep {
+    fn deadline(&self) -> Instant {
+        self.shared.start + self.deadline
+    }
+
+    fn reset(&mut self, deadline: Instant) {
+        self.deadline = deadline.saturating_duration_since(self.shared.start);
+
+        let mut clock = self.shared.clock.lock().unwrap();
+        if let Some(waker) = self.timer.take().and_then(|timer| clock.remove(timer)) {
+            self.timer = Some(clock.register(self.deadline, waker));
+        }
+    }
+}
+
+impl fmt::Debug for SimSleep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SimSleep")
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Drop for SimSleep {
+    fn drop(&mut self) {
+        if let Some(timer) = self.timer.take() {
+            self.shared.clock.lock().unwrap().remove(timer);
+        }
+    }
+}
+
+impl TimeRuntime for SimRuntime {
+    type Sleep = SimSleep;
+
+    /// Returns the instant of the virtual clock. Virtual instants are offset from the real instant
+    /// the simulation was created at.
+    fn now(&self) -> Instant {
+        self.shared.start + self.elapsed()
+    }
+
+    /// Returns the wall-clock time configured by
+    /// [`SimRuntimeBuilder::system_time`](crate::SimRuntimeBuilder::system_time) advanced by the
+    /// virtual clock.
+    fn system_now(&self) -> SystemTime {
+        self.shared.system_start + self.elapsed()
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        SimSleep::until(self, deadline.saturating_duration_since(self.shared.start))
+    }
+}
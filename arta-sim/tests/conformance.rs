@@ -0,0 +1,8 @@
+use arta::task::TaskRuntime;
+use arta_sim::SimRuntime;
+
+arta_conformance::conformance_tests! {
+    runtime: SimRuntime = SimRuntime::new(0),
+    block_on: |runtime, future| runtime.block_on(future),
+    suites: [net, task, time],
+}
@@ -0,0 +1,318 @@
+use arta::{
+    net::{RuntimeTcpListener, RuntimeTcpStream, RuntimeUdpSocket},
+    task::TaskRuntime,
+    time::{JobBuilder, OverlapPolicy, RetryBuilder, Schedule, TimeRuntime},
+};
+use arta_sim::{
+    net::{SimTcpListener, SimTcpStream, SimUdpSocket},
+    SimRuntime, SimRuntimeBuilder,
+};
+use futures::{channel::oneshot, AsyncWriteExt, FutureExt};
+use std::{
+    collections::HashSet,
+    io::ErrorKind,
+    net::{IpAddr, Ipv4Addr},
+    pin::pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+
+const SERVER_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+const CLIENT_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+const OTHER_IP: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 3));
+const RECV_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Runs tasks that interleave on every yield and datagrams with random latencies, returning the
+/// order of task steps and virtual arrival times of datagrams.
+fn run_workload(seed: u64) -> (Vec<usize>, Vec<Duration>) {
+    let runtime = SimRuntimeBuilder::new()
+        .seed(seed)
+        .latency(Duration::from_millis(1), Duration::from_millis(50))
+        .build();
+    runtime.block_on(async {
+        let steps = Arc::new(Mutex::new(Vec::new()));
+        let handles: Vec<_> = (0..8)
+            .map(|id| {
+                let runtime = runtime.clone();
+                let steps = Arc::clone(&steps);
+                runtime.clone().spawn(async move {
+                    for _ in 0..3 {
+                        runtime.yield_now().await;
+                        steps.lock().unwrap().push(id);
+                    }
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.await.unwrap();
+        }
+
+        let sender = SimUdpSocket::bind(&runtime, (Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let receiver = SimUdpSocket::bind(&runtime, (Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let addr = receiver.local_addr().unwrap();
+        let mut arrivals = Vec::new();
+        for _ in 0..8 {
+            sender.send_to(b"ping", addr).await.unwrap();
+            receiver.recv_from(&mut [0u8; 4]).await.unwrap();
+            arrivals.push(runtime.elapsed());
+        }
+
+        let steps = steps.lock().unwrap().clone();
+        (steps, arrivals)
+    })
+}
+
+/// Receives datagrams until none arrives within [`RECV_TIMEOUT`], returning their count.
+async fn recv_all(runtime: &SimRuntime, socket: &SimUdpSocket) -> usize {
+    let mut count = 0;
+    let mut buf = [0u8; 16];
+    while runtime
+        .timeout(RECV_TIMEOUT, socket.recv_from(&mut buf))
+        .await
+        .is_ok()
+    {
+        count += 1;
+    }
+    count
+}
+
+#[test]
+fn same_seed_replays_execution() {
+    let first = run_workload(7);
+    assert_eq!(first, run_workload(7));
+    assert_eq!(first.0.len(), 24);
+
+    let distinct: HashSet<_> = (0..16).map(run_workload).collect();
+    assert!(distinct.len() > 1);
+}
+
+#[test]
+fn partition_and_heal_tcp() {
+    let runtime = SimRuntime::new(0);
+    let (server, client, other) = (
+        runtime.node(SERVER_IP),
+        runtime.node(CLIENT_IP),
+        runtime.node(OTHER_IP),
+    );
+
+    runtime.block_on(async {
+        let listener = SimTcpListener::bind(&server, (SERVER_IP, 80))
+            .await
+            .unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        runtime.partition(CLIENT_IP, SERVER_IP);
+        let result = SimTcpStream::connect(&client, addr).await;
+        assert_eq!(
+            result.err().map(|err| err.kind()),
+            Some(ErrorKind::TimedOut)
+        );
+
+        // Partitions only affect the specified pair of hosts.
+        let (stream, accepted) =
+            futures::join!(SimTcpStream::connect(&other, addr), listener.accept());
+        drop((stream.unwrap(), accepted.unwrap()));
+
+        // Partitions are symmetric.
+        runtime.heal(SERVER_IP, CLIENT_IP);
+        let (stream, accepted) =
+            futures::join!(SimTcpStream::connect(&client, addr), listener.accept());
+        let (stream, _accepted) = (stream.unwrap(), accepted.unwrap());
+        let mut stream = pin!(stream);
+        stream.write_all(b"before").await.unwrap();
+
+        runtime.partition(SERVER_IP, CLIENT_IP);
+        let err = stream.write_all(b"during").await.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::ConnectionReset);
+
+        runtime.heal_all();
+        let (stream, accepted) =
+            futures::join!(SimTcpStream::connect(&client, addr), listener.accept());
+        drop((stream.unwrap(), accepted.unwrap()));
+    });
+}
+
+#[test]
+fn partition_and_heal_udp() {
+    let runtime = SimRuntime::new(0);
+    let (server, client) = (runtime.node(SERVER_IP), runtime.node(CLIENT_IP));
+
+    runtime.block_on(async {
+        let receiver = SimUdpSocket::bind(&server, (SERVER_IP, 0)).await.unwrap();
+        let sender = SimUdpSocket::bind(&client, (CLIENT_IP, 0)).await.unwrap();
+        let addr = receiver.local_addr().unwrap();
+
+        runtime.partition(SERVER_IP, CLIENT_IP);
+        sender.send_to(b"dropped", addr).await.unwrap();
+        assert_eq!(recv_all(&runtime, &receiver).await, 0);
+
+        runtime.heal(CLIENT_IP, SERVER_IP);
+        sender.send_to(b"delivered", addr).await.unwrap();
+        assert_eq!(recv_all(&runtime, &receiver).await, 1);
+
+        runtime.partition(SERVER_IP, CLIENT_IP);
+        runtime.partition(SERVER_IP, OTHER_IP);
+        runtime.heal_all();
+        sender.send_to(b"delivered", addr).await.unwrap();
+        assert_eq!(recv_all(&runtime, &receiver).await, 1);
+    });
+}
+
+#[test]
+fn latency_stays_within_bounds() {
+    let (min, max) = (Duration::from_millis(5), Duration::from_millis(20));
+    let runtime = SimRuntimeBuilder::new().latency(min, max).build();
+
+    runtime.block_on(async {
+        let sender = SimUdpSocket::bind(&runtime, (Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let receiver = SimUdpSocket::bind(&runtime, (Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let addr = receiver.local_addr().unwrap();
+        let mut buf = [0u8; 4];
+
+        let mut latencies = HashSet::new();
+        for _ in 0..100 {
+            let sent = runtime.elapsed();
+            sender.send_to(b"ping", addr).await.unwrap();
+            receiver.recv_from(&mut buf).await.unwrap();
+            let latency = runtime.elapsed() - sent;
+            assert!((min..=max).contains(&latency), "{latency:?}");
+            latencies.insert(latency);
+        }
+        assert!(latencies.len() > 1);
+
+        let fixed = Duration::from_millis(30);
+        runtime.set_latency(fixed, fixed);
+        let sent = runtime.elapsed();
+        sender.send_to(b"ping", addr).await.unwrap();
+        receiver.recv_from(&mut buf).await.unwrap();
+        assert_eq!(runtime.elapsed() - sent, fixed);
+
+        // The upper bound is raised to the lower one.
+        runtime.set_latency(Duration::from_millis(10), Duration::from_millis(1));
+        let sent = runtime.elapsed();
+        sender.send_to(b"ping", addr).await.unwrap();
+        receiver.recv_from(&mut buf).await.unwrap();
+        assert_eq!(runtime.elapsed() - sent, Duration::from_millis(10));
+    });
+}
+
+#[test]
+fn packet_loss_drops_datagrams() {
+    let runtime = SimRuntimeBuilder::new().packet_loss(1.0).build();
+
+    runtime.block_on(async {
+        let sender = SimUdpSocket::bind(&runtime, (Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let receiver = SimUdpSocket::bind(&runtime, (Ipv4Addr::LOCALHOST, 0))
+            .await
+            .unwrap();
+        let addr = receiver.local_addr().unwrap();
+        let send = |count| {
+            let sender = &sender;
+            async move {
+                for _ in 0..count {
+                    sender.send_to(b"ping", addr).await.unwrap();
+                }
+            }
+        };
+
+        send(100).await;
+        assert_eq!(recv_all(&runtime, &receiver).await, 0);
+
+        runtime.set_packet_loss(0.0);
+        send(100).await;
+        assert_eq!(recv_all(&runtime, &receiver).await, 100);
+
+        runtime.set_packet_loss(0.5);
+        send(1000).await;
+        let received = recv_all(&runtime, &receiver).await;
+        assert!((400..=600).contains(&received), "{received}");
+    });
+}
+
+#[test]
+#[should_panic(expected = "packet loss probability")]
+fn packet_loss_rejects_nan() {
+    SimRuntimeBuilder::new().packet_loss(f64::NAN);
+}
+
+#[test]
+#[should_panic(expected = "packet loss probability")]
+fn packet_loss_rejects_negative() {
+    SimRuntimeBuilder::new().packet_loss(-0.1);
+}
+
+#[test]
+#[should_panic(expected = "packet loss probability")]
+fn set_packet_loss_rejects_above_one() {
+    SimRuntime::new(0).set_packet_loss(1.5);
+}
+
+#[test]
+fn retry_jitter_is_reproducible_with_seed() {
+    let run = || {
+        let runtime = SimRuntime::new(3);
+        runtime.block_on(async {
+            let output: Result<(), ()> = RetryBuilder::new()
+                .seed(runtime.seed())
+                .max_attempts(5)
+                .run(&runtime, || futures::future::ready(Err(())))
+                .await;
+            assert_eq!(output, Err(()));
+            runtime.elapsed()
+        })
+    };
+    assert_eq!(run(), run());
+}
+
+/// Returns the numbers of started runs of a job while its first run is blocked and half a period
+/// after it's unblocked.
+fn overlapping_runs(overlap: OverlapPolicy) -> (usize, usize) {
+    let runtime = SimRuntime::new(0);
+    runtime.block_on(async {
+        let period = Duration::from_millis(50);
+        let (open, gate) = oneshot::channel::<()>();
+        let gate = gate.shared();
+        let started = Arc::new(AtomicUsize::new(0));
+
+        let job_started = Arc::clone(&started);
+        let handle = JobBuilder::new(Schedule::Every(period))
+            .overlap(overlap)
+            .spawn(&runtime, move || {
+                job_started.fetch_add(1, Ordering::SeqCst);
+                let gate = gate.clone();
+                async move { gate.await.unwrap() }
+            });
+
+        // Stay clear of scheduled runs, so they don't race with the checks.
+        runtime.sleep(period * 5 + period / 4).await;
+        let while_blocked = started.load(Ordering::SeqCst);
+        open.send(()).unwrap();
+        runtime.sleep(period / 2).await;
+        let after_unblocked = started.load(Ordering::SeqCst);
+
+        handle.cancel();
+        handle.await.unwrap_err();
+        (while_blocked, after_unblocked)
+    })
+}
+
+#[test]
+fn scheduled_job_overlap() {
+    // The first run starts after a period, four more runs are due while it's blocked.
+    assert_eq!(overlapping_runs(OverlapPolicy::Skip), (1, 1));
+    assert_eq!(overlapping_runs(OverlapPolicy::Queue), (1, 5));
+    assert_eq!(overlapping_runs(OverlapPolicy::Concurrent), (5, 5));
+}
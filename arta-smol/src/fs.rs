@@ -0,0 +1,156 @@
+//! Smol specific filesystem manipulation implementations.
+mod dir_entry;
+mod file;
+
+pub use dir_entry::*;
+pub use file::*;
+
+use crate::SmolGlobalRuntime;
+use arta::fs::{FSRuntime, Metadata, Permissions};
+use futures::{prelude::Stream, StreamExt as _, TryFutureExt as _};
+use std::{
+    future::Future,
+    path::{Path, PathBuf},
+};
+
+impl FSRuntime for SmolGlobalRuntime {
+    type File = SmolFile;
+    type DirEntry = SmolDirEntry;
+
+    fn canonicalize(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<PathBuf>> + Send {
+        async_fs::canonicalize(path)
+    }
+
+    fn copy(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<u64>> + Send {
+        async_fs::copy(from, to)
+    }
+
+    fn create_dir(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::create_dir(path)
+    }
+
+    fn create_dir_all(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::create_dir_all(path)
+    }
+
+    fn remove_dir(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::remove_dir(path)
+    }
+
+    fn remove_dir_all(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::remove_dir_all(path)
+    }
+
+    fn read_dir(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<
+        Output = std::io::Result<impl Stream<Item = std::io::Result<Self::DirEntry>> + Send>,
+    > + Send {
+        async_fs::read_dir(path)
+            .map_ok(|stream| stream.map(|entry| entry.map(|entry| SmolDirEntry { inner: entry })))
+    }
+
+    fn read_link(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<PathBuf>> + Send {
+        async_fs::read_link(path)
+    }
+
+    #[cfg(unix)]
+    fn symlink(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::unix::symlink(from, to)
+    }
+
+    #[cfg(windows)]
+    fn symlink_dir(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::windows::symlink_dir(from, to)
+    }
+
+    #[cfg(windows)]
+    fn symlink_file(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::windows::symlink_file(from, to)
+    }
+
+    fn hard_link(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::hard_link(from, to)
+    }
+
+    fn metadata(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        async_fs::metadata(path).map_ok(Into::into)
+    }
+
+    fn remove_file(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::remove_file(path)
+    }
+
+    fn rename(
+        &self,
+        from: impl AsRef<Path> + Send,
+        to: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        async_fs::rename(from, to)
+    }
+
+    fn set_permissions(
+        &self,
+        path: impl AsRef<Path> + Send,
+        permissions: Permissions,
+    ) -> impl Future<Output = std::io::Result<()>> + Send {
+        let path = path.as_ref().to_owned();
+        async move {
+            let current = async_fs::metadata(&path).map_ok(|metadata| metadata.permissions());
+            let permissions = permissions.into_os_permissions(current).await?;
+            async_fs::set_permissions(path, permissions).await
+        }
+    }
+
+    fn symlink_metadata(
+        &self,
+        path: impl AsRef<Path> + Send,
+    ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        async_fs::symlink_metadata(path).map_ok(Into::into)
+    }
+}
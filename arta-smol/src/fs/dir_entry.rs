@@ -0,0 +1,31 @@
+use arta::fs::{FileType, Metadata, RuntimeDirEntry};
+use futures::TryFutureExt as _;
+use std::{ffi::OsString, future::Future, path::PathBuf};
+
+/// Smol specific [`RuntimeDirEntry`] implementation.
+pub struct SmolDirEntry {
+    pub(super) inner: async_fs::DirEntry,
+}
+
+impl RuntimeDirEntry for SmolDirEntry {
+    #[cfg(unix)]
+    fn ino(&self) -> u64 {
+        async_fs::unix::DirEntryExt::ino(&self.inner)
+    }
+
+    fn file_name(&self) -> OsString {
+        self.inner.file_name()
+    }
+
+    fn file_type(&self) -> impl Future<Output = std::io::Result<FileType>> + Send {
+        self.inner.file_type().map_ok(Into::into)
+    }
+
+    fn metadata(&self) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        self.inner.metadata().map_ok(Into::into)
+    }
+
+    fn path(&self) -> PathBuf {
+        self.inner.path()
+    }
+}
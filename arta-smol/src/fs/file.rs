@@ -0,0 +1,140 @@
+use crate::SmolGlobalRuntime;
+use arta::fs::{Metadata, OpenOptions, Permissions, RuntimeFile};
+use cfg_if::cfg_if;
+use futures::{AsyncRead, AsyncSeek, AsyncWrite, TryFutureExt as _};
+use std::{
+    future::Future,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+cfg_if! {
+    if #[cfg(windows)] {
+        impl std::os::windows::io::AsRawHandle for SmolFile {
+            fn as_raw_handle(&self) -> std::os::windows::io::RawHandle {
+                self.inner.as_raw_handle()
+            }
+        }
+
+        impl std::os::windows::io::AsHandle for SmolFile {
+            fn as_handle(&self) -> std::os::windows::io::BorrowedHandle<'_> {
+                self.inner.as_handle()
+            }
+        }
+
+        impl From<std::os::windows::io::OwnedHandle> for SmolFile {
+            fn from(handle: std::os::windows::io::OwnedHandle) -> Self {
+                Self { inner: async_fs::File::from(handle) }
+            }
+        }
+    } else if #[cfg(any(unix, target_os = "wasi"))] {
+        impl std::os::fd::AsRawFd for SmolFile {
+            fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+                self.inner.as_raw_fd()
+            }
+        }
+
+        impl std::os::fd::AsFd for SmolFile {
+            fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+                self.inner.as_fd()
+            }
+        }
+
+        impl From<std::os::fd::OwnedFd> for SmolFile {
+            fn from(fd: std::os::fd::OwnedFd) -> Self {
+                Self { inner: async_fs::File::from(fd) }
+            }
+        }
+    }
+}
+
+/// Smol specific [`RuntimeFile`] implementation.
+pub struct SmolFile {
+    inner: async_fs::File,
+}
+
+impl AsyncRead for SmolFile {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for SmolFile {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+}
+
+impl AsyncSeek for SmolFile {
+    fn poll_seek(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        pos: std::io::SeekFrom,
+    ) -> Poll<std::io::Result<u64>> {
+        Pin::new(&mut self.get_mut().inner).poll_seek(cx, pos)
+    }
+}
+
+impl RuntimeFile for SmolFile {
+    type Runtime = SmolGlobalRuntime;
+
+    fn open(
+        _runtime: &Self::Runtime,
+        open_options: &OpenOptions,
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        // async_fs::OpenOptions can't be built from std one so open the file on
+        // blocking thread pool instead.
+        let open_options = std::fs::OpenOptions::from(open_options);
+        let path = path.as_ref().to_owned();
+
+        blocking::unblock(move || open_options.open(path)).map_ok(|file| Self {
+            inner: async_fs::File::from(file),
+        })
+    }
+
+    fn set_len(&self, size: u64) -> impl Future<Output = std::io::Result<()>> + Send {
+        self.inner.set_len(size)
+    }
+
+    fn metadata(&self) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+        self.inner.metadata().map_ok(Into::into)
+    }
+
+    fn sync_all(&self) -> impl Future<Output = std::io::Result<()>> + Send {
+        self.inner.sync_all()
+    }
+
+    fn sync_data(&self) -> impl Future<Output = std::io::Result<()>> + Send {
+        self.inner.sync_data()
+    }
+
+    async fn set_permissions(&self, permissions: Permissions) -> std::io::Result<()> {
+        let current = self
+            .inner
+            .metadata()
+            .map_ok(|metadata| metadata.permissions());
+        let permissions = permissions.into_os_permissions(current).await?;
+        self.inner.set_permissions(permissions).await
+    }
+}
@@ -0,0 +1,49 @@
+//! Smol specific TCP/UDP and Unix domain socket communication implementation.
+mod tcp_listener;
+mod tcp_socket;
+mod tcp_stream;
+mod udp_socket;
+#[cfg(unix)]
+mod unix_datagram;
+#[cfg(unix)]
+mod unix_listener;
+#[cfg(unix)]
+mod unix_stream;
+
+pub use tcp_listener::*;
+pub use tcp_socket::*;
+pub use tcp_stream::*;
+pub use udp_socket::*;
+#[cfg(unix)]
+pub use unix_datagram::*;
+#[cfg(unix)]
+pub use unix_listener::*;
+#[cfg(unix)]
+pub use unix_stream::*;
+
+use crate::SmolGlobalRuntime;
+use arta::net::NetRuntime;
+use std::{future::Future, net::SocketAddr};
+
+impl NetRuntime for SmolGlobalRuntime {
+    type TcpListener = SmolTcpListener;
+    type TcpStream = SmolTcpStream;
+    type TcpSocket = SmolTcpSocket;
+    type UdpSocket = SmolUdpSocket;
+
+    fn resolve(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> impl Future<Output = std::io::Result<Vec<SocketAddr>>> + Send {
+        // Calls `getaddrinfo` on the blocking thread pool.
+        async_net::resolve((host, port))
+    }
+}
+
+#[cfg(unix)]
+impl arta::net::UnixNetRuntime for SmolGlobalRuntime {
+    type UnixListener = SmolUnixListener;
+    type UnixStream = SmolUnixStream;
+    type UnixDatagram = SmolUnixDatagram;
+}
@@ -0,0 +1,88 @@
+use super::SmolTcpStream;
+use crate::SmolGlobalRuntime;
+use arta::net::{NetRuntime, RuntimeTcpListener, ToSocketAddrs};
+use cfg_if::cfg_if;
+use futures::{prelude::Future, TryFutureExt as _};
+use std::net::SocketAddr;
+
+cfg_if! {
+    if #[cfg(windows)] {
+        impl std::os::windows::io::AsRawSocket for SmolTcpListener {
+            fn as_raw_socket(&self) -> std::os::windows::io::RawSocket {
+                self.inner.as_raw_socket()
+            }
+        }
+
+        impl std::os::windows::io::AsSocket for SmolTcpListener {
+            fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
+                self.inner.as_socket()
+            }
+        }
+
+        impl From<std::os::windows::io::OwnedSocket> for SmolTcpListener {
+            fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+                Self { inner: async_net::TcpListener::try_from(socket).unwrap() }
+            }
+        }
+    } else if #[cfg(any(unix, target_os = "wasi"))] {
+        impl std::os::fd::AsRawFd for SmolTcpListener {
+            fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+                self.inner.as_raw_fd()
+            }
+        }
+
+        impl std::os::fd::AsFd for SmolTcpListener {
+            fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+                self.inner.as_fd()
+            }
+        }
+
+        impl From<std::os::fd::OwnedFd> for SmolTcpListener {
+            fn from(fd: std::os::fd::OwnedFd) -> Self {
+                Self { inner: async_net::TcpListener::try_from(fd).unwrap() }
+            }
+        }
+    }
+}
+
+/// Smol specific [`RuntimeTcpListener`] implementation.
+pub struct SmolTcpListener {
+    pub(super) inner: async_net::TcpListener,
+}
+
+impl RuntimeTcpListener for SmolTcpListener {
+    type Runtime = SmolGlobalRuntime;
+
+    fn accept(
+        &self,
+    ) -> impl Future<Output = std::io::Result<(<Self::Runtime as NetRuntime>::TcpStream, SocketAddr)>>
+           + Send {
+        self.inner
+            .accept()
+            .map_ok(|(stream, addr)| (SmolTcpStream { inner: stream }, addr))
+    }
+
+    fn bind(
+        runtime: &Self::Runtime,
+        addr: impl ToSocketAddrs<Self::Runtime>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        addr.for_each_resolved_addr_until_success(runtime, |addr| {
+            async_net::TcpListener::bind(addr).map_ok(|listener| Self { inner: listener })
+        })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn ttl(&self) -> std::io::Result<u32> {
+        self.inner.ttl()
+    }
+
+    fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+        self.inner.set_ttl(ttl)
+    }
+}
@@ -0,0 +1,324 @@
+use crate::SmolGlobalRuntime;
+use arta::net::{ReuniteError, RuntimeTcpStream, TcpKeepalive, TcpStreamOptions, ToSocketAddrs};
+use async_io::Async;
+use cfg_if::cfg_if;
+use futures::{prelude::Future, ready, AsyncRead, AsyncWrite, TryFutureExt as _};
+use socket2::SockRef;
+use std::{
+    net::{Shutdown, SocketAddr},
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+    time::Duration,
+};
+
+cfg_if! {
+    if #[cfg(windows)] {
+        impl std::os::windows::io::AsRawSocket for SmolTcpStream {
+            fn as_raw_socket(&self) -> std::os::windows::io::RawSocket {
+                self.inner.as_raw_socket()
+            }
+        }
+
+        impl std::os::windows::io::AsSocket for SmolTcpStream {
+            fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
+                self.inner.as_socket()
+            }
+        }
+
+        impl From<std::os::windows::io::OwnedSocket> for SmolTcpStream {
+            fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+                Self { inner: async_net::TcpStream::try_from(socket).unwrap() }
+            }
+        }
+    } else if #[cfg(any(unix, target_os = "wasi"))] {
+        impl std::os::fd::AsRawFd for SmolTcpStream {
+            fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+                self.inner.as_raw_fd()
+            }
+        }
+
+        impl std::os::fd::AsFd for SmolTcpStream {
+            fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+                self.inner.as_fd()
+            }
+        }
+
+        impl From<std::os::fd::OwnedFd> for SmolTcpStream {
+            fn from(fd: std::os::fd::OwnedFd) -> Self {
+                Self { inner: async_net::TcpStream::try_from(fd).unwrap() }
+            }
+        }
+    }
+}
+
+/// Smol specific [`RuntimeTcpStream`] implementation.
+pub struct SmolTcpStream {
+    pub(super) inner: async_net::TcpStream,
+}
+
+impl RuntimeTcpStream for SmolTcpStream {
+    type Runtime = SmolGlobalRuntime;
+    type OwnedReadHalf = SmolTcpReadHalf;
+    type OwnedWriteHalf = SmolTcpWriteHalf;
+
+    fn connect(
+        runtime: &Self::Runtime,
+        addr: impl ToSocketAddrs<Self::Runtime>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        addr.for_each_resolved_addr_until_success(runtime, |addr| {
+            async_net::TcpStream::connect(addr).map_ok(|stream| Self { inner: stream })
+        })
+    }
+
+    fn into_split(self) -> (Self::OwnedReadHalf, Self::OwnedWriteHalf) {
+        let inner: Arc<Async<std::net::TcpStream>> = self.inner.into();
+        (
+            SmolTcpReadHalf {
+                inner: Arc::clone(&inner),
+            },
+            SmolTcpWriteHalf { inner },
+        )
+    }
+
+    fn reunite(
+        read: Self::OwnedReadHalf,
+        write: Self::OwnedWriteHalf,
+    ) -> Result<Self, ReuniteError<Self::OwnedReadHalf, Self::OwnedWriteHalf>> {
+        if !Arc::ptr_eq(&read.inner, &write.inner) {
+            return Err(ReuniteError(read, write));
+        }
+        drop(write);
+        Arc::try_unwrap(read.inner)
+            .map(|inner| Self {
+                inner: inner.into(),
+            })
+            .map_err(|inner| {
+                ReuniteError(
+                    SmolTcpReadHalf {
+                        inner: Arc::clone(&inner),
+                    },
+                    SmolTcpWriteHalf { inner },
+                )
+            })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn peer_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.peer_addr()
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    fn linger(&self) -> std::io::Result<Option<Duration>> {
+        SockRef::from(self).linger()
+    }
+
+    #[cfg(not(target_os = "wasi"))]
+    fn set_linger(&self, linger: Option<Duration>) -> std::io::Result<()> {
+        SockRef::from(self).set_linger(linger)
+    }
+
+    fn nodelay(&self) -> std::io::Result<bool> {
+        self.inner.nodelay()
+    }
+
+    fn set_nodelay(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.inner.set_nodelay(is_enabled)
+    }
+
+    fn ttl(&self) -> std::io::Result<u32> {
+        self.inner.ttl()
+    }
+
+    fn set_ttl(&self, ttl: u32) -> std::io::Result<()> {
+        self.inner.set_ttl(ttl)
+    }
+
+    fn peek(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        self.inner.peek(buf)
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        SockRef::from(self).take_error()
+    }
+}
+
+impl TcpStreamOptions for SmolTcpStream {
+    fn keepalive(&self) -> std::io::Result<bool> {
+        SockRef::from(self).keepalive()
+    }
+
+    fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> std::io::Result<()> {
+        match keepalive {
+            Some(keepalive) => {
+                SockRef::from(self).set_tcp_keepalive(&socket2::TcpKeepalive::try_from(keepalive)?)
+            }
+            None => SockRef::from(self).set_keepalive(false),
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn user_timeout(&self) -> std::io::Result<Option<Duration>> {
+        SockRef::from(self).tcp_user_timeout()
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn set_user_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        SockRef::from(self).set_tcp_user_timeout(timeout)
+    }
+
+    fn send_buffer_size(&self) -> std::io::Result<u32> {
+        SockRef::from(self)
+            .send_buffer_size()
+            .map(|size| u32::try_from(size).unwrap_or(u32::MAX))
+    }
+
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        SockRef::from(self).set_send_buffer_size(size as usize)
+    }
+
+    fn recv_buffer_size(&self) -> std::io::Result<u32> {
+        SockRef::from(self)
+            .recv_buffer_size()
+            .map(|size| u32::try_from(size).unwrap_or(u32::MAX))
+    }
+
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        SockRef::from(self).set_recv_buffer_size(size as usize)
+    }
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn tos(&self) -> std::io::Result<u32> {
+        SockRef::from(self).tos()
+    }
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn set_tos(&self, tos: u32) -> std::io::Result<()> {
+        SockRef::from(self).set_tos(tos)
+    }
+
+    fn shutdown(&self, how: Shutdown) -> std::io::Result<()> {
+        self.inner.shutdown(how)
+    }
+}
+
+impl AsyncRead for SmolTcpStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read_vectored(cx, bufs)
+    }
+}
+
+impl AsyncWrite for SmolTcpStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
+
+/// Smol specific owned read half of a [`SmolTcpStream`].
+#[derive(Debug)]
+pub struct SmolTcpReadHalf {
+    inner: Arc<Async<std::net::TcpStream>>,
+}
+
+impl AsyncRead for SmolTcpReadHalf {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut &*self.inner).poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut &*self.inner).poll_read_vectored(cx, bufs)
+    }
+}
+
+/// Smol specific owned write half of a [`SmolTcpStream`].
+#[derive(Debug)]
+pub struct SmolTcpWriteHalf {
+    inner: Arc<Async<std::net::TcpStream>>,
+}
+
+impl AsyncWrite for SmolTcpWriteHalf {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut &*self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut &*self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut &*self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        // Unlike `async_net::TcpStream`, `Async` only flushes on close, shutdown is required for
+        // a peer to observe the end of data.
+        ready!(Pin::new(&mut &*self.inner).poll_flush(cx))?;
+        Poll::Ready(self.inner.get_ref().shutdown(Shutdown::Write))
+    }
+}
@@ -0,0 +1,108 @@
+use crate::SmolGlobalRuntime;
+use arta::net::RuntimeUnixDatagram;
+use async_io::Async;
+use futures::prelude::Future;
+use socket2::SockRef;
+use std::{os::unix::net::SocketAddr, path::Path, sync::Arc};
+
+impl std::os::fd::AsRawFd for SmolUnixDatagram {
+    fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+impl std::os::fd::AsFd for SmolUnixDatagram {
+    fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+        let raw_fd = std::os::fd::AsRawFd::as_raw_fd(self);
+        unsafe { std::os::fd::BorrowedFd::borrow_raw(raw_fd) }
+    }
+}
+
+impl From<std::os::fd::OwnedFd> for SmolUnixDatagram {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self {
+            inner: async_net::unix::UnixDatagram::try_from(std::os::unix::net::UnixDatagram::from(
+                fd,
+            ))
+            .unwrap(),
+        }
+    }
+}
+
+/// Smol specific [`RuntimeUnixDatagram`] implementation.
+pub struct SmolUnixDatagram {
+    inner: async_net::unix::UnixDatagram,
+}
+
+impl RuntimeUnixDatagram for SmolUnixDatagram {
+    type Runtime = SmolGlobalRuntime;
+
+    fn bind_addr(_runtime: &Self::Runtime, addr: &SocketAddr) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            inner: std::os::unix::net::UnixDatagram::bind_addr(addr)?.try_into()?,
+        })
+    }
+
+    fn unbound(_runtime: &Self::Runtime) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            inner: async_net::unix::UnixDatagram::unbound()?,
+        })
+    }
+
+    fn pair(_runtime: &Self::Runtime) -> std::io::Result<(Self, Self)>
+    where
+        Self: Sized,
+    {
+        let (first, second) = async_net::unix::UnixDatagram::pair()?;
+        Ok((Self { inner: first }, Self { inner: second }))
+    }
+
+    fn send(&self, buf: &[u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        self.inner.send(buf)
+    }
+
+    fn send_to(
+        &self,
+        buf: &[u8],
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<usize>> + Send {
+        self.inner.send_to(buf, path.as_ref().to_owned())
+    }
+
+    async fn send_to_addr(&self, buf: &[u8], addr: &SocketAddr) -> std::io::Result<usize> {
+        // Async-net only sends to path names, so the datagram is sent by the underlying socket.
+        let socket: Arc<Async<std::os::unix::net::UnixDatagram>> = self.inner.clone().into();
+        socket
+            .write_with(|socket| socket.send_to_addr(buf, addr))
+            .await
+    }
+
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        self.inner.recv(buf)
+    }
+
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = std::io::Result<(usize, SocketAddr)>> + Send {
+        self.inner.recv_from(buf)
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn peer_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.peer_addr()
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        SockRef::from(self).take_error()
+    }
+}
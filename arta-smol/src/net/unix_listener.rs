@@ -0,0 +1,62 @@
+use super::SmolUnixStream;
+use crate::SmolGlobalRuntime;
+use arta::net::{RuntimeUnixListener, UnixNetRuntime};
+use futures::{prelude::Future, TryFutureExt as _};
+use socket2::SockRef;
+use std::os::unix::net::SocketAddr;
+
+impl std::os::fd::AsRawFd for SmolUnixListener {
+    fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+impl std::os::fd::AsFd for SmolUnixListener {
+    fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+        self.inner.as_fd()
+    }
+}
+
+impl From<std::os::fd::OwnedFd> for SmolUnixListener {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self {
+            inner: async_net::unix::UnixListener::try_from(fd).unwrap(),
+        }
+    }
+}
+
+/// Smol specific [`RuntimeUnixListener`] implementation.
+pub struct SmolUnixListener {
+    inner: async_net::unix::UnixListener,
+}
+
+impl RuntimeUnixListener for SmolUnixListener {
+    type Runtime = SmolGlobalRuntime;
+
+    fn accept(
+        &self,
+    ) -> impl Future<
+        Output = std::io::Result<(<Self::Runtime as UnixNetRuntime>::UnixStream, SocketAddr)>,
+    > + Send {
+        self.inner
+            .accept()
+            .map_ok(|(stream, addr)| (SmolUnixStream { inner: stream }, addr))
+    }
+
+    fn bind_addr(_runtime: &Self::Runtime, addr: &SocketAddr) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Ok(Self {
+            inner: std::os::unix::net::UnixListener::bind_addr(addr)?.try_into()?,
+        })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        SockRef::from(self).take_error()
+    }
+}
@@ -0,0 +1,133 @@
+use crate::SmolGlobalRuntime;
+use arta::net::RuntimeUnixStream;
+use futures::{prelude::Future, AsyncRead, AsyncWrite, TryFutureExt as _};
+use socket2::SockRef;
+use std::{
+    os::unix::net::SocketAddr,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+impl std::os::fd::AsRawFd for SmolUnixStream {
+    fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+impl std::os::fd::AsFd for SmolUnixStream {
+    fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+        self.inner.as_fd()
+    }
+}
+
+impl From<std::os::fd::OwnedFd> for SmolUnixStream {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self {
+            inner: async_net::unix::UnixStream::try_from(fd).unwrap(),
+        }
+    }
+}
+
+/// Smol specific [`RuntimeUnixStream`] implementation.
+pub struct SmolUnixStream {
+    pub(super) inner: async_net::unix::UnixStream,
+}
+
+impl RuntimeUnixStream for SmolUnixStream {
+    type Runtime = SmolGlobalRuntime;
+
+    fn connect(
+        _runtime: &Self::Runtime,
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        async_net::unix::UnixStream::connect(path.as_ref().to_owned())
+            .map_ok(|stream| Self { inner: stream })
+    }
+
+    fn connect_addr(
+        _runtime: &Self::Runtime,
+        addr: &SocketAddr,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        // Connecting blocks while the backlog of the listener is full.
+        let addr = addr.clone();
+        async move {
+            let stream =
+                blocking::unblock(move || std::os::unix::net::UnixStream::connect_addr(&addr))
+                    .await?;
+            Ok(Self {
+                inner: stream.try_into()?,
+            })
+        }
+    }
+
+    fn pair(_runtime: &Self::Runtime) -> std::io::Result<(Self, Self)>
+    where
+        Self: Sized,
+    {
+        let (first, second) = async_net::unix::UnixStream::pair()?;
+        Ok((Self { inner: first }, Self { inner: second }))
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn peer_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.peer_addr()
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        SockRef::from(self).take_error()
+    }
+}
+
+impl AsyncRead for SmolUnixStream {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read(cx, buf)
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_read_vectored(cx, bufs)
+    }
+}
+
+impl AsyncWrite for SmolUnixStream {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write_vectored(cx, bufs)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.inner).poll_close(cx)
+    }
+}
@@ -0,0 +1,16 @@
+//! Smol specific process manipulation implementation.
+mod child;
+mod command;
+mod outputs;
+
+pub use child::*;
+pub use command::*;
+pub use outputs::*;
+
+use crate::SmolGlobalRuntime;
+use arta::process::ProcessRuntime;
+
+impl ProcessRuntime for SmolGlobalRuntime {
+    type Command = SmolCommand;
+    type Child = SmolChild;
+}
@@ -0,0 +1,70 @@
+use super::{SmolStderr, SmolStdin, SmolStdout};
+use crate::SmolGlobalRuntime;
+use arta::process::RuntimeChild;
+use futures::prelude::Future;
+use std::process::{ExitStatus, Output};
+
+/// Smol specific [`RuntimeChild`] implementation.
+pub struct SmolChild {
+    pub(super) inner: async_process::Child,
+}
+
+impl RuntimeChild for SmolChild {
+    type Runtime = SmolGlobalRuntime;
+
+    type Stdin<'a>
+        = SmolStdin<'a>
+    where
+        Self: 'a;
+
+    type Stdout<'a>
+        = SmolStdout<'a>
+    where
+        Self: 'a;
+
+    type Stderr<'a>
+        = SmolStderr<'a>
+    where
+        Self: 'a;
+
+    fn stdin(&mut self) -> Option<Self::Stdin<'_>> {
+        self.inner
+            .stdin
+            .as_mut()
+            .map(|stdin| SmolStdin { inner: stdin })
+    }
+
+    fn stdout(&mut self) -> Option<Self::Stdout<'_>> {
+        self.inner
+            .stdout
+            .as_mut()
+            .map(|stdout| SmolStdout { inner: stdout })
+    }
+
+    fn stderr(&mut self) -> Option<Self::Stderr<'_>> {
+        self.inner
+            .stderr
+            .as_mut()
+            .map(|stderr| SmolStderr { inner: stderr })
+    }
+
+    fn id(&self) -> u32 {
+        self.inner.id()
+    }
+
+    fn kill(&mut self) -> std::io::Result<()> {
+        self.inner.kill()
+    }
+
+    fn output(self) -> impl Future<Output = std::io::Result<Output>> + Send {
+        self.inner.output()
+    }
+
+    fn status(&mut self) -> impl Future<Output = std::io::Result<ExitStatus>> + Send {
+        self.inner.status()
+    }
+
+    fn try_status(&mut self) -> std::io::Result<Option<ExitStatus>> {
+        self.inner.try_status()
+    }
+}
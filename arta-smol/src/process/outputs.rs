@@ -0,0 +1,159 @@
+use cfg_if::cfg_if;
+use futures::{AsyncRead, AsyncWrite};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Smol specific async stdin implementation.
+pub struct SmolStdin<'a> {
+    pub(super) inner: &'a mut async_process::ChildStdin,
+}
+
+/// Smol specific async stdout implementation.
+pub struct SmolStdout<'a> {
+    pub(super) inner: &'a mut async_process::ChildStdout,
+}
+
+/// Smol specific async stderr implementation.
+pub struct SmolStderr<'a> {
+    pub(super) inner: &'a mut async_process::ChildStderr,
+}
+
+impl AsyncWrite for SmolStdin<'_> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        AsyncWrite::poll_write(Pin::new(self.inner), cx, buf)
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        AsyncWrite::poll_write_vectored(Pin::new(self.inner), cx, bufs)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        AsyncWrite::poll_flush(Pin::new(self.inner), cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        AsyncWrite::poll_close(Pin::new(self.inner), cx)
+    }
+}
+
+impl AsyncRead for SmolStdout<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        AsyncRead::poll_read(Pin::new(self.inner), cx, buf)
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        AsyncRead::poll_read_vectored(Pin::new(self.inner), cx, bufs)
+    }
+}
+
+impl AsyncRead for SmolStderr<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        AsyncRead::poll_read(Pin::new(self.inner), cx, buf)
+    }
+
+    fn poll_read_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &mut [std::io::IoSliceMut<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        AsyncRead::poll_read_vectored(Pin::new(self.inner), cx, bufs)
+    }
+}
+
+cfg_if! {
+    if #[cfg(windows)] {
+        impl std::os::windows::io::AsRawHandle for SmolStdin<'_> {
+            fn as_raw_handle(&self) -> std::os::windows::io::RawHandle {
+                self.inner.as_raw_handle()
+            }
+        }
+
+        impl std::os::windows::io::AsRawHandle for SmolStdout<'_> {
+            fn as_raw_handle(&self) -> std::os::windows::io::RawHandle {
+                self.inner.as_raw_handle()
+            }
+        }
+
+        impl std::os::windows::io::AsRawHandle for SmolStderr<'_> {
+            fn as_raw_handle(&self) -> std::os::windows::io::RawHandle {
+                self.inner.as_raw_handle()
+            }
+        }
+
+        impl std::os::windows::io::AsHandle for SmolStdin<'_> {
+            fn as_handle(&self) -> std::os::windows::io::BorrowedHandle<'_> {
+                self.inner.as_handle()
+            }
+        }
+
+        impl std::os::windows::io::AsHandle for SmolStdout<'_> {
+            fn as_handle(&self) -> std::os::windows::io::BorrowedHandle<'_> {
+                self.inner.as_handle()
+            }
+        }
+
+        impl std::os::windows::io::AsHandle for SmolStderr<'_> {
+            fn as_handle(&self) -> std::os::windows::io::BorrowedHandle<'_> {
+                self.inner.as_handle()
+            }
+        }
+    } else if #[cfg(any(unix, target_os = "wasi"))] {
+        impl std::os::fd::AsRawFd for SmolStdin<'_> {
+            fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+                self.inner.as_raw_fd()
+            }
+        }
+
+        impl std::os::fd::AsRawFd for SmolStdout<'_> {
+            fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+                self.inner.as_raw_fd()
+            }
+        }
+
+        impl std::os::fd::AsRawFd for SmolStderr<'_> {
+            fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+                self.inner.as_raw_fd()
+            }
+        }
+
+        impl std::os::fd::AsFd for SmolStdin<'_> {
+            fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+                self.inner.as_fd()
+            }
+        }
+
+        impl std::os::fd::AsFd for SmolStdout<'_> {
+            fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+                self.inner.as_fd()
+            }
+        }
+
+        impl std::os::fd::AsFd for SmolStderr<'_> {
+            fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+                self.inner.as_fd()
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Smol specific green thread management implementation.
+use crate::SmolGlobalRuntime;
+use arta::task::{JoinError, RuntimeJoinHandle, TaskRuntime};
+use futures::{prelude::Future, FutureExt as _};
+use std::{
+    panic::AssertUnwindSafe,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// Smol specific [`RuntimeJoinHandle`] implementation.
+pub struct SmolJoinHandle<T> {
+    // Smol cancels a task when its handle is dropped so handle is kept in `Option` to detach
+    // the task on drop instead, matching other runtimes.
+    inner: Option<smol::Task<std::thread::Result<T>>>,
+}
+
+impl<T> Future for SmolJoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // Handle is taken only by `cancel` and `drop` which consume it.
+        self.inner
+            .as_mut()
+            .unwrap()
+            .poll_unpin(cx)
+            .map_err(JoinError::panic)
+    }
+}
+
+impl<T> Drop for SmolJoinHandle<T> {
+    fn drop(&mut self) {
+        if let Some(task) = self.inner.take() {
+            task.detach();
+        }
+    }
+}
+
+impl<T> RuntimeJoinHandle<T> for SmolJoinHandle<T>
+where
+    T: Send + 'static,
+{
+    fn cancel(mut self) -> impl Future<Output = Result<T, JoinError>> + Send {
+        self.inner
+            .take()
+            .unwrap()
+            .cancel()
+            .map(|result| match result {
+                Some(result) => result.map_err(JoinError::panic),
+                None => Err(JoinError::cancelled()),
+            })
+    }
+}
+
+impl TaskRuntime for SmolGlobalRuntime {
+    type JoinHandle<T>
+        = SmolJoinHandle<T>
+    where
+        T: Send + 'static;
+
+    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        SmolJoinHandle {
+            inner: Some(smol::spawn(AssertUnwindSafe(future).catch_unwind())),
+        }
+    }
+
+    fn spawn_blocking<R>(&self, task: impl FnOnce() -> R + Send + 'static) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        SmolJoinHandle {
+            inner: Some(smol::spawn(smol::unblock(|| {
+                std::panic::catch_unwind(AssertUnwindSafe(task))
+            }))),
+        }
+    }
+
+    fn block_on<R>(&self, future: impl Future<Output = R>) -> R {
+        smol::block_on(future)
+    }
+
+    fn yield_now(&self) -> impl Future<Output = ()> + Send {
+        smol::future::yield_now()
+    }
+}
@@ -0,0 +1,51 @@
+//! Smol specific timer implementation.
+use crate::SmolGlobalRuntime;
+use arta::time::{RuntimeSleep, TimeRuntime};
+use futures::{prelude::Future, FutureExt as _};
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+    time::Instant,
+};
+
+/// Smol specific [`RuntimeSleep`] implementation.
+#[derive(Debug)]
+pub struct SmolSleep {
+    timer: async_io::Timer,
+    deadline: Instant,
+}
+
+impl Future for SmolSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        // A timer that has fired never completes again, so check the deadline first.
+        if Instant::now() >= self.deadline {
+            return Poll::Ready(());
+        }
+        self.timer.poll_unpin(cx).map(|_| ())
+    }
+}
+
+impl RuntimeSleep for SmolSleep {
+    fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    fn reset(&mut self, deadline: Instant) {
+        self.timer.set_at(deadline);
+        self.deadline = deadline;
+    }
+}
+
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl TimeRuntime for SmolGlobalRuntime {
+    type Sleep = SmolSleep;
+
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+        SmolSleep {
+            timer: async_io::Timer::at(deadline),
+            deadline,
+        }
+    }
+}
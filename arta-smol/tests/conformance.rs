@@ -0,0 +1,7 @@
+use arta_smol::SmolGlobalRuntime;
+
+arta_conformance::conformance_tests! {
+    runtime: SmolGlobalRuntime = SmolGlobalRuntime,
+    block_on: |_runtime, future| smol::block_on(future),
+    suites: [fs, net, process, task, time, unix_net],
+}
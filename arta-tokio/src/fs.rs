@@ -5,157 +5,179 @@ mod file;
 pub use dir_entry::*;
 pub use file::*;
 
-use crate::TokioGlobalRuntime;
-use arta::fs::FSRuntime;
-use futures::prelude::Stream;
+use crate::{in_context, TokioContext as _, TokioGlobalRuntime};
+use arta::fs::{FSRuntime, Metadata, Permissions};
+use futures::{prelude::Stream, TryFutureExt as _};
 use std::{
-    fs::Metadata,
     future::Future,
     path::{Path, PathBuf},
     task::{ready, Poll},
 };
 
-impl FSRuntime for TokioGlobalRuntime {
-    type File = TokioFile;
-    type DirEntry = TokioDirEntry;
-
-    fn canonicalize(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<PathBuf>> + Send {
-        tokio::fs::canonicalize(path)
-    }
-
-    fn copy(
-        &self,
-        from: impl AsRef<Path> + Send,
-        to: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<u64>> + Send {
-        tokio::fs::copy(from, to)
-    }
-
-    fn create_dir(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::create_dir(path)
-    }
-
-    fn create_dir_all(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::create_dir_all(path)
-    }
-
-    fn remove_dir(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::remove_dir(path)
-    }
-
-    fn remove_dir_all(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::remove_dir_all(path)
-    }
-
-    async fn read_dir(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> std::io::Result<impl Stream<Item = std::io::Result<Self::DirEntry>> + Send> {
-        let mut read_dir = tokio::fs::read_dir(path).await?;
-        let read_dir = futures::stream::poll_fn(move |cx| {
-            let entry = ready!(read_dir.poll_next_entry(cx));
-            let entry = match entry {
-                Ok(entry) => entry.map(|entry| Ok(TokioDirEntry { inner: entry })),
-                Err(err) => Some(Err(err)),
-            };
-
-            Poll::Ready(entry)
-        });
-
-        Ok(read_dir)
-    }
-
-    fn read_link(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<PathBuf>> + Send {
-        tokio::fs::read_link(path)
-    }
-
-    #[cfg(unix)]
-    fn symlink(
-        &self,
-        from: impl AsRef<Path> + Send,
-        to: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::symlink(from, to)
-    }
-
-    #[cfg(windows)]
-    fn symlink_dir(
-        &self,
-        from: impl AsRef<Path> + Send,
-        to: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::symlink_dir(from, to)
-    }
-
-    #[cfg(windows)]
-    fn symlink_file(
-        &self,
-        from: impl AsRef<Path> + Send,
-        to: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::symlink_file(from, to)
-    }
-
-    fn hard_link(
-        &self,
-        from: impl AsRef<Path> + Send,
-        to: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::hard_link(from, to)
-    }
-
-    fn metadata(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
-        tokio::fs::metadata(path)
-    }
-
-    fn remove_file(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::remove_file(path)
-    }
-
-    fn rename(
-        &self,
-        from: impl AsRef<Path> + Send,
-        to: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::rename(from, to)
-    }
-
-    fn set_permissions(
-        &self,
-        path: impl AsRef<Path> + Send,
-        permissions: std::fs::Permissions,
-    ) -> impl Future<Output = std::io::Result<()>> + Send {
-        tokio::fs::set_permissions(path, permissions)
-    }
-
-    fn symlink_metadata(
-        &self,
-        path: impl AsRef<Path> + Send,
-    ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
-        tokio::fs::symlink_metadata(path)
-    }
+macro_rules! impl_fs_runtime {
+    ($runtime:ty) => {
+        impl FSRuntime for $runtime {
+            type File = TokioFile<Self>;
+            type DirEntry = TokioDirEntry<Self>;
+
+            fn canonicalize(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<PathBuf>> + Send {
+                in_context(self, tokio::fs::canonicalize(path))
+            }
+
+            fn copy(
+                &self,
+                from: impl AsRef<Path> + Send,
+                to: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<u64>> + Send {
+                in_context(self, tokio::fs::copy(from, to))
+            }
+
+            fn create_dir(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::create_dir(path))
+            }
+
+            fn create_dir_all(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::create_dir_all(path))
+            }
+
+            fn remove_dir(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::remove_dir(path))
+            }
+
+            fn remove_dir_all(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::remove_dir_all(path))
+            }
+
+            async fn read_dir(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> std::io::Result<impl Stream<Item = std::io::Result<Self::DirEntry>> + Send> {
+                let mut read_dir = in_context(self, tokio::fs::read_dir(path)).await?;
+                let runtime = self.clone();
+                let read_dir = futures::stream::poll_fn(move |cx| {
+                    let entry = {
+                        let _guard = runtime.enter();
+                        ready!(read_dir.poll_next_entry(cx))
+                    };
+                    let entry = match entry {
+                        Ok(entry) => entry.map(|entry| {
+                            Ok(TokioDirEntry {
+                                inner: entry,
+                                runtime: runtime.clone(),
+                            })
+                        }),
+                        Err(err) => Some(Err(err)),
+                    };
+
+                    Poll::Ready(entry)
+                });
+
+                Ok(read_dir)
+            }
+
+            fn read_link(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<PathBuf>> + Send {
+                in_context(self, tokio::fs::read_link(path))
+            }
+
+            #[cfg(unix)]
+            fn symlink(
+                &self,
+                from: impl AsRef<Path> + Send,
+                to: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::symlink(from, to))
+            }
+
+            #[cfg(windows)]
+            fn symlink_dir(
+                &self,
+                from: impl AsRef<Path> + Send,
+                to: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::symlink_dir(from, to))
+            }
+
+            #[cfg(windows)]
+            fn symlink_file(
+                &self,
+                from: impl AsRef<Path> + Send,
+                to: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::symlink_file(from, to))
+            }
+
+            fn hard_link(
+                &self,
+                from: impl AsRef<Path> + Send,
+                to: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::hard_link(from, to))
+            }
+
+            fn metadata(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+                in_context(self, tokio::fs::metadata(path).map_ok(Into::into))
+            }
+
+            fn remove_file(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::remove_file(path))
+            }
+
+            fn rename(
+                &self,
+                from: impl AsRef<Path> + Send,
+                to: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                in_context(self, tokio::fs::rename(from, to))
+            }
+
+            fn set_permissions(
+                &self,
+                path: impl AsRef<Path> + Send,
+                permissions: Permissions,
+            ) -> impl Future<Output = std::io::Result<()>> + Send {
+                let path = path.as_ref().to_owned();
+                in_context(self, async move {
+                    let current =
+                        tokio::fs::metadata(&path).map_ok(|metadata| metadata.permissions());
+                    let permissions = permissions.into_os_permissions(current).await?;
+                    tokio::fs::set_permissions(path, permissions).await
+                })
+            }
+
+            fn symlink_metadata(
+                &self,
+                path: impl AsRef<Path> + Send,
+            ) -> impl Future<Output = std::io::Result<Metadata>> + Send {
+                in_context(self, tokio::fs::symlink_metadata(path).map_ok(Into::into))
+            }
+        }
+    };
 }
+
+impl_fs_runtime!(TokioGlobalRuntime);
+#[cfg(feature = "rt")]
+impl_fs_runtime!(crate::TokioRuntime);
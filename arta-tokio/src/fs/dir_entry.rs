@@ -1,17 +1,15 @@
-use arta::fs::RuntimeDirEntry;
-use std::{
-    ffi::OsString,
-    fs::{FileType, Metadata},
-    future::Future,
-    path::PathBuf,
-};
+use crate::{in_context, TokioContext, TokioGlobalRuntime};
+use arta::fs::{FileType, Metadata, RuntimeDirEntry};
+use futures::TryFutureExt as _;
+use std::{ffi::OsString, future::Future, path::PathBuf};
 
 /// Tokio specific [`RuntimeDirEntry`] implementation.
-pub struct TokioDirEntry {
+pub struct TokioDirEntry<R = TokioGlobalRuntime> {
     pub(super) inner: tokio::fs::DirEntry,
+    pub(super) runtime: R,
 }
 
-impl RuntimeDirEntry for TokioDirEntry {
+impl<R: TokioContext> RuntimeDirEntry for TokioDirEntry<R> {
     #[cfg(unix)]
     fn ino(&self) -> u64 {
         self.inner.ino()
@@ -22,11 +20,11 @@ impl RuntimeDirEntry for TokioDirEntry {
     }
 
     fn file_type(&self) -> impl Future<Output = std::io::Result<FileType>> + Send {
-        self.inner.file_type()
+        in_context(&self.runtime, self.inner.file_type()).map_ok(Into::into)
     }
 
     fn metadata(&self) -> impl Future<Output = std::io::Result<Metadata>> + Send {
-        self.inner.metadata()
+        in_context(&self.runtime, self.inner.metadata()).map_ok(Into::into)
     }
 
     fn path(&self) -> PathBuf {
@@ -1,9 +1,8 @@
-use crate::TokioGlobalRuntime;
-use arta::fs::RuntimeFile;
+use crate::{in_context, TokioContext, TokioGlobalRuntime};
+use arta::fs::{FSRuntime, Metadata, OpenOptions, Permissions, RuntimeFile};
 use cfg_if::cfg_if;
-use futures::{AsyncRead, AsyncSeek, AsyncWrite};
+use futures::{AsyncRead, AsyncSeek, AsyncWrite, TryFutureExt as _};
 use std::{
-    fs::{Metadata, OpenOptions},
     future::Future,
     path::Path,
     pin::Pin,
@@ -13,74 +12,89 @@ use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
 
 cfg_if! {
     if #[cfg(windows)] {
-        impl std::os::windows::io::AsRawHandle for TokioFile {
+        impl<R> std::os::windows::io::AsRawHandle for TokioFile<R> {
             fn as_raw_handle(&self) -> std::os::windows::io::RawHandle {
                 self.inner.get_ref().as_raw_handle()
             }
         }
 
-        impl std::os::windows::io::AsHandle for TokioFile {
+        impl<R> std::os::windows::io::AsHandle for TokioFile<R> {
             fn as_handle(&self) -> std::os::windows::io::BorrowedHandle<'_> {
                 self.inner.get_ref().as_handle()
             }
         }
 
-        impl From<std::os::windows::io::OwnedHandle> for TokioFile {
+        impl<R: TokioContext> From<std::os::windows::io::OwnedHandle> for TokioFile<R> {
             fn from(handle: std::os::windows::io::OwnedHandle) -> Self {
-                Self { inner: tokio::fs::File::from_std(std::fs::File::from(handle)).compat() }
+                Self {
+                    inner: tokio::fs::File::from_std(std::fs::File::from(handle)).compat(),
+                    runtime: R::current(),
+                }
             }
         }
     } else if #[cfg(any(unix, target_os = "wasi"))] {
-        impl std::os::fd::AsRawFd for TokioFile {
+        impl<R> std::os::fd::AsRawFd for TokioFile<R> {
             fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
                 self.inner.get_ref().as_raw_fd()
             }
         }
 
-        impl std::os::fd::AsFd for TokioFile {
+        impl<R> std::os::fd::AsFd for TokioFile<R> {
             fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
                 self.inner.get_ref().as_fd()
             }
         }
 
-        impl From<std::os::fd::OwnedFd> for TokioFile {
+        impl<R: TokioContext> From<std::os::fd::OwnedFd> for TokioFile<R> {
             fn from(fd: std::os::fd::OwnedFd) -> Self {
-                Self { inner: tokio::fs::File::from_std(std::fs::File::from(fd)).compat() }
+                Self {
+                    inner: tokio::fs::File::from_std(std::fs::File::from(fd)).compat(),
+                    runtime: R::current(),
+                }
             }
         }
     }
 }
 
 /// Tokio specific [`RuntimeFile`] implementation.
-pub struct TokioFile {
+pub struct TokioFile<R = TokioGlobalRuntime> {
     inner: Compat<tokio::fs::File>,
+    runtime: R,
 }
 
-impl AsyncRead for TokioFile {
+impl<R: TokioContext> AsyncRead for TokioFile<R> {
     fn poll_read(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
         buf: &mut [u8],
     ) -> Poll<std::io::Result<usize>> {
-        Pin::new(&mut self.get_mut().inner).poll_read(cx, buf)
+        let this = self.get_mut();
+        let _guard = this.runtime.enter();
+        Pin::new(&mut this.inner).poll_read(cx, buf)
     }
 }
 
-impl AsyncWrite for TokioFile {
+impl<R: TokioContext> AsyncWrite for TokioFile<R> {
     fn poll_write(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
         buf: &[u8],
     ) -> Poll<std::io::Result<usize>> {
-        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+        let this = self.get_mut();
+        let _guard = this.runtime.enter();
+        Pin::new(&mut this.inner).poll_write(cx, buf)
     }
 
     fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
-        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+        let this = self.get_mut();
+        let _guard = this.runtime.enter();
+        Pin::new(&mut this.inner).poll_flush(cx)
     }
 
     fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
-        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+        let this = self.get_mut();
+        let _guard = this.runtime.enter();
+        Pin::new(&mut this.inner).poll_close(cx)
     }
 
     fn poll_write_vectored(
@@ -88,25 +102,32 @@ impl AsyncWrite for TokioFile {
         cx: &mut Context<'_>,
         bufs: &[std::io::IoSlice<'_>],
     ) -> Poll<std::io::Result<usize>> {
-        Pin::new(&mut self.get_mut().inner).poll_write_vectored(cx, bufs)
+        let this = self.get_mut();
+        let _guard = this.runtime.enter();
+        Pin::new(&mut this.inner).poll_write_vectored(cx, bufs)
     }
 }
 
-impl AsyncSeek for TokioFile {
+impl<R: TokioContext> AsyncSeek for TokioFile<R> {
     fn poll_seek(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
         pos: std::io::SeekFrom,
     ) -> Poll<std::io::Result<u64>> {
-        Pin::new(&mut self.get_mut().inner).poll_seek(cx, pos)
+        let this = self.get_mut();
+        let _guard = this.runtime.enter();
+        Pin::new(&mut this.inner).poll_seek(cx, pos)
     }
 }
 
-impl RuntimeFile for TokioFile {
-    type Runtime = TokioGlobalRuntime;
+impl<R> RuntimeFile for TokioFile<R>
+where
+    R: TokioContext + FSRuntime<File = Self>,
+{
+    type Runtime = R;
 
     fn open(
-        _runtime: &Self::Runtime,
+        runtime: &Self::Runtime,
         open_options: &OpenOptions,
         path: impl AsRef<Path>,
     ) -> impl Future<Output = std::io::Result<Self>> + Send
@@ -114,34 +135,43 @@ impl RuntimeFile for TokioFile {
         Self: Sized,
     {
         let path = path.as_ref().to_owned();
-        async {
-            let open_options = tokio::fs::OpenOptions::from(open_options.clone());
-            open_options.open(path).await.map(|inner| Self {
-                inner: inner.compat(),
-            })
+        let open_options = tokio::fs::OpenOptions::from(std::fs::OpenOptions::from(open_options));
+        let runtime = runtime.clone();
+        async move {
+            in_context(&runtime, open_options.open(path))
+                .await
+                .map(|inner| Self {
+                    inner: inner.compat(),
+                    runtime,
+                })
         }
     }
 
     fn set_len(&self, size: u64) -> impl Future<Output = std::io::Result<()>> + Send {
-        self.inner.get_ref().set_len(size)
+        in_context(&self.runtime, self.inner.get_ref().set_len(size))
     }
 
     fn metadata(&self) -> impl Future<Output = std::io::Result<Metadata>> + Send {
-        self.inner.get_ref().metadata()
+        in_context(&self.runtime, self.inner.get_ref().metadata()).map_ok(Into::into)
     }
 
     fn sync_all(&self) -> impl Future<Output = std::io::Result<()>> + Send {
-        self.inner.get_ref().sync_all()
+        in_context(&self.runtime, self.inner.get_ref().sync_all())
     }
 
     fn sync_data(&self) -> impl Future<Output = std::io::Result<()>> + Send {
-        self.inner.get_ref().sync_data()
+        in_context(&self.runtime, self.inner.get_ref().sync_data())
     }
 
     fn set_permissions(
         &self,
-        permissions: std::fs::Permissions,
+        permissions: Permissions,
     ) -> impl Future<Output = std::io::Result<()>> + Send {
-        self.inner.get_ref().set_permissions(permissions)
+        let file = self.inner.get_ref();
+        in_context(&self.runtime, async move {
+            let current = file.metadata().map_ok(|metadata| metadata.permissions());
+            let permissions = permissions.into_os_permissions(current).await?;
+            file.set_permissions(permissions).await
+        })
     }
 }
@@ -5,8 +5,8 @@
 //! Add a following dependencies to your `Cargo.toml`:
 //! ```toml
 //! [dependencies]
-//! arta-tokio = "0.1.0"
-//! arta = "0.1.0"
+//! arta-tokio = "0.3.0"
+//! arta = "0.3.0"
 //! tokio = { version = "^1", features = ["full"] }
 //! ```
 //! ## Usage
@@ -21,6 +21,21 @@
 //!     println!("Hosts: {hosts}");
 //! }
 //! ```
+//!
+//! To configure a runtime or to use it from threads outside of its context build an owned
+//! `TokioRuntime`, all operations performed through it are routed to that runtime:
+//!
+//! ```ignore
+//! fn main() {
+//!     let runtime = TokioRuntimeBuilder::new_multi_thread()
+//!         .worker_threads(4)
+//!         .enable_all()
+//!         .build()
+//!         .unwrap();
+//!     let hosts = runtime.block_on(runtime.read_to_string("/etc/hosts")).unwrap();
+//!     println!("Hosts: {hosts}");
+//! }
+//! ```
 
 #![deny(
     warnings,
@@ -86,10 +101,86 @@ pub mod net;
 #[cfg_attr(docsrs, doc(cfg(feature = "process")))]
 pub mod process;
 #[cfg(feature = "rt")]
+mod runtime;
+#[cfg(feature = "rt")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rt")))]
 pub mod task;
 #[cfg(feature = "time")]
-mod time;
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+pub mod time;
+
+#[cfg(feature = "rt")]
+pub use runtime::*;
+
+#[cfg(any(feature = "fs", feature = "net", feature = "process"))]
+use pin_project_lite::pin_project;
+#[cfg(any(feature = "fs", feature = "net", feature = "process"))]
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 /// Struct representing tokio global runtime usage.
+#[derive(Debug, Clone, Copy)]
 pub struct TokioGlobalRuntime;
+
+/// Tokio runtime context arta types operate in.
+///
+/// Implemented by [`TokioGlobalRuntime`] that uses the runtime of the calling context and by
+/// `TokioRuntime` that enters its own runtime.
+pub trait TokioContext: Clone + Send + Sync + Unpin + 'static {
+    /// Returns the runtime of the calling context. Used when arta type is created from an OS one.
+    fn current() -> Self;
+
+    /// Enters this runtime context until the returned guard is dropped.
+    fn enter(&self) -> impl Sized + '_;
+}
+
+impl TokioContext for TokioGlobalRuntime {
+    fn current() -> Self {
+        Self
+    }
+
+    fn enter(&self) -> impl Sized + '_ {}
+}
+
+#[cfg(any(feature = "fs", feature = "net", feature = "process"))]
+pin_project! {
+    /// Future that is polled inside of a runtime context.
+    pub(crate) struct InContext<R, F> {
+        runtime: Option<R>,
+        #[pin]
+        future: F,
+    }
+}
+
+#[cfg(any(feature = "fs", feature = "net", feature = "process"))]
+impl<R, F> InContext<R, F> {
+    pub(crate) const fn new(runtime: Option<R>, future: F) -> Self {
+        Self { runtime, future }
+    }
+}
+
+#[cfg(any(feature = "fs", feature = "net", feature = "process"))]
+impl<R, F> Future for InContext<R, F>
+where
+    R: TokioContext,
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.project();
+        let _guard = this.runtime.as_ref().map(TokioContext::enter);
+        this.future.poll(cx)
+    }
+}
+
+#[cfg(any(feature = "fs", feature = "net"))]
+pub(crate) fn in_context<R, F>(runtime: &R, future: F) -> InContext<R, F>
+where
+    R: TokioContext,
+{
+    InContext::new(Some(runtime.clone()), future)
+}
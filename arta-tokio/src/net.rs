@@ -1,17 +1,57 @@
-//! Tokio specific TCP/UDP communication implementation.
+//! Tokio specific TCP/UDP and Unix domain socket communication implementation.
 mod tcp_listener;
+mod tcp_socket;
 mod tcp_stream;
 mod udp_socket;
+#[cfg(unix)]
+mod unix_datagram;
+#[cfg(unix)]
+mod unix_listener;
+#[cfg(unix)]
+mod unix_stream;
 
 pub use tcp_listener::*;
+pub use tcp_socket::*;
 pub use tcp_stream::*;
 pub use udp_socket::*;
+#[cfg(unix)]
+pub use unix_datagram::*;
+#[cfg(unix)]
+pub use unix_listener::*;
+#[cfg(unix)]
+pub use unix_stream::*;
 
-use crate::TokioGlobalRuntime;
+use crate::{in_context, TokioGlobalRuntime};
 use arta::net::NetRuntime;
+use futures::TryFutureExt as _;
+use std::{future::Future, net::SocketAddr};
 
-impl NetRuntime for TokioGlobalRuntime {
-    type TcpListener = TokioTcpListener;
-    type TcpStream = TokioTcpStream;
-    type UdpSocket = TokioUdpSocket;
+macro_rules! impl_net_runtime {
+    ($runtime:ty) => {
+        impl NetRuntime for $runtime {
+            type TcpListener = TokioTcpListener<Self>;
+            type TcpStream = TokioTcpStream<Self>;
+            type TcpSocket = TokioTcpSocket<Self>;
+            type UdpSocket = TokioUdpSocket<Self>;
+
+            fn resolve(
+                &self,
+                host: &str,
+                port: u16,
+            ) -> impl Future<Output = std::io::Result<Vec<SocketAddr>>> + Send {
+                in_context(self, tokio::net::lookup_host((host, port))).map_ok(Iterator::collect)
+            }
+        }
+
+        #[cfg(unix)]
+        impl arta::net::UnixNetRuntime for $runtime {
+            type UnixListener = TokioUnixListener<Self>;
+            type UnixStream = TokioUnixStream<Self>;
+            type UnixDatagram = TokioUnixDatagram<Self>;
+        }
+    };
 }
+
+impl_net_runtime!(TokioGlobalRuntime);
+#[cfg(feature = "rt")]
+impl_net_runtime!(crate::TokioRuntime);
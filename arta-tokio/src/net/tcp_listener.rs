@@ -1,67 +1,78 @@
 use super::TokioTcpStream;
-use crate::TokioGlobalRuntime;
+use crate::{in_context, TokioContext, TokioGlobalRuntime};
 use arta::net::{NetRuntime, RuntimeTcpListener};
 use cfg_if::cfg_if;
 use futures::TryFutureExt;
-use std::{future::Future, net::SocketAddr};
+use std::{future::Future, marker::PhantomData, net::SocketAddr};
 use tokio_util::compat::TokioAsyncReadCompatExt;
 
 cfg_if! {
     if #[cfg(windows)] {
-        impl std::os::windows::io::AsRawSocket for TokioTcpListener {
+        impl<R> std::os::windows::io::AsRawSocket for TokioTcpListener<R> {
             fn as_raw_socket(&self) -> std::os::windows::io::RawSocket {
                 self.inner.as_raw_socket()
             }
         }
 
-        impl std::os::windows::io::AsSocket for TokioTcpListener {
+        impl<R> std::os::windows::io::AsSocket for TokioTcpListener<R> {
             fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
                 self.inner.as_socket()
             }
         }
 
-        impl From<std::os::windows::io::OwnedSocket> for TokioTcpListener {
+        impl<R: TokioContext> From<std::os::windows::io::OwnedSocket> for TokioTcpListener<R> {
             fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
-                Self { inner: tokio::net::TcpListener::from_std(std::net::TcpListener::from(socket)).unwrap() }
+                Self {
+                    inner: tokio::net::TcpListener::from_std(std::net::TcpListener::from(socket)).unwrap(),
+                    runtime: R::current(),
+                }
             }
         }
     } else if #[cfg(any(unix, target_os = "wasi"))] {
-        impl std::os::fd::AsRawFd for TokioTcpListener {
+        impl<R> std::os::fd::AsRawFd for TokioTcpListener<R> {
             fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
                 self.inner.as_raw_fd()
             }
         }
 
-        impl std::os::fd::AsFd for TokioTcpListener {
+        impl<R> std::os::fd::AsFd for TokioTcpListener<R> {
             fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
                 self.inner.as_fd()
             }
         }
 
-        impl From<std::os::fd::OwnedFd> for TokioTcpListener {
+        impl<R: TokioContext> From<std::os::fd::OwnedFd> for TokioTcpListener<R> {
             fn from(fd: std::os::fd::OwnedFd) -> Self {
-                Self { inner: tokio::net::TcpListener::from_std(std::net::TcpListener::from(fd)).unwrap() }
+                Self {
+                    inner: tokio::net::TcpListener::from_std(std::net::TcpListener::from(fd)).unwrap(),
+                    runtime: R::current(),
+                }
             }
         }
     }
 }
 
 /// Tokio specific [`RuntimeTcpListener`] implementation.
-pub struct TokioTcpListener {
-    inner: tokio::net::TcpListener,
+pub struct TokioTcpListener<R = TokioGlobalRuntime> {
+    pub(super) inner: tokio::net::TcpListener,
+    pub(super) runtime: R,
 }
 
-impl RuntimeTcpListener for TokioTcpListener {
-    type Runtime = TokioGlobalRuntime;
+impl<R> RuntimeTcpListener for TokioTcpListener<R>
+where
+    R: TokioContext + NetRuntime<TcpListener = Self, TcpStream = TokioTcpStream<R>>,
+{
+    type Runtime = R;
 
     fn accept(
         &self,
     ) -> impl Future<Output = std::io::Result<(<Self::Runtime as NetRuntime>::TcpStream, SocketAddr)>>
            + Send {
-        self.inner.accept().map_ok(|(stream, addr)| {
+        in_context(&self.runtime, self.inner.accept()).map_ok(|(stream, addr)| {
             (
                 TokioTcpStream {
                     inner: stream.compat(),
+                    runtime: PhantomData,
                 },
                 addr,
             )
@@ -76,7 +87,10 @@ impl RuntimeTcpListener for TokioTcpListener {
         Self: Sized,
     {
         addr.for_each_resolved_addr_until_success(runtime, |addr| {
-            tokio::net::TcpListener::bind(addr).map_ok(|listener| Self { inner: listener })
+            in_context(runtime, tokio::net::TcpListener::bind(addr)).map_ok(|listener| Self {
+                inner: listener,
+                runtime: runtime.clone(),
+            })
         })
     }
 
@@ -0,0 +1,159 @@
+use super::{TokioTcpListener, TokioTcpStream};
+use crate::{in_context, TokioContext, TokioGlobalRuntime};
+use arta::net::{NetRuntime, RuntimeTcpSocket};
+use cfg_if::cfg_if;
+use futures::{prelude::Future, TryFutureExt as _};
+use socket2::SockRef;
+use std::{marker::PhantomData, net::SocketAddr};
+use tokio_util::compat::TokioAsyncReadCompatExt as _;
+
+cfg_if! {
+    if #[cfg(windows)] {
+        impl<R> std::os::windows::io::AsRawSocket for TokioTcpSocket<R> {
+            fn as_raw_socket(&self) -> std::os::windows::io::RawSocket {
+                self.inner.as_raw_socket()
+            }
+        }
+
+        impl<R> std::os::windows::io::AsSocket for TokioTcpSocket<R> {
+            fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
+                self.inner.as_socket()
+            }
+        }
+
+        impl<R: TokioContext> From<std::os::windows::io::OwnedSocket> for TokioTcpSocket<R> {
+            fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
+                Self {
+                    inner: tokio::net::TcpSocket::from_std_stream(std::net::TcpStream::from(socket)),
+                    runtime: R::current(),
+                }
+            }
+        }
+    } else if #[cfg(any(unix, target_os = "wasi"))] {
+        impl<R> std::os::fd::AsRawFd for TokioTcpSocket<R> {
+            fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+                self.inner.as_raw_fd()
+            }
+        }
+
+        impl<R> std::os::fd::AsFd for TokioTcpSocket<R> {
+            fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+                self.inner.as_fd()
+            }
+        }
+
+        impl<R: TokioContext> From<std::os::fd::OwnedFd> for TokioTcpSocket<R> {
+            fn from(fd: std::os::fd::OwnedFd) -> Self {
+                Self {
+                    inner: tokio::net::TcpSocket::from_std_stream(std::net::TcpStream::from(fd)),
+                    runtime: R::current(),
+                }
+            }
+        }
+    }
+}
+
+/// Tokio specific [`RuntimeTcpSocket`] implementation.
+pub struct TokioTcpSocket<R = TokioGlobalRuntime> {
+    inner: tokio::net::TcpSocket,
+    runtime: R,
+}
+
+impl<R> RuntimeTcpSocket for TokioTcpSocket<R>
+where
+    R: TokioContext
+        + NetRuntime<
+            TcpSocket = Self,
+            TcpListener = TokioTcpListener<R>,
+            TcpStream = TokioTcpStream<R>,
+        >,
+{
+    type Runtime = R;
+
+    fn new_v4(runtime: &Self::Runtime) -> std::io::Result<Self> {
+        Ok(Self {
+            inner: tokio::net::TcpSocket::new_v4()?,
+            runtime: runtime.clone(),
+        })
+    }
+
+    fn new_v6(runtime: &Self::Runtime) -> std::io::Result<Self> {
+        Ok(Self {
+            inner: tokio::net::TcpSocket::new_v6()?,
+            runtime: runtime.clone(),
+        })
+    }
+
+    fn reuseaddr(&self) -> std::io::Result<bool> {
+        self.inner.reuseaddr()
+    }
+
+    fn set_reuseaddr(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.inner.set_reuseaddr(is_enabled)
+    }
+
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    fn reuseport(&self) -> std::io::Result<bool> {
+        self.inner.reuseport()
+    }
+
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    fn set_reuseport(&self, is_enabled: bool) -> std::io::Result<()> {
+        self.inner.set_reuseport(is_enabled)
+    }
+
+    fn send_buffer_size(&self) -> std::io::Result<u32> {
+        self.inner.send_buffer_size()
+    }
+
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        self.inner.set_send_buffer_size(size)
+    }
+
+    fn recv_buffer_size(&self) -> std::io::Result<u32> {
+        self.inner.recv_buffer_size()
+    }
+
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        self.inner.set_recv_buffer_size(size)
+    }
+
+    fn only_v6(&self) -> std::io::Result<bool> {
+        SockRef::from(&self.inner).only_v6()
+    }
+
+    fn set_only_v6(&self, is_enabled: bool) -> std::io::Result<()> {
+        SockRef::from(&self.inner).set_only_v6(is_enabled)
+    }
+
+    fn bind(&self, addr: SocketAddr) -> std::io::Result<()> {
+        self.inner.bind(addr)
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr()
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        self.inner.take_error()
+    }
+
+    fn connect(
+        self,
+        addr: SocketAddr,
+    ) -> impl Future<Output = std::io::Result<<Self::Runtime as NetRuntime>::TcpStream>> + Send
+    {
+        in_context(&self.runtime, self.inner.connect(addr)).map_ok(|stream| TokioTcpStream {
+            inner: stream.compat(),
+            runtime: PhantomData,
+        })
+    }
+
+    fn listen(self, backlog: u32) -> std::io::Result<<Self::Runtime as NetRuntime>::TcpListener> {
+        let _guard = self.runtime.enter();
+        Ok(TokioTcpListener {
+            inner: self.inner.listen(backlog)?,
+            runtime: self.runtime.clone(),
+        })
+    }
+}
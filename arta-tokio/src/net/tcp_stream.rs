@@ -1,60 +1,122 @@
-use crate::TokioGlobalRuntime;
-use arta::net::{RuntimeTcpStream, ToSocketAddrs};
+use crate::{in_context, TokioContext, TokioGlobalRuntime};
+use arta::net::{
+    NetRuntime, ReuniteError, RuntimeTcpStream, TcpKeepalive, TcpStreamOptions, ToSocketAddrs,
+};
 use cfg_if::cfg_if;
 use futures::{prelude::Future, AsyncRead, AsyncWrite, TryFutureExt};
+use socket2::SockRef;
 use std::{
-    net::SocketAddr,
+    marker::PhantomData,
+    net::{Shutdown, SocketAddr},
     pin::Pin,
     task::{Context, Poll},
+    time::Duration,
 };
-use tokio_util::compat::{Compat, TokioAsyncReadCompatExt};
+use tokio_util::compat::{Compat, TokioAsyncReadCompatExt as _, TokioAsyncWriteCompatExt as _};
 
 cfg_if! {
     if #[cfg(windows)] {
-        impl std::os::windows::io::AsRawSocket for TokioTcpStream {
+        impl<R> std::os::windows::io::AsRawSocket for TokioTcpStream<R> {
             fn as_raw_socket(&self) -> std::os::windows::io::RawSocket {
                 self.inner.get_ref().as_raw_socket()
             }
         }
 
-        impl std::os::windows::io::AsSocket for TokioTcpStream {
+        impl<R> std::os::windows::io::AsSocket for TokioTcpStream<R> {
             fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
                 self.inner.get_ref().as_socket()
             }
         }
 
-        impl From<std::os::windows::io::OwnedSocket> for TokioTcpStream {
+        impl<R> From<std::os::windows::io::OwnedSocket> for TokioTcpStream<R> {
             fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
-                Self { inner: tokio::net::TcpStream::from_std(std::net::TcpStream::from(socket)).unwrap().compat() }
+                Self {
+                    inner: tokio::net::TcpStream::from_std(std::net::TcpStream::from(socket)).unwrap().compat(),
+                    runtime: PhantomData,
+                }
             }
         }
     } else if #[cfg(any(unix, target_os = "wasi"))] {
-        impl std::os::fd::AsRawFd for TokioTcpStream {
+        impl<R> std::os::fd::AsRawFd for TokioTcpStream<R> {
             fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
                 self.inner.get_ref().as_raw_fd()
             }
         }
 
-        impl std::os::fd::AsFd for TokioTcpStream {
+        impl<R> std::os::fd::AsFd for TokioTcpStream<R> {
             fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
                 self.inner.get_ref().as_fd()
             }
         }
 
-        impl From<std::os::fd::OwnedFd> for TokioTcpStream {
+        impl<R> From<std::os::fd::OwnedFd> for TokioTcpStream<R> {
             fn from(fd: std::os::fd::OwnedFd) -> Self {
-                Self { inner: tokio::net::TcpStream::from_std(std::net::TcpStream::from(fd)).unwrap().compat() }
+                Self {
+                    inner: tokio::net::TcpStream::from_std(std::net::TcpStream::from(fd)).unwrap().compat(),
+                    runtime: PhantomData,
+                }
             }
         }
     }
 }
 
 /// Tokio specific [`RuntimeTcpStream`] implementation.
-pub struct TokioTcpStream {
+pub struct TokioTcpStream<R = TokioGlobalRuntime> {
     pub(super) inner: Compat<tokio::net::TcpStream>,
+    pub(super) runtime: PhantomData<R>,
+}
+
+impl<R: TokioContext> AsyncRead for TokioTcpStream<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_read(cx, buf)
+    }
+}
+
+impl<R: TokioContext> AsyncWrite for TokioTcpStream<R> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write_vectored(cx, bufs)
+    }
+}
+
+/// Tokio specific owned read half of a [`TokioTcpStream`].
+pub struct TokioTcpReadHalf<R = TokioGlobalRuntime> {
+    inner: Compat<tokio::net::tcp::OwnedReadHalf>,
+    runtime: PhantomData<R>,
+}
+
+impl<R> std::fmt::Debug for TokioTcpReadHalf<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokioTcpReadHalf")
+            .field("inner", self.inner.get_ref())
+            .finish_non_exhaustive()
+    }
 }
 
-impl AsyncRead for TokioTcpStream {
+impl<R: TokioContext> AsyncRead for TokioTcpReadHalf<R> {
     fn poll_read(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
@@ -64,7 +126,21 @@ impl AsyncRead for TokioTcpStream {
     }
 }
 
-impl AsyncWrite for TokioTcpStream {
+/// Tokio specific owned write half of a [`TokioTcpStream`].
+pub struct TokioTcpWriteHalf<R = TokioGlobalRuntime> {
+    inner: Compat<tokio::net::tcp::OwnedWriteHalf>,
+    runtime: PhantomData<R>,
+}
+
+impl<R> std::fmt::Debug for TokioTcpWriteHalf<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TokioTcpWriteHalf")
+            .field("inner", self.inner.get_ref())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<R: TokioContext> AsyncWrite for TokioTcpWriteHalf<R> {
     fn poll_write(
         self: Pin<&mut Self>,
         cx: &mut Context<'_>,
@@ -90,8 +166,13 @@ impl AsyncWrite for TokioTcpStream {
     }
 }
 
-impl RuntimeTcpStream for TokioTcpStream {
-    type Runtime = TokioGlobalRuntime;
+impl<R> RuntimeTcpStream for TokioTcpStream<R>
+where
+    R: TokioContext + NetRuntime<TcpStream = Self>,
+{
+    type Runtime = R;
+    type OwnedReadHalf = TokioTcpReadHalf<R>;
+    type OwnedWriteHalf = TokioTcpWriteHalf<R>;
 
     fn connect(
         runtime: &Self::Runtime,
@@ -101,12 +182,52 @@ impl RuntimeTcpStream for TokioTcpStream {
         Self: Sized,
     {
         addr.for_each_resolved_addr_until_success(runtime, |addr| {
-            tokio::net::TcpStream::connect(addr).map_ok(|stream| Self {
+            in_context(runtime, tokio::net::TcpStream::connect(addr)).map_ok(|stream| Self {
                 inner: stream.compat(),
+                runtime: PhantomData,
             })
         })
     }
 
+    fn into_split(self) -> (Self::OwnedReadHalf, Self::OwnedWriteHalf) {
+        let (read, write) = self.inner.into_inner().into_split();
+        (
+            TokioTcpReadHalf {
+                inner: read.compat(),
+                runtime: PhantomData,
+            },
+            TokioTcpWriteHalf {
+                inner: write.compat_write(),
+                runtime: PhantomData,
+            },
+        )
+    }
+
+    fn reunite(
+        read: Self::OwnedReadHalf,
+        write: Self::OwnedWriteHalf,
+    ) -> Result<Self, ReuniteError<Self::OwnedReadHalf, Self::OwnedWriteHalf>> {
+        read.inner
+            .into_inner()
+            .reunite(write.inner.into_inner())
+            .map(|stream| Self {
+                inner: stream.compat(),
+                runtime: PhantomData,
+            })
+            .map_err(|err| {
+                ReuniteError(
+                    TokioTcpReadHalf {
+                        inner: err.0.compat(),
+                        runtime: PhantomData,
+                    },
+                    TokioTcpWriteHalf {
+                        inner: err.1.compat_write(),
+                        runtime: PhantomData,
+                    },
+                )
+            })
+    }
+
     fn local_addr(&self) -> std::io::Result<SocketAddr> {
         self.inner.get_ref().local_addr()
     }
@@ -121,6 +242,10 @@ impl RuntimeTcpStream for TokioTcpStream {
     }
 
     #[cfg(not(target_os = "wasi"))]
+    #[allow(
+        deprecated,
+        reason = "Tokio discourages `SO_LINGER`, but the option is still exposed by the trait"
+    )]
     fn set_linger(&self, linger: Option<std::time::Duration>) -> std::io::Result<()> {
         self.inner.get_ref().set_linger(linger)
     }
@@ -149,3 +274,76 @@ impl RuntimeTcpStream for TokioTcpStream {
         self.inner.get_ref().take_error()
     }
 }
+
+impl<R> TcpStreamOptions for TokioTcpStream<R>
+where
+    R: TokioContext + NetRuntime<TcpStream = Self>,
+{
+    fn keepalive(&self) -> std::io::Result<bool> {
+        SockRef::from(self.inner.get_ref()).keepalive()
+    }
+
+    fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> std::io::Result<()> {
+        match keepalive {
+            Some(keepalive) => SockRef::from(self.inner.get_ref())
+                .set_tcp_keepalive(&socket2::TcpKeepalive::try_from(keepalive)?),
+            None => SockRef::from(self.inner.get_ref()).set_keepalive(false),
+        }
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn user_timeout(&self) -> std::io::Result<Option<Duration>> {
+        SockRef::from(self.inner.get_ref()).tcp_user_timeout()
+    }
+
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    fn set_user_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()> {
+        SockRef::from(self.inner.get_ref()).set_tcp_user_timeout(timeout)
+    }
+
+    fn send_buffer_size(&self) -> std::io::Result<u32> {
+        SockRef::from(self.inner.get_ref())
+            .send_buffer_size()
+            .map(|size| u32::try_from(size).unwrap_or(u32::MAX))
+    }
+
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        SockRef::from(self.inner.get_ref()).set_send_buffer_size(size as usize)
+    }
+
+    fn recv_buffer_size(&self) -> std::io::Result<u32> {
+        SockRef::from(self.inner.get_ref())
+            .recv_buffer_size()
+            .map(|size| u32::try_from(size).unwrap_or(u32::MAX))
+    }
+
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()> {
+        SockRef::from(self.inner.get_ref()).set_recv_buffer_size(size as usize)
+    }
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn tos(&self) -> std::io::Result<u32> {
+        SockRef::from(self.inner.get_ref()).tos()
+    }
+
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    fn set_tos(&self, tos: u32) -> std::io::Result<()> {
+        SockRef::from(self.inner.get_ref()).set_tos(tos)
+    }
+
+    fn shutdown(&self, how: Shutdown) -> std::io::Result<()> {
+        SockRef::from(self.inner.get_ref()).shutdown(how)
+    }
+}
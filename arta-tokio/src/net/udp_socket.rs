@@ -1,56 +1,66 @@
-use crate::TokioGlobalRuntime;
-use arta::net::{RuntimeUdpSocket, ToSocketAddrs};
+use crate::{in_context, TokioContext, TokioGlobalRuntime};
+use arta::net::{NetRuntime, RuntimeUdpSocket, ToSocketAddrs};
 use cfg_if::cfg_if;
 use futures::TryFutureExt;
 use std::future::Future;
 
 /// Tokio specific [`RuntimeUdpSocket`] implementation.
-pub struct TokioUdpSocket {
+pub struct TokioUdpSocket<R = TokioGlobalRuntime> {
     inner: tokio::net::UdpSocket,
+    runtime: R,
 }
 
 cfg_if! {
     if #[cfg(windows)] {
-        impl std::os::windows::AsRawSocket for TokioUdpSocket {
+        impl<R> std::os::windows::AsRawSocket for TokioUdpSocket<R> {
             fn as_raw_socket(&self) -> std::os::windows::io::RawSocket {
                 self.inner.as_raw_socket()
             }
         }
 
-        impl std::os::windows::AsSocket for TokioUdpSocket {
+        impl<R> std::os::windows::AsSocket for TokioUdpSocket<R> {
             fn as_socket(&self) -> std::os::windows::io::BorrowedSocket<'_> {
                 self.inner.as_socket()
             }
         }
 
-        impl From<std::os::windows::io::OwnedSocket> for TokioUdpSocket {
+        impl<R: TokioContext> From<std::os::windows::io::OwnedSocket> for TokioUdpSocket<R> {
             fn from(socket: std::os::windows::io::OwnedSocket) -> Self {
-                Self { inner: tokio::net::UdpSocket::from_std(std::net::UdpSocket::from(socket)).unwrap() }
+                Self {
+                    inner: tokio::net::UdpSocket::from_std(std::net::UdpSocket::from(socket)).unwrap(),
+                    runtime: R::current(),
+                }
             }
         }
     } else if #[cfg(any(unix, target_os = "wasi"))] {
-        impl std::os::fd::AsRawFd for TokioUdpSocket {
+        impl<R> std::os::fd::AsRawFd for TokioUdpSocket<R> {
             fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
                 self.inner.as_raw_fd()
             }
         }
 
-        impl std::os::fd::AsFd for TokioUdpSocket {
+        impl<R> std::os::fd::AsFd for TokioUdpSocket<R> {
             fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
                 self.inner.as_fd()
             }
         }
 
-        impl From<std::os::fd::OwnedFd> for TokioUdpSocket {
+        impl<R: TokioContext> From<std::os::fd::OwnedFd> for TokioUdpSocket<R> {
             fn from(fd: std::os::fd::OwnedFd) -> Self {
-                Self { inner: tokio::net::UdpSocket::from_std(std::net::UdpSocket::from(fd)).unwrap() }
+                Self {
+                    inner: tokio::net::UdpSocket::from_std(std::net::UdpSocket::from(fd)).unwrap(),
+                    runtime: R::current(),
+                }
             }
         }
     }
 }
 
-impl RuntimeUdpSocket for TokioUdpSocket {
-    type Runtime = TokioGlobalRuntime;
+impl<R> RuntimeUdpSocket for TokioUdpSocket<R>
+where
+    R: TokioContext + NetRuntime<UdpSocket = Self>,
+{
+    type Runtime = R;
 
     fn bind(
         runtime: &Self::Runtime,
@@ -60,14 +70,17 @@ impl RuntimeUdpSocket for TokioUdpSocket {
         Self: Sized,
     {
         addrs.for_each_resolved_addr_until_success(runtime, |addr| {
-            tokio::net::UdpSocket::bind(addr).map_ok(|socket| Self { inner: socket })
+            in_context(runtime, tokio::net::UdpSocket::bind(addr)).map_ok(|socket| Self {
+                inner: socket,
+                runtime: runtime.clone(),
+            })
         })
     }
     fn connect(
         &self,
         addrs: impl ToSocketAddrs<Self::Runtime>,
     ) -> impl Future<Output = std::io::Result<()>> {
-        addrs.for_each_resolved_addr_until_success(&TokioGlobalRuntime, move |addr| {
+        addrs.for_each_resolved_addr_until_success(&self.runtime, move |addr| {
             self.inner.connect(addr)
         })
     }
@@ -81,7 +94,7 @@ impl RuntimeUdpSocket for TokioUdpSocket {
         buf: &[u8],
         addrs: impl ToSocketAddrs<Self::Runtime>,
     ) -> std::io::Result<usize> {
-        if let Some(addr) = addrs.to_socket_addrs(&TokioGlobalRuntime).await?.next() {
+        if let Some(addr) = addrs.to_socket_addrs(&self.runtime).await?.next() {
             self.inner.send_to(buf, addr).await
         } else {
             Err(std::io::Error::new(
@@ -0,0 +1,124 @@
+use crate::{TokioContext, TokioGlobalRuntime};
+use arta::net::{RuntimeUnixDatagram, UnixNetRuntime};
+use std::{
+    future::Future, marker::PhantomData, os::fd::AsFd as _, os::unix::net::SocketAddr, path::Path,
+};
+use tokio::io::Interest;
+
+impl<R> std::os::fd::AsRawFd for TokioUnixDatagram<R> {
+    fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+impl<R> std::os::fd::AsFd for TokioUnixDatagram<R> {
+    fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+        self.inner.as_fd()
+    }
+}
+
+impl<R> From<std::os::fd::OwnedFd> for TokioUnixDatagram<R> {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self {
+            inner: tokio::net::UnixDatagram::from_std(std::os::unix::net::UnixDatagram::from(fd))
+                .unwrap(),
+            runtime: PhantomData,
+        }
+    }
+}
+
+/// Tokio specific [`RuntimeUnixDatagram`] implementation.
+pub struct TokioUnixDatagram<R = TokioGlobalRuntime> {
+    inner: tokio::net::UnixDatagram,
+    runtime: PhantomData<R>,
+}
+
+impl<R> TokioUnixDatagram<R>
+where
+    R: TokioContext,
+{
+    fn from_std(runtime: &R, socket: std::os::unix::net::UnixDatagram) -> std::io::Result<Self> {
+        socket.set_nonblocking(true)?;
+
+        let _guard = runtime.enter();
+        Ok(Self {
+            inner: tokio::net::UnixDatagram::from_std(socket)?,
+            runtime: PhantomData,
+        })
+    }
+}
+
+impl<R> RuntimeUnixDatagram for TokioUnixDatagram<R>
+where
+    R: TokioContext + UnixNetRuntime<UnixDatagram = Self>,
+{
+    type Runtime = R;
+
+    fn bind_addr(runtime: &Self::Runtime, addr: &SocketAddr) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_std(runtime, std::os::unix::net::UnixDatagram::bind_addr(addr)?)
+    }
+
+    fn unbound(runtime: &Self::Runtime) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Self::from_std(runtime, std::os::unix::net::UnixDatagram::unbound()?)
+    }
+
+    fn pair(runtime: &Self::Runtime) -> std::io::Result<(Self, Self)>
+    where
+        Self: Sized,
+    {
+        let (first, second) = std::os::unix::net::UnixDatagram::pair()?;
+        Ok((
+            Self::from_std(runtime, first)?,
+            Self::from_std(runtime, second)?,
+        ))
+    }
+
+    fn send(&self, buf: &[u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        self.inner.send(buf)
+    }
+
+    fn send_to(
+        &self,
+        buf: &[u8],
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<usize>> + Send {
+        self.inner.send_to(buf, path.as_ref().to_owned())
+    }
+
+    async fn send_to_addr(&self, buf: &[u8], addr: &SocketAddr) -> std::io::Result<usize> {
+        // Tokio only sends to path names, so the datagram is sent through a duplicate of the
+        // descriptor which refers to the same socket once it's writable.
+        let socket =
+            std::os::unix::net::UnixDatagram::from(self.inner.as_fd().try_clone_to_owned()?);
+        self.inner
+            .async_io(Interest::WRITABLE, || socket.send_to_addr(buf, addr))
+            .await
+    }
+
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send {
+        self.inner.recv(buf)
+    }
+
+    async fn recv_from(&self, buf: &mut [u8]) -> std::io::Result<(usize, SocketAddr)> {
+        let (len, addr) = self.inner.recv_from(buf).await?;
+        Ok((len, addr.into()))
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr().map(Into::into)
+    }
+
+    fn peer_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.peer_addr().map(Into::into)
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        self.inner.take_error()
+    }
+}
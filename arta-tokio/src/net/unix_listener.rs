@@ -0,0 +1,79 @@
+use super::TokioUnixStream;
+use crate::{in_context, TokioContext, TokioGlobalRuntime};
+use arta::net::{RuntimeUnixListener, UnixNetRuntime};
+use futures::TryFutureExt as _;
+use std::{future::Future, marker::PhantomData, os::unix::net::SocketAddr};
+use tokio_util::compat::TokioAsyncReadCompatExt as _;
+
+impl<R> std::os::fd::AsRawFd for TokioUnixListener<R> {
+    fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+        self.inner.as_raw_fd()
+    }
+}
+
+impl<R> std::os::fd::AsFd for TokioUnixListener<R> {
+    fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+        self.inner.as_fd()
+    }
+}
+
+impl<R: TokioContext> From<std::os::fd::OwnedFd> for TokioUnixListener<R> {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self {
+            inner: tokio::net::UnixListener::from_std(std::os::unix::net::UnixListener::from(fd))
+                .unwrap(),
+            runtime: R::current(),
+        }
+    }
+}
+
+/// Tokio specific [`RuntimeUnixListener`] implementation.
+pub struct TokioUnixListener<R = TokioGlobalRuntime> {
+    inner: tokio::net::UnixListener,
+    runtime: R,
+}
+
+impl<R> RuntimeUnixListener for TokioUnixListener<R>
+where
+    R: TokioContext + UnixNetRuntime<UnixListener = Self, UnixStream = TokioUnixStream<R>>,
+{
+    type Runtime = R;
+
+    fn accept(
+        &self,
+    ) -> impl Future<
+        Output = std::io::Result<(<Self::Runtime as UnixNetRuntime>::UnixStream, SocketAddr)>,
+    > + Send {
+        in_context(&self.runtime, self.inner.accept()).map_ok(|(stream, addr)| {
+            (
+                TokioUnixStream {
+                    inner: stream.compat(),
+                    runtime: PhantomData,
+                },
+                addr.into(),
+            )
+        })
+    }
+
+    fn bind_addr(runtime: &Self::Runtime, addr: &SocketAddr) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        let listener = std::os::unix::net::UnixListener::bind_addr(addr)?;
+        listener.set_nonblocking(true)?;
+
+        let _guard = runtime.enter();
+        Ok(Self {
+            inner: tokio::net::UnixListener::from_std(listener)?,
+            runtime: runtime.clone(),
+        })
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.local_addr().map(Into::into)
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        self.inner.take_error()
+    }
+}
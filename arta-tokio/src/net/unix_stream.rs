@@ -0,0 +1,161 @@
+use crate::{in_context, TokioContext, TokioGlobalRuntime};
+use arta::net::{RuntimeUnixStream, UnixNetRuntime};
+use futures::{prelude::Future, AsyncRead, AsyncWrite, TryFutureExt as _};
+use std::{
+    marker::PhantomData,
+    os::unix::net::SocketAddr,
+    path::Path,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio_util::compat::{Compat, TokioAsyncReadCompatExt as _};
+
+impl<R> std::os::fd::AsRawFd for TokioUnixStream<R> {
+    fn as_raw_fd(&self) -> std::os::unix::prelude::RawFd {
+        self.inner.get_ref().as_raw_fd()
+    }
+}
+
+impl<R> std::os::fd::AsFd for TokioUnixStream<R> {
+    fn as_fd(&self) -> std::os::unix::prelude::BorrowedFd<'_> {
+        self.inner.get_ref().as_fd()
+    }
+}
+
+impl<R> From<std::os::fd::OwnedFd> for TokioUnixStream<R> {
+    fn from(fd: std::os::fd::OwnedFd) -> Self {
+        Self {
+            inner: tokio::net::UnixStream::from_std(std::os::unix::net::UnixStream::from(fd))
+                .unwrap()
+                .compat(),
+            runtime: PhantomData,
+        }
+    }
+}
+
+/// Tokio specific [`RuntimeUnixStream`] implementation.
+pub struct TokioUnixStream<R = TokioGlobalRuntime> {
+    pub(super) inner: Compat<tokio::net::UnixStream>,
+    pub(super) runtime: PhantomData<R>,
+}
+
+impl<R: TokioContext> AsyncRead for TokioUnixStream<R> {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_read(cx, buf)
+    }
+}
+
+impl<R: TokioContext> AsyncWrite for TokioUnixStream<R> {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.get_mut().inner).poll_close(cx)
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<std::io::Result<usize>> {
+        Pin::new(&mut self.get_mut().inner).poll_write_vectored(cx, bufs)
+    }
+}
+
+impl<R> TokioUnixStream<R>
+where
+    R: TokioContext,
+{
+    fn from_std(runtime: &R, stream: std::os::unix::net::UnixStream) -> std::io::Result<Self> {
+        let _guard = runtime.enter();
+        Ok(Self {
+            inner: tokio::net::UnixStream::from_std(stream)?.compat(),
+            runtime: PhantomData,
+        })
+    }
+}
+
+impl<R> RuntimeUnixStream for TokioUnixStream<R>
+where
+    R: TokioContext + UnixNetRuntime<UnixStream = Self>,
+{
+    type Runtime = R;
+
+    fn connect(
+        runtime: &Self::Runtime,
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        in_context(
+            runtime,
+            tokio::net::UnixStream::connect(path.as_ref().to_owned()),
+        )
+        .map_ok(|stream| Self {
+            inner: stream.compat(),
+            runtime: PhantomData,
+        })
+    }
+
+    fn connect_addr(
+        runtime: &Self::Runtime,
+        addr: &SocketAddr,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+    {
+        // Connecting blocks while the backlog of the listener is full.
+        let runtime = runtime.clone();
+        let addr = addr.clone();
+        async move {
+            let connect = {
+                let _guard = runtime.enter();
+                tokio::task::spawn_blocking(move || {
+                    std::os::unix::net::UnixStream::connect_addr(&addr)
+                })
+            };
+            let stream = connect.await??;
+            stream.set_nonblocking(true)?;
+            Self::from_std(&runtime, stream)
+        }
+    }
+
+    fn pair(runtime: &Self::Runtime) -> std::io::Result<(Self, Self)>
+    where
+        Self: Sized,
+    {
+        let (first, second) = std::os::unix::net::UnixStream::pair()?;
+        first.set_nonblocking(true)?;
+        second.set_nonblocking(true)?;
+        Ok((
+            Self::from_std(runtime, first)?,
+            Self::from_std(runtime, second)?,
+        ))
+    }
+
+    fn local_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.get_ref().local_addr().map(Into::into)
+    }
+
+    fn peer_addr(&self) -> std::io::Result<SocketAddr> {
+        self.inner.get_ref().peer_addr().map(Into::into)
+    }
+
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>> {
+        self.inner.get_ref().take_error()
+    }
+}
@@ -8,9 +8,24 @@ pub use command::*;
 pub use outputs::*;
 
 use crate::TokioGlobalRuntime;
-use arta::process::ProcessRuntime;
+use arta::process::{ProcessRuntime, RuntimeCommand as _};
+use std::ffi::OsStr;
 
-impl ProcessRuntime for TokioGlobalRuntime {
-    type Command = TokioCommand;
-    type Child = TokioChild;
+macro_rules! impl_process_runtime {
+    ($runtime:ty) => {
+        impl ProcessRuntime for $runtime {
+            type Command = TokioCommand<Self>;
+            type Child = TokioChild<Self>;
+
+            fn command(&self, program: impl AsRef<OsStr>) -> Self::Command {
+                let mut command = Self::Command::new(program);
+                command.runtime = Some(self.clone());
+                command
+            }
+        }
+    };
 }
+
+impl_process_runtime!(TokioGlobalRuntime);
+#[cfg(feature = "rt")]
+impl_process_runtime!(crate::TokioRuntime);
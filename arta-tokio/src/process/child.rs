@@ -1,20 +1,25 @@
 use super::{TokioStderr, TokioStdin, TokioStdout};
-use crate::TokioGlobalRuntime;
-use arta::process::RuntimeChild;
+use crate::{TokioContext, TokioGlobalRuntime};
+use arta::process::{ProcessRuntime, RuntimeChild};
 use std::{
     future::Future,
+    marker::PhantomData,
     process::{ExitStatus, Output},
 };
 use tokio_util::compat::{TokioAsyncReadCompatExt, TokioAsyncWriteCompatExt};
 
 /// Tokio specific [`RuntimeChild`] implementation.
-pub struct TokioChild {
+pub struct TokioChild<R = TokioGlobalRuntime> {
     pub(super) id: u32,
     pub(super) inner: tokio::process::Child,
+    pub(super) runtime: PhantomData<R>,
 }
 
-impl RuntimeChild for TokioChild {
-    type Runtime = TokioGlobalRuntime;
+impl<R> RuntimeChild for TokioChild<R>
+where
+    R: TokioContext + ProcessRuntime<Child = Self>,
+{
+    type Runtime = R;
     type Stdin<'a> = TokioStdin<'a>;
     type Stdout<'a> = TokioStdout<'a>;
     type Stderr<'a> = TokioStderr<'a>;
@@ -1,20 +1,27 @@
 use super::TokioChild;
-use crate::TokioGlobalRuntime;
+use crate::{InContext, TokioContext, TokioGlobalRuntime};
 use arta::process::{ProcessRuntime, RuntimeCommand};
 use std::{
     ffi::OsStr,
     future::Future,
+    marker::PhantomData,
     path::Path,
     process::{ExitStatus, Output},
 };
 
 /// Tokio specific [`RuntimeCommand`] implementation.
-pub struct TokioCommand {
+pub struct TokioCommand<R = TokioGlobalRuntime> {
     inner: tokio::process::Command,
+    // Command created with `RuntimeCommand::new` isn't bound to a runtime and uses the runtime of
+    // the calling context.
+    pub(super) runtime: Option<R>,
 }
 
-impl RuntimeCommand for TokioCommand {
-    type Runtime = TokioGlobalRuntime;
+impl<R> RuntimeCommand for TokioCommand<R>
+where
+    R: TokioContext + ProcessRuntime<Command = Self, Child = TokioChild<R>>,
+{
+    type Runtime = R;
 
     fn new(program: impl AsRef<OsStr>) -> Self
     where
@@ -22,6 +29,7 @@ impl RuntimeCommand for TokioCommand {
     {
         Self {
             inner: tokio::process::Command::new(program),
+            runtime: None,
         }
     }
 
@@ -79,18 +87,24 @@ impl RuntimeCommand for TokioCommand {
     }
 
     fn spawn(&mut self) -> std::io::Result<<Self::Runtime as ProcessRuntime>::Child> {
+        let _guard = self.runtime.as_ref().map(TokioContext::enter);
         self.inner.spawn().map(|child| TokioChild {
             id: child.id().unwrap(),
             inner: child,
+            runtime: PhantomData,
         })
     }
 
     fn output(&mut self) -> impl Future<Output = std::io::Result<Output>> + Send {
-        self.inner.output()
+        // Tokio spawns the child eagerly, before the future is polled.
+        let _guard = self.runtime.as_ref().map(TokioContext::enter);
+        InContext::new(self.runtime.clone(), self.inner.output())
     }
 
     fn status(&mut self) -> impl Future<Output = std::io::Result<ExitStatus>> + Send {
-        self.inner.status()
+        // Tokio spawns the child eagerly, before the future is polled.
+        let _guard = self.runtime.as_ref().map(TokioContext::enter);
+        InContext::new(self.runtime.clone(), self.inner.status())
     }
 
     #[cfg(unix)]
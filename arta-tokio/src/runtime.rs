@@ -0,0 +1,175 @@
+use crate::TokioContext;
+use futures::Future;
+use std::sync::Arc;
+use tokio::runtime::{Handle, Runtime};
+
+/// Tokio runtime owned or referenced through its handle.
+///
+/// Unlike [`TokioGlobalRuntime`](crate::TokioGlobalRuntime) every operation is routed to this
+/// runtime, so it can be used from threads that are not inside of a runtime context and several
+/// isolated runtimes can coexist. Clones refer to the same runtime, an owned runtime is shut down
+/// when the last clone is dropped.
+///
+/// A current-thread runtime is driven only by
+/// [`TaskRuntime::block_on`](arta::task::TaskRuntime::block_on): its tasks, I/O and timers don't
+/// make progress otherwise, so its futures, e.g. sleeps, awaited from another executor never
+/// complete unless some thread is blocked on the runtime.
+#[derive(Debug, Clone)]
+pub struct TokioRuntime {
+    handle: Handle,
+    runtime: Option<Arc<OwnedRuntime>>,
+}
+
+impl TokioRuntime {
+    /// Returns a handle of this runtime.
+    #[must_use]
+    pub const fn handle(&self) -> &Handle {
+        &self.handle
+    }
+
+    /// Implementation of [`TaskRuntime::block_on`](arta::task::TaskRuntime::block_on).
+    pub(crate) fn run<F: Future>(&self, future: F) -> F::Output {
+        match self
+            .runtime
+            .as_ref()
+            .and_then(|runtime| runtime.inner.as_ref())
+        {
+            Some(runtime) => runtime.block_on(future),
+            None => self.handle.block_on(future),
+        }
+    }
+}
+
+impl TokioContext for TokioRuntime {
+    /// Returns the runtime of the calling context.
+    ///
+    /// # Panics
+    ///
+    /// Panics if called outside of a tokio runtime context.
+    fn current() -> Self {
+        Handle::current().into()
+    }
+
+    fn enter(&self) -> impl Sized + '_ {
+        self.handle.enter()
+    }
+}
+
+impl From<Handle> for TokioRuntime {
+    fn from(handle: Handle) -> Self {
+        Self {
+            handle,
+            runtime: None,
+        }
+    }
+}
+
+impl From<Runtime> for TokioRuntime {
+    fn from(runtime: Runtime) -> Self {
+        Self {
+            handle: runtime.handle().clone(),
+            runtime: Some(Arc::new(OwnedRuntime {
+                inner: Some(runtime),
+            })),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct OwnedRuntime {
+    inner: Option<Runtime>,
+}
+
+impl Drop for OwnedRuntime {
+    fn drop(&mut self) {
+        // Runtime can't be dropped in an asynchronous context, e.g. when the last clone is held by
+        // one of its own tasks, so it's shut down without waiting for blocking tasks.
+        if let Some(runtime) = self.inner.take() {
+            if Handle::try_current().is_ok() {
+                runtime.shutdown_background();
+            }
+        }
+    }
+}
+
+/// Builder of an owned [`TokioRuntime`].
+#[derive(Debug)]
+pub struct TokioRuntimeBuilder {
+    inner: tokio::runtime::Builder,
+}
+
+impl TokioRuntimeBuilder {
+    /// Returns a new builder of a runtime that runs all tasks on the current thread.
+    ///
+    /// The runtime makes progress only while a thread is inside of
+    /// [`TaskRuntime::block_on`](arta::task::TaskRuntime::block_on), a multi-thread runtime should
+    /// be used from other executors instead.
+    #[must_use]
+    pub fn new_current_thread() -> Self {
+        Self {
+            inner: tokio::runtime::Builder::new_current_thread(),
+        }
+    }
+
+    /// Returns a new builder of a runtime that runs tasks on a pool of worker threads.
+    #[cfg(feature = "rt-multi-thread")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "rt-multi-thread")))]
+    #[must_use]
+    pub fn new_multi_thread() -> Self {
+        Self {
+            inner: tokio::runtime::Builder::new_multi_thread(),
+        }
+    }
+
+    /// Sets the number of worker threads. Defaults to the number of cores available to the
+    /// system.
+    pub fn worker_threads(&mut self, count: usize) -> &mut Self {
+        self.inner.worker_threads(count);
+        self
+    }
+
+    /// Sets the limit of threads spawned for blocking operations. Defaults to 512.
+    pub fn max_blocking_threads(&mut self, count: usize) -> &mut Self {
+        self.inner.max_blocking_threads(count);
+        self
+    }
+
+    /// Sets the name of threads spawned by the runtime.
+    pub fn thread_name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.inner.thread_name(name);
+        self
+    }
+
+    /// Sets the stack size, in bytes, of threads spawned by the runtime.
+    pub fn thread_stack_size(&mut self, size: usize) -> &mut Self {
+        self.inner.thread_stack_size(size);
+        self
+    }
+
+    /// Enables both I/O and time drivers.
+    pub fn enable_all(&mut self) -> &mut Self {
+        self.inner.enable_all();
+        self
+    }
+
+    /// Enables the I/O driver required by networking and process operations.
+    #[cfg(any(feature = "net", feature = "process"))]
+    #[cfg_attr(docsrs, doc(cfg(any(feature = "net", feature = "process"))))]
+    pub fn enable_io(&mut self) -> &mut Self {
+        self.inner.enable_io();
+        self
+    }
+
+    /// Enables the time driver required by timers.
+    #[cfg(feature = "time")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+    pub fn enable_time(&mut self) -> &mut Self {
+        self.inner.enable_time();
+        self
+    }
+
+    /// Creates the configured runtime.
+    pub fn build(&mut self) -> std::io::Result<TokioRuntime> {
+        self.inner.build().map(Into::into)
+    }
+}
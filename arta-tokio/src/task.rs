@@ -1,6 +1,10 @@
 //! Tokio specific green thread management implementation.
-use crate::TokioGlobalRuntime;
-use arta::task::{RuntimeJoinHandle, TaskRuntime};
+use crate::{TokioContext as _, TokioGlobalRuntime};
+use arta::task::{
+    JoinError, LocalTaskRuntime, RuntimeJoinHandle, RuntimeLocalJoinHandle, TaskBuilder,
+    TaskRuntime,
+};
+use cfg_if::cfg_if;
 use futures::{prelude::Future, FutureExt};
 use std::{
     pin::Pin,
@@ -13,32 +17,46 @@ pub struct TokioJoinHandle<T> {
 }
 
 impl<T> Future for TokioJoinHandle<T> {
-    type Output = std::thread::Result<T>;
+    type Output = Result<T, JoinError>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        // There's no UB because we are consuming owned handle on cancel method.
-        self.inner
-            .poll_unpin(cx)
-            .map_err(|err| unsafe { err.try_into_panic().unwrap_unchecked() })
+        self.inner.poll_unpin(cx).map_err(join_error)
+    }
+}
+
+fn join_error(err: tokio::task::JoinError) -> JoinError {
+    // Task is cancelled not only by `cancel` but also by a runtime shutdown.
+    match err.try_into_panic() {
+        Ok(payload) => JoinError::panic(payload),
+        Err(_) => JoinError::cancelled(),
+    }
+}
+
+/// Tokio specific [`RuntimeLocalJoinHandle`] implementation.
+pub struct TokioLocalJoinHandle<T> {
+    inner: tokio::task::JoinHandle<T>,
+}
+
+impl<T> Future for TokioLocalJoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.poll_unpin(cx).map_err(join_error)
     }
 }
 
 struct CancelFuture<T> {
-    handle: TokioJoinHandle<T>,
+    inner: tokio::task::JoinHandle<T>,
 }
 
 impl<T> Future for CancelFuture<T> {
-    type Output = Option<std::thread::Result<T>>;
+    type Output = Result<T, JoinError>;
 
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
-        let result = if let Poll::Ready(result) = self.handle.poll_unpin(cx) {
-            Some(result)
-        } else {
-            self.handle.inner.abort();
-            None
-        };
-
-        Poll::Ready(result)
+        // Aborting a finished task has no effect, so its output is still returned. Otherwise the
+        // handle resolves only after the task is dropped.
+        self.inner.abort();
+        self.inner.poll_unpin(cx).map_err(join_error)
     }
 }
 
@@ -46,29 +64,202 @@ impl<T> RuntimeJoinHandle<T> for TokioJoinHandle<T>
 where
     T: Send + 'static,
 {
-    fn cancel(self) -> impl Future<Output = Option<std::thread::Result<T>>> + Send {
-        CancelFuture { handle: self }
+    fn cancel(self) -> impl Future<Output = Result<T, JoinError>> + Send {
+        CancelFuture { inner: self.inner }
     }
 }
 
-impl TaskRuntime for TokioGlobalRuntime {
-    type JoinHandle<T> = TokioJoinHandle<T> where T: Send + 'static;
+impl<T> RuntimeLocalJoinHandle<T> for TokioLocalJoinHandle<T>
+where
+    T: 'static,
+{
+    fn cancel(self) -> impl Future<Output = Result<T, JoinError>> {
+        CancelFuture { inner: self.inner }
+    }
+}
+
+cfg_if! {
+    if #[cfg(all(tokio_unstable, feature = "tracing"))] {
+        fn tokio_builder(builder: &TaskBuilder) -> tokio::task::Builder<'_> {
+            let tokio_builder = tokio::task::Builder::new();
+            match builder.get_name() {
+                Some(name) => tokio_builder.name(name),
+                None => tokio_builder,
+            }
+        }
+
+        fn spawn_with<R>(
+            builder: &TaskBuilder,
+            future: impl Future<Output = R> + Send + 'static,
+        ) -> tokio::task::JoinHandle<R>
+        where
+            R: Send + 'static,
+        {
+            tokio_builder(builder).spawn(future).unwrap()
+        }
+
+        fn spawn_blocking_with<R>(
+            builder: &TaskBuilder,
+            task: impl FnOnce() -> R + Send + 'static,
+        ) -> tokio::task::JoinHandle<R>
+        where
+            R: Send + 'static,
+        {
+            tokio_builder(builder).spawn_blocking(task).unwrap()
+        }
+    } else {
+        fn spawn_with<R>(
+            _builder: &TaskBuilder,
+            future: impl Future<Output = R> + Send + 'static,
+        ) -> tokio::task::JoinHandle<R>
+        where
+            R: Send + 'static,
+        {
+            tokio::task::spawn(future)
+        }
 
-    fn spawn<R>(&self, future: impl Future<Output = R> + Send + 'static) -> Self::JoinHandle<R>
-    where
-        R: Send + 'static,
-    {
-        TokioJoinHandle {
-            inner: tokio::task::spawn(future),
+        fn spawn_blocking_with<R>(
+            _builder: &TaskBuilder,
+            task: impl FnOnce() -> R + Send + 'static,
+        ) -> tokio::task::JoinHandle<R>
+        where
+            R: Send + 'static,
+        {
+            tokio::task::spawn_blocking(task)
         }
     }
+}
 
-    fn spawn_blocking<R>(&self, task: impl FnOnce() -> R + Send + 'static) -> Self::JoinHandle<R>
-    where
-        R: Send + 'static,
-    {
-        TokioJoinHandle {
-            inner: tokio::task::spawn_blocking(task),
+impl TokioGlobalRuntime {
+    /// Blocks on a future using the runtime of the calling context or a new current-thread
+    /// runtime that is dropped afterwards, along with tasks spawned on it, if there's none.
+    #[expect(
+        clippy::unused_self,
+        reason = "matches `TokioRuntime::run` to be used by the macro below"
+    )]
+    fn run<F: Future>(self, future: F) -> F::Output {
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => handle.block_on(future),
+            Err(_) => tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap()
+                .block_on(future),
         }
     }
 }
+
+macro_rules! impl_task_runtime {
+    ($runtime:ty) => {
+        impl TaskRuntime for $runtime {
+            type JoinHandle<T>
+                = TokioJoinHandle<T>
+            where
+                T: Send + 'static;
+
+            fn spawn<R>(
+                &self,
+                future: impl Future<Output = R> + Send + 'static,
+            ) -> Self::JoinHandle<R>
+            where
+                R: Send + 'static,
+            {
+                let _guard = self.enter();
+                TokioJoinHandle {
+                    inner: tokio::task::spawn(future),
+                }
+            }
+
+            fn spawn_blocking<R>(
+                &self,
+                task: impl FnOnce() -> R + Send + 'static,
+            ) -> Self::JoinHandle<R>
+            where
+                R: Send + 'static,
+            {
+                let _guard = self.enter();
+                TokioJoinHandle {
+                    inner: tokio::task::spawn_blocking(task),
+                }
+            }
+
+            /// # Panics
+            ///
+            /// Panics if called from an asynchronous execution context.
+            fn block_on<R>(&self, future: impl Future<Output = R>) -> R {
+                self.run(future)
+            }
+
+            fn yield_now(&self) -> impl Future<Output = ()> + Send {
+                tokio::task::yield_now()
+            }
+
+            /// Task names are passed to Tokio only if it's built with `--cfg tokio_unstable` and
+            /// the `tracing` feature is enabled.
+            fn spawn_with<R>(
+                &self,
+                builder: &TaskBuilder,
+                future: impl Future<Output = R> + Send + 'static,
+            ) -> Self::JoinHandle<R>
+            where
+                R: Send + 'static,
+            {
+                let _guard = self.enter();
+                TokioJoinHandle {
+                    inner: spawn_with(builder, future),
+                }
+            }
+
+            /// Task names are handled the same way as by `spawn_with`. A task with the stack size
+            /// set is executed on a dedicated thread instead of the blocking pool of the runtime,
+            /// see [`TaskBuilder::spawn_thread`].
+            ///
+            /// # Panics
+            ///
+            /// Panics if the OS fails to create a thread.
+            fn spawn_blocking_with<R>(
+                &self,
+                builder: &TaskBuilder,
+                task: impl FnOnce() -> R + Send + 'static,
+            ) -> Self::JoinHandle<R>
+            where
+                R: Send + 'static,
+            {
+                let _guard = self.enter();
+                let inner = match builder.get_stack_size() {
+                    Some(_) => spawn_with(builder, builder.spawn_thread(task).unwrap()),
+                    None => spawn_blocking_with(builder, task),
+                };
+                TokioJoinHandle { inner }
+            }
+        }
+
+        impl LocalTaskRuntime for $runtime {
+            type LocalJoinHandle<T>
+                = TokioLocalJoinHandle<T>
+            where
+                T: 'static;
+
+            /// Tasks are spawned on the [`tokio::task::LocalSet`] of the calling context.
+            ///
+            /// # Panics
+            ///
+            /// Panics if called outside of a [`tokio::task::LocalSet`].
+            fn spawn_local<R>(
+                &self,
+                future: impl Future<Output = R> + 'static,
+            ) -> Self::LocalJoinHandle<R>
+            where
+                R: 'static,
+            {
+                let _guard = self.enter();
+                TokioLocalJoinHandle {
+                    inner: tokio::task::spawn_local(future),
+                }
+            }
+        }
+    };
+}
+
+impl_task_runtime!(TokioGlobalRuntime);
+impl_task_runtime!(crate::TokioRuntime);
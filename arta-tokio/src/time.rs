@@ -1,10 +1,90 @@
-use crate::TokioGlobalRuntime;
-use arta::time::TimeRuntime;
-use std::{future::Future, time::Duration};
-
-#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
-impl TimeRuntime for TokioGlobalRuntime {
-    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send {
-        tokio::time::sleep(duration)
+//! Tokio specific timer implementation.
+use crate::{TokioContext as _, TokioGlobalRuntime};
+use arta::time::{Elapsed, RuntimeSleep, TimeRuntime};
+use futures::TryFutureExt as _;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+/// Tokio specific [`RuntimeSleep`] implementation.
+#[derive(Debug)]
+pub struct TokioSleep {
+    inner: Pin<Box<tokio::time::Sleep>>,
+}
+
+impl Future for TokioSleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+impl RuntimeSleep for TokioSleep {
+    fn deadline(&self) -> Instant {
+        self.inner.deadline().into_std()
+    }
+
+    fn reset(&mut self, deadline: Instant) {
+        self.inner.as_mut().reset(deadline.into());
     }
 }
+
+macro_rules! impl_time_runtime {
+    ($runtime:ty) => {
+        #[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+        impl TimeRuntime for $runtime {
+            type Sleep = TokioSleep;
+
+            fn now(&self) -> Instant {
+                let _guard = self.enter();
+                tokio::time::Instant::now().into_std()
+            }
+
+            fn sleep(&self, duration: Duration) -> Self::Sleep {
+                let _guard = self.enter();
+                TokioSleep {
+                    inner: Box::pin(tokio::time::sleep(duration)),
+                }
+            }
+
+            fn sleep_until(&self, deadline: Instant) -> Self::Sleep {
+                let _guard = self.enter();
+                TokioSleep {
+                    inner: Box::pin(tokio::time::sleep_until(deadline.into())),
+                }
+            }
+
+            fn timeout<F>(
+                &self,
+                duration: Duration,
+                future: F,
+            ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send
+            where
+                F: Future + Send,
+            {
+                let _guard = self.enter();
+                tokio::time::timeout(duration, future).map_err(|_| Elapsed::new())
+            }
+
+            fn timeout_at<F>(
+                &self,
+                deadline: Instant,
+                future: F,
+            ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send
+            where
+                F: Future + Send,
+            {
+                let _guard = self.enter();
+                tokio::time::timeout_at(deadline.into(), future).map_err(|_| Elapsed::new())
+            }
+        }
+    };
+}
+
+impl_time_runtime!(TokioGlobalRuntime);
+#[cfg(feature = "rt")]
+impl_time_runtime!(crate::TokioRuntime);
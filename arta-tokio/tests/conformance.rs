@@ -0,0 +1,31 @@
+mod global {
+    use arta_tokio::TokioGlobalRuntime;
+
+    arta_conformance::conformance_tests! {
+        runtime: TokioGlobalRuntime = TokioGlobalRuntime,
+        block_on: |_runtime, future| {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            tokio::task::LocalSet::new().block_on(&runtime, future)
+        },
+        suites: [fs, local_task, net, process, task, time, unix_net],
+    }
+}
+
+// Futures are driven outside of the runtime context to check that every operation is routed to
+// the owned runtime.
+mod owned {
+    use arta_tokio::{TokioRuntime, TokioRuntimeBuilder};
+
+    arta_conformance::conformance_tests! {
+        runtime: TokioRuntime = TokioRuntimeBuilder::new_multi_thread()
+            .worker_threads(2)
+            .enable_all()
+            .build()
+            .unwrap(),
+        block_on: |_runtime, future| futures::executor::block_on(future),
+        suites: [fs, net, process, task, time, unix_net],
+    }
+}
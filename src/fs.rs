@@ -2,13 +2,16 @@
 
 mod dir_entry;
 mod file;
+mod metadata;
+mod open_options;
 
 pub use dir_entry::*;
 pub use file::*;
+pub use metadata::*;
+pub use open_options::*;
 
-use futures::{AsyncReadExt, AsyncWriteExt, Stream};
+use futures::{AsyncReadExt as _, AsyncWriteExt as _, Stream};
 use std::{
-    fs::{Metadata, OpenOptions, Permissions},
     future::Future,
     path::{Path, PathBuf},
     pin::pin,
@@ -188,7 +191,8 @@ pub trait FSRuntime: Send + Sync {
         }
     }
 
-    /// Write a slice as the entire contents of a file.
+    /// Write a slice as the entire contents of a file. The file is created if it doesn't exist
+    /// and truncated if it does.
     ///
     /// This is an async version of [`std::fs::write`]
     fn write(
@@ -197,9 +201,16 @@ pub trait FSRuntime: Send + Sync {
         content: &[u8],
     ) -> impl Future<Output = std::io::Result<()>> + Send {
         async {
-            let mut file =
-                pin!(Self::File::open(self, OpenOptions::new().create(true), path).await?);
-            file.write_all(content).await
+            let mut file = pin!(
+                Self::File::open(
+                    self,
+                    OpenOptions::new().write(true).create(true).truncate(true),
+                    path
+                )
+                .await?
+            );
+            file.write_all(content).await?;
+            file.flush().await
         }
     }
 
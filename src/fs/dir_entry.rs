@@ -1,5 +1,5 @@
+use super::{FileType, Metadata};
 use std::ffi::OsString;
-use std::fs::{FileType, Metadata};
 use std::future::Future;
 use std::path::PathBuf;
 
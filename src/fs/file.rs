@@ -1,12 +1,8 @@
 use cfg_if::cfg_if;
 use futures::{AsyncRead, AsyncSeek, AsyncWrite};
-use std::{
-    fs::{Metadata, OpenOptions, Permissions},
-    future::Future,
-    path::Path,
-};
+use std::{future::Future, path::Path};
 
-use super::FSRuntime;
+use super::{FSRuntime, Metadata, OpenOptions, Permissions};
 
 cfg_if! {
     if #[cfg(windows)] {
@@ -43,9 +39,7 @@ cfg_if! {
 /// Represents an object providing access to an open file on the filesystem.
 ///
 /// An async version of [`std::fs::File`].
-pub trait RuntimeFile:
-    OsFile + FromOsOnwedDescriptor + AsyncRead + AsyncWrite + AsyncSeek + Send + Sync
-{
+pub trait RuntimeFile: AsyncRead + AsyncWrite + AsyncSeek + Send + Sync {
     /// An async runtime.
     type Runtime: FSRuntime<File = Self>;
 
@@ -91,3 +85,10 @@ pub trait RuntimeFile:
         permissions: Permissions,
     ) -> impl Future<Output = std::io::Result<()>> + Send;
 }
+
+/// Represents a [`RuntimeFile`] backed by an OS file, giving access to its descriptor or handle.
+///
+/// Implemented for every file which implements OS specific methods. Files that don't exist on
+/// the OS filesystem, such as in-memory ones, don't implement it.
+pub trait RuntimeOsFile: RuntimeFile + OsFile + FromOsOnwedDescriptor {}
+impl<T> RuntimeOsFile for T where T: RuntimeFile + OsFile + FromOsOnwedDescriptor {}
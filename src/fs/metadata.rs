@@ -0,0 +1,316 @@
+#[cfg(unix)]
+use std::os::unix::fs::PermissionsExt;
+use std::{future::Future, time::SystemTime};
+
+/// A structure representing a type of file with accessors for each file type.
+///
+/// An analogue of [`std::fs::FileType`] that can be constructed by runtimes that don't work
+/// with OS filesystem.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileType {
+    /// A regular file.
+    File,
+    /// A directory.
+    Dir,
+    /// A symbolic link.
+    Symlink,
+    /// Any other kind of file like a socket, a pipe or a device.
+    Other,
+}
+
+impl FileType {
+    /// Tests whether this file type represents a directory.
+    #[must_use]
+    pub const fn is_dir(self) -> bool {
+        matches!(self, Self::Dir)
+    }
+
+    /// Tests whether this file type represents a regular file.
+    #[must_use]
+    pub const fn is_file(self) -> bool {
+        matches!(self, Self::File)
+    }
+
+    /// Tests whether this file type represents a symbolic link.
+    #[must_use]
+    pub const fn is_symlink(self) -> bool {
+        matches!(self, Self::Symlink)
+    }
+}
+
+impl From<std::fs::FileType> for FileType {
+    #[expect(
+        clippy::filetype_is_file,
+        reason = "other file types are checked separately"
+    )]
+    fn from(file_type: std::fs::FileType) -> Self {
+        if file_type.is_dir() {
+            Self::Dir
+        } else if file_type.is_file() {
+            Self::File
+        } else if file_type.is_symlink() {
+            Self::Symlink
+        } else {
+            Self::Other
+        }
+    }
+}
+
+/// Representation of the various permissions on a file.
+///
+/// An analogue of [`std::fs::Permissions`] that can be constructed by runtimes that don't work
+/// with OS filesystem. On Unix it keeps the mode bits accessible through [`PermissionsExt`],
+/// on other platforms only the read-only flag is kept.
+///
+/// [`PermissionsExt`]: std::os::unix::fs::PermissionsExt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Permissions {
+    #[cfg(unix)]
+    mode: u32,
+    #[cfg(not(unix))]
+    readonly: bool,
+}
+
+impl Permissions {
+    /// Creates permissions of a file that is either read-only or writable. On Unix the file is
+    /// readable by everyone and writable by everyone too unless it's read-only, which is mode
+    /// `0o444` or `0o666`.
+    #[must_use]
+    pub const fn new(readonly: bool) -> Self {
+        Self {
+            #[cfg(unix)]
+            mode: if readonly { 0o444 } else { 0o666 },
+            #[cfg(not(unix))]
+            readonly,
+        }
+    }
+
+    /// Returns `true` if these permissions describe a read-only file.
+    ///
+    /// On Unix a file is read-only if none of its write bits is set.
+    #[must_use]
+    pub const fn readonly(self) -> bool {
+        #[cfg(unix)]
+        return self.mode & 0o222 == 0;
+        #[cfg(not(unix))]
+        return self.readonly;
+    }
+
+    /// Modifies the read-only flag of these permissions.
+    ///
+    /// Like [`std::fs::Permissions::set_readonly`], on Unix it clears or sets write bits for
+    /// everyone.
+    pub fn set_readonly(&mut self, readonly: bool) {
+        #[cfg(unix)]
+        if readonly {
+            self.mode &= !0o222;
+        } else {
+            self.mode |= 0o222;
+        }
+        #[cfg(not(unix))]
+        {
+            self.readonly = readonly;
+        }
+    }
+
+    /// Converts these permissions into OS permissions, which is meant for runtimes that work with
+    /// OS filesystem.
+    ///
+    /// OS permissions can only be constructed on Unix. On other platforms `current` permissions
+    /// of the file are awaited and the read-only flag is set on them, `current` is never polled
+    /// on Unix.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `current`.
+    #[cfg_attr(
+        unix,
+        expect(
+            clippy::unused_async,
+            reason = "`current` is awaited on other platforms"
+        )
+    )]
+    pub async fn into_os_permissions<F>(self, current: F) -> std::io::Result<std::fs::Permissions>
+    where
+        F: Future<Output = std::io::Result<std::fs::Permissions>>,
+    {
+        #[cfg(unix)]
+        {
+            drop(current);
+            Ok(std::fs::Permissions::from_mode(self.mode))
+        }
+        #[cfg(not(unix))]
+        {
+            let mut permissions = current.await?;
+            permissions.set_readonly(self.readonly);
+            Ok(permissions)
+        }
+    }
+}
+
+impl From<std::fs::Permissions> for Permissions {
+    fn from(permissions: std::fs::Permissions) -> Self {
+        Self {
+            #[cfg(unix)]
+            mode: permissions.mode(),
+            #[cfg(not(unix))]
+            readonly: permissions.readonly(),
+        }
+    }
+}
+
+#[cfg(unix)]
+impl PermissionsExt for Permissions {
+    fn mode(&self) -> u32 {
+        self.mode
+    }
+
+    fn set_mode(&mut self, mode: u32) {
+        self.mode = mode;
+    }
+
+    fn from_mode(mode: u32) -> Self {
+        Self { mode }
+    }
+}
+
+/// Metadata information about a file.
+///
+/// An analogue of [`std::fs::Metadata`] that can be constructed by runtimes that don't work
+/// with OS filesystem.
+#[derive(Debug, Clone)]
+pub struct Metadata {
+    file_type: FileType,
+    len: u64,
+    permissions: Permissions,
+    modified: Option<SystemTime>,
+    accessed: Option<SystemTime>,
+    created: Option<SystemTime>,
+    os: Option<std::fs::Metadata>,
+}
+
+impl Metadata {
+    /// Creates metadata without timestamps.
+    #[must_use]
+    pub const fn new(file_type: FileType, len: u64, permissions: Permissions) -> Self {
+        Self {
+            file_type,
+            len,
+            permissions,
+            modified: None,
+            accessed: None,
+            created: None,
+            os: None,
+        }
+    }
+
+    /// Sets the last modification time.
+    pub fn set_modified(&mut self, time: SystemTime) -> &mut Self {
+        self.modified = Some(time);
+        self
+    }
+
+    /// Sets the last access time.
+    pub fn set_accessed(&mut self, time: SystemTime) -> &mut Self {
+        self.accessed = Some(time);
+        self
+    }
+
+    /// Sets the creation time.
+    pub fn set_created(&mut self, time: SystemTime) -> &mut Self {
+        self.created = Some(time);
+        self
+    }
+
+    /// Returns the file type for this metadata.
+    #[must_use]
+    pub const fn file_type(&self) -> FileType {
+        self.file_type
+    }
+
+    /// Returns `true` if this metadata is for a directory.
+    #[must_use]
+    pub const fn is_dir(&self) -> bool {
+        self.file_type.is_dir()
+    }
+
+    /// Returns `true` if this metadata is for a regular file.
+    #[must_use]
+    pub const fn is_file(&self) -> bool {
+        self.file_type.is_file()
+    }
+
+    /// Returns `true` if this metadata is for a symbolic link.
+    #[must_use]
+    pub const fn is_symlink(&self) -> bool {
+        self.file_type.is_symlink()
+    }
+
+    /// Returns the size of the file, in bytes, this metadata is for.
+    #[must_use]
+    #[expect(clippy::len_without_is_empty, reason = "mirrors `std::fs::Metadata`")]
+    pub const fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns the permissions of the file this metadata is for.
+    #[must_use]
+    pub const fn permissions(&self) -> Permissions {
+        self.permissions
+    }
+
+    /// Returns the last modification time listed in this metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`std::io::ErrorKind::Unsupported`] error if the time isn't available.
+    pub fn modified(&self) -> std::io::Result<SystemTime> {
+        self.modified.ok_or_else(time_unsupported)
+    }
+
+    /// Returns the last access time listed in this metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`std::io::ErrorKind::Unsupported`] error if the time isn't available.
+    pub fn accessed(&self) -> std::io::Result<SystemTime> {
+        self.accessed.ok_or_else(time_unsupported)
+    }
+
+    /// Returns the creation time listed in this metadata.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`std::io::ErrorKind::Unsupported`] error if the time isn't available.
+    pub fn created(&self) -> std::io::Result<SystemTime> {
+        self.created.ok_or_else(time_unsupported)
+    }
+
+    /// Returns the OS metadata this metadata was converted from, it's useful to access platform
+    /// specific information through extension traits.
+    #[must_use]
+    pub const fn os_metadata(&self) -> Option<&std::fs::Metadata> {
+        self.os.as_ref()
+    }
+}
+
+impl From<std::fs::Metadata> for Metadata {
+    fn from(metadata: std::fs::Metadata) -> Self {
+        Self {
+            file_type: metadata.file_type().into(),
+            len: metadata.len(),
+            permissions: metadata.permissions().into(),
+            modified: metadata.modified().ok(),
+            accessed: metadata.accessed().ok(),
+            created: metadata.created().ok(),
+            os: Some(metadata),
+        }
+    }
+}
+
+fn time_unsupported() -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "time is not available for this file",
+    )
+}
@@ -0,0 +1,174 @@
+#[cfg(unix)]
+use std::os::unix::fs::OpenOptionsExt;
+
+/// Options and flags which can be used to configure how a file is opened.
+///
+/// An analogue of [`std::fs::OpenOptions`] whose options can be inspected by runtimes that don't
+/// work with OS filesystem.
+///
+/// Options of `std::os::windows::fs::OpenOptionsExt` aren't supported, files are opened on Windows
+/// with the access, share mode and attributes that the standard library uses by default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[expect(
+    clippy::struct_excessive_bools,
+    reason = "mirrors `std::fs::OpenOptions`"
+)]
+pub struct OpenOptions {
+    read: bool,
+    write: bool,
+    append: bool,
+    truncate: bool,
+    create: bool,
+    create_new: bool,
+    #[cfg(unix)]
+    mode: u32,
+    #[cfg(unix)]
+    custom_flags: i32,
+}
+
+impl OpenOptions {
+    /// Creates a blank new set of options ready for configuration. All options are initially
+    /// set to `false`.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            read: false,
+            write: false,
+            append: false,
+            truncate: false,
+            create: false,
+            create_new: false,
+            #[cfg(unix)]
+            mode: 0o666,
+            #[cfg(unix)]
+            custom_flags: 0,
+        }
+    }
+
+    /// Sets the option for read access.
+    pub fn read(&mut self, is_enabled: bool) -> &mut Self {
+        self.read = is_enabled;
+        self
+    }
+
+    /// Sets the option for write access.
+    pub fn write(&mut self, is_enabled: bool) -> &mut Self {
+        self.write = is_enabled;
+        self
+    }
+
+    /// Sets the option for the append mode. Implies write access.
+    pub fn append(&mut self, is_enabled: bool) -> &mut Self {
+        self.append = is_enabled;
+        self
+    }
+
+    /// Sets the option for truncating a previous file.
+    pub fn truncate(&mut self, is_enabled: bool) -> &mut Self {
+        self.truncate = is_enabled;
+        self
+    }
+
+    /// Sets the option to create a new file, or open it if it already exists.
+    pub fn create(&mut self, is_enabled: bool) -> &mut Self {
+        self.create = is_enabled;
+        self
+    }
+
+    /// Sets the option to create a new file, failing if it already exists.
+    pub fn create_new(&mut self, is_enabled: bool) -> &mut Self {
+        self.create_new = is_enabled;
+        self
+    }
+
+    /// Returns `true` if read access is requested.
+    #[must_use]
+    pub const fn is_read(&self) -> bool {
+        self.read
+    }
+
+    /// Returns `true` if write access is requested, either directly or through the append mode.
+    #[must_use]
+    pub const fn is_write(&self) -> bool {
+        self.write || self.append
+    }
+
+    /// Returns `true` if the append mode is requested.
+    #[must_use]
+    pub const fn is_append(&self) -> bool {
+        self.append
+    }
+
+    /// Returns `true` if truncating a previous file is requested.
+    #[must_use]
+    pub const fn is_truncate(&self) -> bool {
+        self.truncate
+    }
+
+    /// Returns `true` if a missing file should be created.
+    #[must_use]
+    pub const fn is_create(&self) -> bool {
+        self.create
+    }
+
+    /// Returns `true` if the file must not exist and should be created.
+    #[must_use]
+    pub const fn is_create_new(&self) -> bool {
+        self.create_new
+    }
+
+    /// Returns the mode bits a new file is created with.
+    #[cfg(any(unix, doc))]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[must_use]
+    pub const fn unix_mode(&self) -> u32 {
+        self.mode
+    }
+
+    /// Returns the custom flags passed to `open`.
+    #[cfg(any(unix, doc))]
+    #[cfg_attr(docsrs, doc(cfg(unix)))]
+    #[must_use]
+    pub const fn unix_custom_flags(&self) -> i32 {
+        self.custom_flags
+    }
+}
+
+impl Default for OpenOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(unix)]
+impl OpenOptionsExt for OpenOptions {
+    fn mode(&mut self, mode: u32) -> &mut Self {
+        self.mode = mode;
+        self
+    }
+
+    fn custom_flags(&mut self, flags: i32) -> &mut Self {
+        self.custom_flags = flags;
+        self
+    }
+}
+
+impl From<&OpenOptions> for std::fs::OpenOptions {
+    fn from(open_options: &OpenOptions) -> Self {
+        let mut std_open_options = Self::new();
+        std_open_options
+            .read(open_options.read)
+            .write(open_options.write)
+            .append(open_options.append)
+            .truncate(open_options.truncate)
+            .create(open_options.create)
+            .create_new(open_options.create_new);
+
+        #[cfg(unix)]
+        std_open_options
+            .mode(open_options.mode)
+            .custom_flags(open_options.custom_flags);
+
+        std_open_options
+    }
+}
@@ -1,28 +1,57 @@
-//! Networking primitives for TCP/UDP communication.
+//! Networking primitives for TCP/UDP and Unix domain socket communication.
 
+mod happy_eyeballs;
 mod tcp_listener;
+mod tcp_socket;
 mod tcp_stream;
+mod tcp_stream_options;
 mod to_socket_addrs;
 mod udp_socket;
+#[cfg(unix)]
+mod unix_datagram;
+#[cfg(unix)]
+mod unix_listener;
+#[cfg(unix)]
+mod unix_stream;
 
+pub use happy_eyeballs::*;
 pub use tcp_listener::*;
+pub use tcp_socket::*;
 pub use tcp_stream::*;
+pub use tcp_stream_options::*;
 pub use to_socket_addrs::*;
 pub use udp_socket::*;
+#[cfg(unix)]
+pub use unix_datagram::*;
+#[cfg(unix)]
+pub use unix_listener::*;
+#[cfg(unix)]
+pub use unix_stream::*;
 
+use crate::task::TaskBuilder;
 use cfg_if::cfg_if;
+use std::{future::Future, net::SocketAddr};
 
 cfg_if! {
     if #[cfg(windows)] {
         /// Represents a socket that implements OS specific methods.
+        ///
+        /// Implemented by sockets of runtimes backed by the OS network stack, generic code that
+        /// needs the descriptor of a runtime's socket bounds on it explicitly.
         pub trait OsSocket: std::os::windows::io::AsRawSocket + std::os::windows::io::AsSocket + From<std::os::windows::io::OwnedSocket> {}
         impl<T> OsSocket for T where T: std::os::windows::io::AsRawSocket + std::os::windows::io::AsSocket {}
     } else if #[cfg(any(unix, target_os = "wasi"))]{
         /// Represents a socket that implements OS specific methods.
+        ///
+        /// Implemented by sockets of runtimes backed by the OS network stack, generic code that
+        /// needs the descriptor of a runtime's socket bounds on it explicitly.
         pub trait OsSocket: std::os::fd::AsRawFd + std::os::fd::AsFd + From<std::os::fd::OwnedFd> {}
         impl<T> OsSocket for T where T: std::os::fd::AsRawFd + std::os::fd::AsFd + From<std::os::fd::OwnedFd> {}
     } else {
         /// Represents a socket that implements OS specific methods.
+        ///
+        /// Implemented by sockets of runtimes backed by the OS network stack, generic code that
+        /// needs the descriptor of a runtime's socket bounds on it explicitly.
         pub trait OsSocket {}
         impl<T> OsSocket for T {}
     }
@@ -34,6 +63,43 @@ pub trait NetRuntime: Send + Sync {
     type TcpListener: RuntimeTcpListener<Runtime = Self>;
     /// Runtime's tcp stream.
     type TcpStream: RuntimeTcpStream<Runtime = Self>;
+    /// Runtime's tcp socket used to configure a stream or a listener before it's created.
+    type TcpSocket: RuntimeTcpSocket<Runtime = Self>;
     /// Runtime's udp socket.
     type UdpSocket: RuntimeUdpSocket<Runtime = Self>;
+
+    /// Resolves a host name to socket addresses with the specified port.
+    ///
+    /// Used by [`ToSocketAddrs`] implementations for host names, so a runtime can plug in its own
+    /// resolver.
+    ///
+    /// The default implementation resolves the host name with [`std::net::ToSocketAddrs`] on a
+    /// dedicated thread, so the runtime isn't blocked by `getaddrinfo`. Runtimes with a blocking
+    /// thread pool or an asynchronous resolver should override it.
+    fn resolve(
+        &self,
+        host: &str,
+        port: u16,
+    ) -> impl Future<Output = std::io::Result<Vec<SocketAddr>>> + Send {
+        let host = host.to_owned();
+        let resolve = TaskBuilder::new()
+            .name("arta-resolve")
+            .spawn_thread(move || {
+                std::net::ToSocketAddrs::to_socket_addrs(&(host.as_str(), port))
+                    .map(Iterator::collect)
+            });
+        async move { resolve?.await }
+    }
+}
+
+/// Represents an async runtime that supports asynchronous networking over Unix domain sockets.
+#[cfg(unix)]
+#[cfg_attr(docsrs, doc(cfg(unix)))]
+pub trait UnixNetRuntime: NetRuntime {
+    /// Runtime's Unix domain socket listener.
+    type UnixListener: RuntimeUnixListener<Runtime = Self>;
+    /// Runtime's Unix domain stream socket.
+    type UnixStream: RuntimeUnixStream<Runtime = Self>;
+    /// Runtime's Unix domain datagram socket.
+    type UnixDatagram: RuntimeUnixDatagram<Runtime = Self>;
 }
@@ -0,0 +1,122 @@
+use super::{NetRuntime, RuntimeTcpStream as _, ToSocketAddrs};
+use crate::time::{RuntimeSleep as _, TimeRuntime};
+use futures::{future::poll_fn, stream::FuturesUnordered, FutureExt as _, StreamExt as _};
+use std::{collections::VecDeque, net::SocketAddr, task::Poll, time::Duration};
+
+/// Options of connecting to a host with multiple addresses using the Happy Eyeballs algorithm
+/// ([RFC 8305]).
+///
+/// Resolved addresses are reordered so that IPv6 and IPv4 addresses alternate, starting with the
+/// family of the first resolved address. Connection attempts are started one after another, a
+/// new attempt starts once the previous one fails or the attempt delay passes, without
+/// cancelling attempts in progress. The first established connection is returned and the other
+/// attempts are cancelled, so an unreachable address delays connecting only by the attempt
+/// delay instead of a full connection timeout.
+///
+/// [RFC 8305]: https://www.rfc-editor.org/rfc/rfc8305
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HappyEyeballsBuilder {
+    attempt_delay: Duration,
+}
+
+impl HappyEyeballsBuilder {
+    /// Creates a new builder with the attempt delay of 250ms recommended by the RFC.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            attempt_delay: Duration::from_millis(250),
+        }
+    }
+
+    /// Sets the delay after which the next connection attempt starts if the previous one
+    /// hasn't completed yet. A delay overflowing the clock never elapses, so attempts are made
+    /// one at a time.
+    pub fn attempt_delay(&mut self, delay: Duration) -> &mut Self {
+        self.attempt_delay = delay;
+        self
+    }
+
+    /// Returns the delay after which the next connection attempt starts if the previous one
+    /// hasn't completed yet.
+    #[must_use]
+    pub const fn get_attempt_delay(&self) -> Duration {
+        self.attempt_delay
+    }
+
+    /// Opens a TCP connection to a remote host.
+    ///
+    /// If all attempts fail the error of the last failed one is returned.
+    pub async fn connect<RT>(
+        &self,
+        runtime: &RT,
+        addr: impl ToSocketAddrs<RT>,
+    ) -> std::io::Result<RT::TcpStream>
+    where
+        RT: NetRuntime + TimeRuntime,
+    {
+        let mut addrs = interleave_families(addr.to_socket_addrs(runtime).await?).peekable();
+        let mut attempts = FuturesUnordered::new();
+        let mut sleep: Option<RT::Sleep> = None;
+        let mut last_err = None;
+
+        loop {
+            if let Some(addr) = addrs.next() {
+                attempts.push(RT::TcpStream::connect(runtime, addr));
+
+                // An attempt delay overflowing the clock never elapses.
+                let deadline = runtime.now().checked_add(self.attempt_delay);
+                match (&mut sleep, deadline) {
+                    (Some(sleep), Some(deadline)) => sleep.reset(deadline),
+                    (sleep, deadline) => {
+                        *sleep = deadline.map(|deadline| runtime.sleep_until(deadline));
+                    }
+                }
+            }
+
+            let has_next_addr = addrs.peek().is_some();
+            let completed = poll_fn(|cx| {
+                if let Poll::Ready(result) = attempts.poll_next_unpin(cx) {
+                    return Poll::Ready(result);
+                }
+                match &mut sleep {
+                    Some(sleep) if has_next_addr => sleep.poll_unpin(cx).map(|()| None),
+                    Some(_) | None => Poll::Pending,
+                }
+            })
+            .await;
+
+            match completed {
+                Some(Ok(stream)) => return Ok(stream),
+                Some(Err(err)) => last_err = Some(err),
+                None => {}
+            }
+            if !has_next_addr && attempts.is_empty() {
+                return Err(last_err.unwrap_or_else(|| {
+                    std::io::Error::new(std::io::ErrorKind::InvalidInput, "no address was resolved")
+                }));
+            }
+        }
+    }
+}
+
+impl Default for HappyEyeballsBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reorders addresses so that families alternate, keeping the order within each family.
+fn interleave_families(
+    addrs: impl Iterator<Item = SocketAddr>,
+) -> impl Iterator<Item = SocketAddr> {
+    let mut addrs = addrs.peekable();
+    let is_first_ipv6 = addrs.peek().is_some_and(SocketAddr::is_ipv6);
+    let (mut preferred, mut other): (VecDeque<_>, VecDeque<_>) =
+        addrs.partition(|addr| addr.is_ipv6() == is_first_ipv6);
+
+    std::iter::from_fn(move || {
+        let addr = preferred.pop_front().or_else(|| other.pop_front());
+        std::mem::swap(&mut preferred, &mut other);
+        addr
+    })
+}
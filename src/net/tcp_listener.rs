@@ -1,10 +1,10 @@
-use super::{NetRuntime, OsSocket, ToSocketAddrs};
+use super::{NetRuntime, ToSocketAddrs};
 use std::{future::Future, net::SocketAddr};
 
 /// Represents an async TCP socket server, listening for connections.
 ///
 /// An async version of [`std::net::TcpListener`].
-pub trait RuntimeTcpListener: OsSocket + Send + Sync {
+pub trait RuntimeTcpListener: Send + Sync {
     /// An async runtime.
     type Runtime: NetRuntime<TcpListener = Self>;
 
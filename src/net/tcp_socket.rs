@@ -0,0 +1,126 @@
+use super::NetRuntime;
+use std::{future::Future, net::SocketAddr};
+
+/// Represents a TCP socket that has not yet been converted to a stream or a listener.
+///
+/// Allows setting socket options which must be set before connecting or listening, such as
+/// `SO_REUSEADDR` or `SO_REUSEPORT`, and binding outgoing connections to a specific local
+/// address.
+pub trait RuntimeTcpSocket: Send + Sync {
+    /// An async runtime.
+    type Runtime: NetRuntime<TcpSocket = Self>;
+
+    /// Creates a new socket configured for IPv4.
+    fn new_v4(runtime: &Self::Runtime) -> std::io::Result<Self>
+    where
+        Self: Sized;
+
+    /// Creates a new socket configured for IPv6.
+    fn new_v6(runtime: &Self::Runtime) -> std::io::Result<Self>
+    where
+        Self: Sized;
+
+    /// Creates a new socket configured for the family of the specified address.
+    fn new_for_addr(runtime: &Self::Runtime, addr: SocketAddr) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        match addr {
+            SocketAddr::V4(_) => Self::new_v4(runtime),
+            SocketAddr::V6(_) => Self::new_v6(runtime),
+        }
+    }
+
+    /// Gets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// For more information about this option, see [`RuntimeTcpSocket::set_reuseaddr`].
+    fn reuseaddr(&self) -> std::io::Result<bool>;
+
+    /// Sets the value of the `SO_REUSEADDR` option on this socket.
+    ///
+    /// If set, this option allows binding to an address which is in the `TIME_WAIT` state,
+    /// so a restarted server can listen on its port immediately.
+    fn set_reuseaddr(&self, is_enabled: bool) -> std::io::Result<()>;
+
+    /// Gets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// For more information about this option, see [`RuntimeTcpSocket::set_reuseport`].
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos")))))
+    )]
+    fn reuseport(&self) -> std::io::Result<bool>;
+
+    /// Sets the value of the `SO_REUSEPORT` option on this socket.
+    ///
+    /// If set on every socket bound to the same address, this option allows multiple
+    /// listeners to share the address with incoming connections distributed between them.
+    #[cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos"))))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(all(unix, not(any(target_os = "solaris", target_os = "illumos")))))
+    )]
+    fn set_reuseport(&self, is_enabled: bool) -> std::io::Result<()>;
+
+    /// Gets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// The value may differ from the one set with [`RuntimeTcpSocket::set_send_buffer_size`],
+    /// for example Linux doubles it to account for bookkeeping overhead.
+    fn send_buffer_size(&self) -> std::io::Result<u32>;
+
+    /// Sets the value of the `SO_SNDBUF` option on this socket, the size of the buffer
+    /// allocated by the OS for outgoing data.
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()>;
+
+    /// Gets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// The value may differ from the one set with [`RuntimeTcpSocket::set_recv_buffer_size`],
+    /// for example Linux doubles it to account for bookkeeping overhead.
+    fn recv_buffer_size(&self) -> std::io::Result<u32>;
+
+    /// Sets the value of the `SO_RCVBUF` option on this socket, the size of the buffer
+    /// allocated by the OS for incoming data.
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()>;
+
+    /// Gets the value of the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// For more information about this option, see [`RuntimeTcpSocket::set_only_v6`].
+    fn only_v6(&self) -> std::io::Result<bool>;
+
+    /// Sets the value of the `IPV6_V6ONLY` option on this socket.
+    ///
+    /// If set, an IPv6 socket bound to an unspecified address only accepts IPv6 connections,
+    /// otherwise it also accepts IPv4 connections through IPv4-mapped addresses. Only valid
+    /// for IPv6 sockets.
+    fn set_only_v6(&self, is_enabled: bool) -> std::io::Result<()>;
+
+    /// Binds the socket to the specified address.
+    ///
+    /// A socket bound before [`RuntimeTcpSocket::connect`] uses the address as the local
+    /// address of the connection.
+    fn bind(&self, addr: SocketAddr) -> std::io::Result<()>;
+
+    /// Returns the local address this socket is bound to.
+    fn local_addr(&self) -> std::io::Result<SocketAddr>;
+
+    /// Gets the value of the `SO_ERROR` option on this socket.
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>>;
+
+    /// Establishes a TCP connection with a peer at the specified address, converting the
+    /// socket into a stream.
+    fn connect(
+        self,
+        addr: SocketAddr,
+    ) -> impl Future<Output = std::io::Result<<Self::Runtime as NetRuntime>::TcpStream>> + Send
+    where
+        Self: Sized;
+
+    /// Starts listening for incoming connections with the specified maximum number of pending
+    /// connections, converting the socket into a listener.
+    ///
+    /// The socket must be bound beforehand with [`RuntimeTcpSocket::bind`].
+    fn listen(self, backlog: u32) -> std::io::Result<<Self::Runtime as NetRuntime>::TcpListener>
+    where
+        Self: Sized;
+}
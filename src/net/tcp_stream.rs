@@ -1,13 +1,20 @@
-use super::{NetRuntime, OsSocket, ToSocketAddrs};
+use super::{HappyEyeballsBuilder, NetRuntime, ToSocketAddrs};
+use crate::time::TimeRuntime;
 use futures::{AsyncRead, AsyncWrite};
-use std::{future::Future, net::SocketAddr, time::Duration};
+use std::{fmt, future::Future, net::SocketAddr, time::Duration};
 
 /// Represents an async TCP stream between a local and a remote socket.
 ///
 /// An async version of [`std::net::TcpStream`].
-pub trait RuntimeTcpStream: OsSocket + AsyncRead + AsyncWrite + Send + Sync {
+pub trait RuntimeTcpStream: AsyncRead + AsyncWrite + Send + Sync {
     /// An async runtime.
     type Runtime: NetRuntime<TcpStream = Self>;
+    /// Owned read half of the stream, see [`RuntimeTcpStream::into_split`].
+    type OwnedReadHalf: AsyncRead + fmt::Debug + Send + Sync + Unpin;
+    /// Owned write half of the stream, see [`RuntimeTcpStream::into_split`].
+    ///
+    /// Closing the write half shuts down the write direction of the connection.
+    type OwnedWriteHalf: AsyncWrite + fmt::Debug + Send + Sync + Unpin;
 
     /// Opens a TCP connection to remote host.
     ///
@@ -19,6 +26,39 @@ pub trait RuntimeTcpStream: OsSocket + AsyncRead + AsyncWrite + Send + Sync {
     where
         Self: Sized;
 
+    /// Opens a TCP connection to remote host racing its addresses with the Happy Eyeballs
+    /// algorithm with default options, see [`HappyEyeballsBuilder`].
+    fn connect_happy_eyeballs(
+        runtime: &Self::Runtime,
+        addr: impl ToSocketAddrs<Self::Runtime>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized,
+        Self::Runtime: TimeRuntime,
+    {
+        async move { HappyEyeballsBuilder::new().connect(runtime, addr).await }
+    }
+
+    /// Splits the stream into a read half and a write half which can be moved into separate
+    /// tasks and used concurrently without locking.
+    ///
+    /// The halves are joined back into the stream with [`RuntimeTcpStream::reunite`].
+    fn into_split(self) -> (Self::OwnedReadHalf, Self::OwnedWriteHalf)
+    where
+        Self: Sized;
+
+    /// Joins the halves created by [`RuntimeTcpStream::into_split`] back into the stream.
+    ///
+    /// # Errors
+    ///
+    /// Returns the halves back if they don't originate from the same stream.
+    fn reunite(
+        read: Self::OwnedReadHalf,
+        write: Self::OwnedWriteHalf,
+    ) -> Result<Self, ReuniteError<Self::OwnedReadHalf, Self::OwnedWriteHalf>>
+    where
+        Self: Sized;
+
     /// Returns the socket address of the local half of this TCP connection.
     fn local_addr(&self) -> std::io::Result<SocketAddr>;
 
@@ -82,3 +122,16 @@ pub trait RuntimeTcpStream: OsSocket + AsyncRead + AsyncWrite + Send + Sync {
     /// calls.
     fn take_error(&self) -> std::io::Result<Option<std::io::Error>>;
 }
+
+/// An error returned by [`RuntimeTcpStream::reunite`] when the halves don't originate from the
+/// same stream. Contains the halves that were passed in.
+#[derive(Debug)]
+pub struct ReuniteError<R, W>(pub R, pub W);
+
+impl<R, W> fmt::Display for ReuniteError<R, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("tried to reunite halves that are not from the same stream")
+    }
+}
+
+impl<R: fmt::Debug, W: fmt::Debug> std::error::Error for ReuniteError<R, W> {}
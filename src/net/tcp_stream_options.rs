@@ -0,0 +1,256 @@
+use super::RuntimeTcpStream;
+use cfg_if::cfg_if;
+use std::{net::Shutdown, time::Duration};
+
+/// Extension of [`RuntimeTcpStream`] with socket options used by long-lived connections, such
+/// as keepalive probes, and with half-closing a connection.
+pub trait TcpStreamOptions: RuntimeTcpStream {
+    /// Gets the value of the `SO_KEEPALIVE` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStreamOptions::set_keepalive`].
+    fn keepalive(&self) -> std::io::Result<bool>;
+
+    /// Sets the value of the `SO_KEEPALIVE` option on this socket and the parameters of the
+    /// keepalive probes.
+    ///
+    /// If set, the system periodically probes an idle connection and closes it once the peer
+    /// stops responding, which also keeps the connection alive in NAT tables and stateful
+    /// firewalls. Parameters that are not set in [`TcpKeepalive`] keep the system defaults.
+    /// Passing `None` disables keepalive probes.
+    fn set_keepalive(&self, keepalive: Option<&TcpKeepalive>) -> std::io::Result<()>;
+
+    /// Gets the value of the `TCP_USER_TIMEOUT` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStreamOptions::set_user_timeout`].
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))
+    )]
+    fn user_timeout(&self) -> std::io::Result<Option<Duration>>;
+
+    /// Sets the value of the `TCP_USER_TIMEOUT` option on this socket.
+    ///
+    /// This value is the maximum time transmitted data may remain unacknowledged before the
+    /// connection is closed. `None` uses the system default.
+    #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux")))
+    )]
+    fn set_user_timeout(&self, timeout: Option<Duration>) -> std::io::Result<()>;
+
+    /// Gets the value of the `SO_SNDBUF` option on this socket.
+    ///
+    /// The value may differ from the one set with [`TcpStreamOptions::set_send_buffer_size`],
+    /// for example Linux doubles it to account for bookkeeping overhead.
+    fn send_buffer_size(&self) -> std::io::Result<u32>;
+
+    /// Sets the value of the `SO_SNDBUF` option on this socket, the size of the buffer
+    /// allocated by the OS for outgoing data.
+    fn set_send_buffer_size(&self, size: u32) -> std::io::Result<()>;
+
+    /// Gets the value of the `SO_RCVBUF` option on this socket.
+    ///
+    /// The value may differ from the one set with [`TcpStreamOptions::set_recv_buffer_size`],
+    /// for example Linux doubles it to account for bookkeeping overhead.
+    fn recv_buffer_size(&self) -> std::io::Result<u32>;
+
+    /// Sets the value of the `SO_RCVBUF` option on this socket, the size of the buffer
+    /// allocated by the OS for incoming data.
+    fn set_recv_buffer_size(&self, size: u32) -> std::io::Result<()>;
+
+    /// Gets the value of the `IP_TOS` option on this socket.
+    ///
+    /// For more information about this option, see [`TcpStreamOptions::set_tos`].
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(not(any(
+            target_os = "fuchsia",
+            target_os = "redox",
+            target_os = "solaris",
+            target_os = "illumos",
+            target_os = "haiku",
+        ))))
+    )]
+    fn tos(&self) -> std::io::Result<u32>;
+
+    /// Sets the value of the `IP_TOS` option on this socket.
+    ///
+    /// This value sets the type-of-service field that is used in every packet sent from this
+    /// socket. Only valid for IPv4 sockets.
+    #[cfg(not(any(
+        target_os = "fuchsia",
+        target_os = "redox",
+        target_os = "solaris",
+        target_os = "illumos",
+        target_os = "haiku",
+    )))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(not(any(
+            target_os = "fuchsia",
+            target_os = "redox",
+            target_os = "solaris",
+            target_os = "illumos",
+            target_os = "haiku",
+        ))))
+    )]
+    fn set_tos(&self, tos: u32) -> std::io::Result<()>;
+
+    /// Shuts down the read, write, or both halves of this connection.
+    ///
+    /// Shutting down the write half sends a FIN to the peer, which reads the end of the stream
+    /// while the local side can still read the remaining data.
+    ///
+    /// A version of [`std::net::TcpStream::shutdown`].
+    fn shutdown(&self, how: Shutdown) -> std::io::Result<()>;
+}
+
+/// Parameters of TCP keepalive probes, see [`TcpStreamOptions::set_keepalive`].
+///
+/// Parameters that are not set keep the system defaults. The interval is not supported on
+/// every platform and the number of retries is not supported on Windows, setting them there
+/// fails with [`std::io::ErrorKind::Unsupported`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TcpKeepalive {
+    time: Option<Duration>,
+    interval: Option<Duration>,
+    retries: Option<u32>,
+}
+
+impl TcpKeepalive {
+    /// Creates new keepalive parameters with all of them keeping the system defaults.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            time: None,
+            interval: None,
+            retries: None,
+        }
+    }
+
+    /// Sets how long the connection has to be idle before the first keepalive probe is sent,
+    /// the value of the `TCP_KEEPIDLE` option.
+    pub fn time(&mut self, time: Duration) -> &mut Self {
+        self.time = Some(time);
+        self
+    }
+
+    /// Returns how long the connection has to be idle before the first keepalive probe is sent.
+    #[must_use]
+    pub const fn get_time(&self) -> Option<Duration> {
+        self.time
+    }
+
+    /// Sets the time between keepalive probes, the value of the `TCP_KEEPINTVL` option.
+    pub fn interval(&mut self, interval: Duration) -> &mut Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Returns the time between keepalive probes.
+    #[must_use]
+    pub const fn get_interval(&self) -> Option<Duration> {
+        self.interval
+    }
+
+    /// Sets the number of unacknowledged keepalive probes after which the connection is
+    /// closed, the value of the `TCP_KEEPCNT` option.
+    pub fn retries(&mut self, retries: u32) -> &mut Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Returns the number of unacknowledged keepalive probes after which the connection is
+    /// closed.
+    #[must_use]
+    pub const fn get_retries(&self) -> Option<u32> {
+        self.retries
+    }
+}
+
+cfg_if! {
+    if #[cfg(any(
+        target_os = "android",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "fuchsia",
+        target_os = "illumos",
+        target_os = "ios",
+        target_os = "linux",
+        target_os = "macos",
+        target_os = "netbsd",
+        target_os = "tvos",
+        target_os = "watchos",
+    ))] {
+        /// Converts keepalive parameters to `socket2` ones.
+        #[cfg(feature = "socket2")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "socket2")))]
+        impl TryFrom<&TcpKeepalive> for socket2::TcpKeepalive {
+            type Error = std::io::Error;
+
+            fn try_from(keepalive: &TcpKeepalive) -> Result<Self, Self::Error> {
+                let mut params = Self::new();
+                if let Some(time) = keepalive.time {
+                    params = params.with_time(time);
+                }
+                if let Some(interval) = keepalive.interval {
+                    params = params.with_interval(interval);
+                }
+                if let Some(retries) = keepalive.retries {
+                    params = params.with_retries(retries);
+                }
+                Ok(params)
+            }
+        }
+    } else if #[cfg(windows)] {
+        /// Converts keepalive parameters to `socket2` ones, failing if the number of retries is
+        /// set.
+        #[cfg(feature = "socket2")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "socket2")))]
+        impl TryFrom<&TcpKeepalive> for socket2::TcpKeepalive {
+            type Error = std::io::Error;
+
+            fn try_from(keepalive: &TcpKeepalive) -> Result<Self, Self::Error> {
+                if keepalive.retries.is_some() {
+                    return Err(std::io::ErrorKind::Unsupported.into());
+                }
+                let mut params = Self::new();
+                if let Some(time) = keepalive.time {
+                    params = params.with_time(time);
+                }
+                if let Some(interval) = keepalive.interval {
+                    params = params.with_interval(interval);
+                }
+                Ok(params)
+            }
+        }
+    } else {
+        /// Converts keepalive parameters to `socket2` ones, failing if the interval or the number
+        /// of retries is set.
+        #[cfg(feature = "socket2")]
+        #[cfg_attr(docsrs, doc(cfg(feature = "socket2")))]
+        impl TryFrom<&TcpKeepalive> for socket2::TcpKeepalive {
+            type Error = std::io::Error;
+
+            fn try_from(keepalive: &TcpKeepalive) -> Result<Self, Self::Error> {
+                if keepalive.interval.is_some() || keepalive.retries.is_some() {
+                    return Err(std::io::ErrorKind::Unsupported.into());
+                }
+                let mut params = Self::new();
+                if let Some(time) = keepalive.time {
+                    params = params.with_time(time);
+                }
+                Ok(params)
+            }
+        }
+    }
+}
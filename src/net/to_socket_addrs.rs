@@ -1,3 +1,4 @@
+use super::NetRuntime;
 use std::{
     future::Future,
     net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6},
@@ -6,7 +7,8 @@ use std::{
 /// A trait for objects which can be converted or resolved to one or more
 /// [`SocketAddr`] values.
 ///
-/// An async version of [`std::net::ToSocketAddrs`].
+/// An async version of [`std::net::ToSocketAddrs`]. Host names are resolved with
+/// [`NetRuntime::resolve`] of the runtime.
 pub trait ToSocketAddrs<R>: Send + Sync
 where
     R: Send + Sync,
@@ -14,11 +16,12 @@ where
     /// Returned iterator over socket addresses which this type may correspond
     /// to.
     type Iterator: Iterator<Item = SocketAddr> + Send;
-    /// Future that resolves addresses.
-    type Future: Future<Output = std::io::Result<Self::Iterator>> + Send;
 
     /// Converts this object to an iterator of resolved [`SocketAddr`]s.
-    fn to_socket_addrs(&self, runtime: &R) -> Self::Future;
+    fn to_socket_addrs(
+        &self,
+        runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send;
 
     /// Utility method used to implement underlying logic for async runtime e.g. used in
     /// `arta-tokio` for socket binding in [`RuntimeTcpListener`](`super::RuntimeTcpListener`).
@@ -57,9 +60,11 @@ where
     R: Send + Sync,
 {
     type Iterator = std::iter::Once<SocketAddr>;
-    type Future = std::future::Ready<std::io::Result<Self::Iterator>>;
 
-    fn to_socket_addrs(&self, _runtime: &R) -> Self::Future {
+    fn to_socket_addrs(
+        &self,
+        _runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
         std::future::ready(Ok(std::iter::once(*self)))
     }
 }
@@ -69,10 +74,12 @@ where
     R: Send + Sync,
 {
     type Iterator = std::iter::Once<SocketAddr>;
-    type Future = std::future::Ready<std::io::Result<Self::Iterator>>;
 
-    fn to_socket_addrs(&self, runtime: &R) -> Self::Future {
-        SocketAddr::V4(*self).to_socket_addrs(runtime)
+    fn to_socket_addrs(
+        &self,
+        _runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
+        std::future::ready(Ok(std::iter::once(SocketAddr::V4(*self))))
     }
 }
 
@@ -81,10 +88,12 @@ where
     R: Send + Sync,
 {
     type Iterator = std::iter::Once<SocketAddr>;
-    type Future = std::future::Ready<std::io::Result<Self::Iterator>>;
 
-    fn to_socket_addrs(&self, runtime: &R) -> Self::Future {
-        SocketAddr::V6(*self).to_socket_addrs(runtime)
+    fn to_socket_addrs(
+        &self,
+        _runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
+        std::future::ready(Ok(std::iter::once(SocketAddr::V6(*self))))
     }
 }
 
@@ -93,10 +102,12 @@ where
     R: Send + Sync,
 {
     type Iterator = std::iter::Once<SocketAddr>;
-    type Future = std::future::Ready<std::io::Result<Self::Iterator>>;
 
-    fn to_socket_addrs(&self, runtime: &R) -> Self::Future {
-        SocketAddr::new(self.0, self.1).to_socket_addrs(runtime)
+    fn to_socket_addrs(
+        &self,
+        _runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
+        std::future::ready(Ok(std::iter::once(SocketAddr::new(self.0, self.1))))
     }
 }
 
@@ -105,10 +116,15 @@ where
     R: Send + Sync,
 {
     type Iterator = std::iter::Once<SocketAddr>;
-    type Future = std::future::Ready<std::io::Result<Self::Iterator>>;
 
-    fn to_socket_addrs(&self, runtime: &R) -> Self::Future {
-        (IpAddr::V4(self.0), self.1).to_socket_addrs(runtime)
+    fn to_socket_addrs(
+        &self,
+        _runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
+        std::future::ready(Ok(std::iter::once(SocketAddr::new(
+            IpAddr::V4(self.0),
+            self.1,
+        ))))
     }
 }
 
@@ -117,10 +133,15 @@ where
     R: Send + Sync,
 {
     type Iterator = std::iter::Once<SocketAddr>;
-    type Future = std::future::Ready<std::io::Result<Self::Iterator>>;
 
-    fn to_socket_addrs(&self, runtime: &R) -> Self::Future {
-        (IpAddr::V6(self.0), self.1).to_socket_addrs(runtime)
+    fn to_socket_addrs(
+        &self,
+        _runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
+        std::future::ready(Ok(std::iter::once(SocketAddr::new(
+            IpAddr::V6(self.0),
+            self.1,
+        ))))
     }
 }
 
@@ -129,9 +150,86 @@ where
     R: Send + Sync,
 {
     type Iterator = std::iter::Copied<std::slice::Iter<'a, SocketAddr>>;
-    type Future = std::future::Ready<std::io::Result<Self::Iterator>>;
 
-    fn to_socket_addrs(&self, _runtime: &R) -> Self::Future {
+    fn to_socket_addrs(
+        &self,
+        _runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
         std::future::ready(Ok(self.iter().copied()))
     }
 }
+
+impl<R> ToSocketAddrs<R> for (&str, u16)
+where
+    R: NetRuntime,
+{
+    type Iterator = std::vec::IntoIter<SocketAddr>;
+
+    fn to_socket_addrs(
+        &self,
+        runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
+        resolve_host(runtime, self.0, self.1)
+    }
+}
+
+impl<R> ToSocketAddrs<R> for &str
+where
+    R: NetRuntime,
+{
+    type Iterator = std::vec::IntoIter<SocketAddr>;
+
+    fn to_socket_addrs(
+        &self,
+        runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
+        resolve_addr(runtime, self)
+    }
+}
+
+impl<R> ToSocketAddrs<R> for String
+where
+    R: NetRuntime,
+{
+    type Iterator = std::vec::IntoIter<SocketAddr>;
+
+    fn to_socket_addrs(
+        &self,
+        runtime: &R,
+    ) -> impl Future<Output = std::io::Result<Self::Iterator>> + Send {
+        resolve_addr(runtime, self)
+    }
+}
+
+/// Resolves a `host:port` string, IP literals are parsed without calling the resolver.
+async fn resolve_addr<R>(runtime: &R, addr: &str) -> std::io::Result<std::vec::IntoIter<SocketAddr>>
+where
+    R: NetRuntime,
+{
+    if let Ok(addr) = addr.parse::<SocketAddr>() {
+        return Ok(vec![addr].into_iter());
+    }
+
+    let (host, port) = addr
+        .rsplit_once(':')
+        .and_then(|(host, port)| Some((host, port.parse::<u16>().ok()?)))
+        .ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "invalid socket address")
+        })?;
+    resolve_host(runtime, host, port).await
+}
+
+/// Resolves a host name, IP literals are parsed without calling the resolver.
+async fn resolve_host<R>(
+    runtime: &R,
+    host: &str,
+    port: u16,
+) -> std::io::Result<std::vec::IntoIter<SocketAddr>>
+where
+    R: NetRuntime,
+{
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(vec![SocketAddr::new(ip, port)].into_iter());
+    }
+    Ok(runtime.resolve(host, port).await?.into_iter())
+}
@@ -1,4 +1,4 @@
-use super::{NetRuntime, OsSocket, ToSocketAddrs};
+use super::{NetRuntime, ToSocketAddrs};
 use std::{
     future::Future,
     net::{Ipv4Addr, Ipv6Addr, SocketAddr},
@@ -7,7 +7,7 @@ use std::{
 /// An async UDP socket.
 ///
 /// An async version of [`std::net::UdpSocket`].
-pub trait RuntimeUdpSocket: OsSocket {
+pub trait RuntimeUdpSocket {
     /// An async runtime.
     type Runtime: NetRuntime<UdpSocket = Self>;
 
@@ -0,0 +1,131 @@
+use super::{OsSocket, UnixNetRuntime};
+use std::{future::Future, os::unix::net::SocketAddr, path::Path};
+
+/// An async Unix domain datagram socket.
+///
+/// An async version of [`std::os::unix::net::UnixDatagram`].
+pub trait RuntimeUnixDatagram: OsSocket + Send + Sync {
+    /// An async runtime.
+    type Runtime: UnixNetRuntime<UnixDatagram = Self>;
+
+    /// Creates a Unix datagram socket bound to the specified path.
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::bind`].
+    fn bind(runtime: &Self::Runtime, path: impl AsRef<Path>) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Self::bind_addr(runtime, &SocketAddr::from_pathname(path)?)
+    }
+
+    /// Creates a Unix datagram socket bound to the specified socket address.
+    ///
+    /// Unlike [`RuntimeUnixDatagram::bind`] this also accepts addresses in the abstract
+    /// namespace on Linux, see [`std::os::linux::net::SocketAddrExt`].
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::bind_addr`].
+    fn bind_addr(runtime: &Self::Runtime, addr: &SocketAddr) -> std::io::Result<Self>
+    where
+        Self: Sized;
+
+    /// Creates a Unix datagram socket which is not bound to any address.
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::unbound`].
+    fn unbound(runtime: &Self::Runtime) -> std::io::Result<Self>
+    where
+        Self: Sized;
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::pair`].
+    fn pair(runtime: &Self::Runtime) -> std::io::Result<(Self, Self)>
+    where
+        Self: Sized;
+
+    /// Connects the socket to the specified path, allowing the `send` and `recv` syscalls to be
+    /// used and only receiving data from that address.
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::connect`].
+    fn connect(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        self.connect_addr(&SocketAddr::from_pathname(path)?)
+    }
+
+    /// Connects the socket to the specified socket address.
+    ///
+    /// Unlike [`RuntimeUnixDatagram::connect`] this also accepts addresses in the abstract
+    /// namespace on Linux, see [`std::os::linux::net::SocketAddrExt`].
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::connect_addr`].
+    fn connect_addr(&self, addr: &SocketAddr) -> std::io::Result<()> {
+        // Connecting doesn't block, so it's done through a duplicate of the descriptor which
+        // refers to the same socket.
+        std::os::unix::net::UnixDatagram::from(self.as_fd().try_clone_to_owned()?)
+            .connect_addr(addr)
+    }
+
+    /// Sends data on the socket to the socket's peer. On success, returns the number of bytes
+    /// written.
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::send`].
+    fn send(&self, buf: &[u8]) -> impl Future<Output = std::io::Result<usize>> + Send;
+
+    /// Sends data on the socket to the specified path. On success, returns the number of bytes
+    /// written.
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::send_to`].
+    fn send_to(
+        &self,
+        buf: &[u8],
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<usize>> + Send;
+
+    /// Sends data on the socket to the specified socket address. On success, returns the number
+    /// of bytes written.
+    ///
+    /// Unlike [`RuntimeUnixDatagram::send_to`] this also accepts addresses in the abstract
+    /// namespace on Linux, see [`std::os::linux::net::SocketAddrExt`].
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::send_to_addr`].
+    ///
+    /// The default implementation only supports addresses with a path name, which are passed to
+    /// [`RuntimeUnixDatagram::send_to`].
+    fn send_to_addr(
+        &self,
+        buf: &[u8],
+        addr: &SocketAddr,
+    ) -> impl Future<Output = std::io::Result<usize>> + Send {
+        let path = addr.as_pathname().map(Path::to_owned);
+        async move {
+            let path = path.ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "sending to an address without a path name is not supported",
+                )
+            })?;
+            self.send_to(buf, path).await
+        }
+    }
+
+    /// Receives data from the socket's peer. On success, returns the number of bytes read.
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::recv`].
+    fn recv(&self, buf: &mut [u8]) -> impl Future<Output = std::io::Result<usize>> + Send;
+
+    /// Receives data from the socket. On success, returns the number of bytes read and the
+    /// address from whence the data came.
+    ///
+    /// An async version of [`std::os::unix::net::UnixDatagram::recv_from`].
+    fn recv_from(
+        &self,
+        buf: &mut [u8],
+    ) -> impl Future<Output = std::io::Result<(usize, SocketAddr)>> + Send;
+
+    /// Returns the address of this socket.
+    fn local_addr(&self) -> std::io::Result<SocketAddr>;
+
+    /// Returns the address of this socket's peer.
+    fn peer_addr(&self) -> std::io::Result<SocketAddr>;
+
+    /// Gets the value of the `SO_ERROR` option on this socket.
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>>;
+}
@@ -0,0 +1,45 @@
+use super::{OsSocket, UnixNetRuntime};
+use std::{future::Future, os::unix::net::SocketAddr, path::Path};
+
+/// Represents an async Unix domain socket server, listening for connections.
+///
+/// An async version of [`std::os::unix::net::UnixListener`].
+pub trait RuntimeUnixListener: OsSocket + Send + Sync {
+    /// An async runtime.
+    type Runtime: UnixNetRuntime<UnixListener = Self>;
+
+    /// Accept a new incoming connection from this listener.
+    ///
+    /// An async version of [`std::os::unix::net::UnixListener::accept`].
+    fn accept(
+        &self,
+    ) -> impl Future<
+        Output = std::io::Result<(<Self::Runtime as UnixNetRuntime>::UnixStream, SocketAddr)>,
+    > + Send;
+
+    /// Creates a new `UnixListener` bound to the specified path.
+    ///
+    /// An async version of [`std::os::unix::net::UnixListener::bind`].
+    fn bind(runtime: &Self::Runtime, path: impl AsRef<Path>) -> std::io::Result<Self>
+    where
+        Self: Sized,
+    {
+        Self::bind_addr(runtime, &SocketAddr::from_pathname(path)?)
+    }
+
+    /// Creates a new `UnixListener` bound to the specified socket address.
+    ///
+    /// Unlike [`RuntimeUnixListener::bind`] this also accepts addresses in the abstract
+    /// namespace on Linux, see [`std::os::linux::net::SocketAddrExt`].
+    ///
+    /// An async version of [`std::os::unix::net::UnixListener::bind_addr`].
+    fn bind_addr(runtime: &Self::Runtime, addr: &SocketAddr) -> std::io::Result<Self>
+    where
+        Self: Sized;
+
+    /// Returns the local socket address of this listener.
+    fn local_addr(&self) -> std::io::Result<SocketAddr>;
+
+    /// Gets the value of the `SO_ERROR` option on this socket.
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>>;
+}
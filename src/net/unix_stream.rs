@@ -0,0 +1,50 @@
+use super::{OsSocket, UnixNetRuntime};
+use futures::{AsyncRead, AsyncWrite};
+use std::{future::Future, os::unix::net::SocketAddr, path::Path};
+
+/// Represents an async Unix domain stream socket.
+///
+/// An async version of [`std::os::unix::net::UnixStream`].
+pub trait RuntimeUnixStream: OsSocket + AsyncRead + AsyncWrite + Send + Sync {
+    /// An async runtime.
+    type Runtime: UnixNetRuntime<UnixStream = Self>;
+
+    /// Connects to the socket bound to the specified path.
+    ///
+    /// An async version of [`std::os::unix::net::UnixStream::connect`].
+    fn connect(
+        runtime: &Self::Runtime,
+        path: impl AsRef<Path>,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized;
+
+    /// Connects to the socket bound to the specified socket address.
+    ///
+    /// Unlike [`RuntimeUnixStream::connect`] this also accepts addresses in the abstract
+    /// namespace on Linux, see [`std::os::linux::net::SocketAddrExt`].
+    ///
+    /// An async version of [`std::os::unix::net::UnixStream::connect_addr`].
+    fn connect_addr(
+        runtime: &Self::Runtime,
+        addr: &SocketAddr,
+    ) -> impl Future<Output = std::io::Result<Self>> + Send
+    where
+        Self: Sized;
+
+    /// Creates an unnamed pair of connected sockets.
+    ///
+    /// An async version of [`std::os::unix::net::UnixStream::pair`].
+    fn pair(runtime: &Self::Runtime) -> std::io::Result<(Self, Self)>
+    where
+        Self: Sized;
+
+    /// Returns the socket address of the local half of this connection.
+    fn local_addr(&self) -> std::io::Result<SocketAddr>;
+
+    /// Returns the socket address of the remote half of this connection.
+    fn peer_addr(&self) -> std::io::Result<SocketAddr>;
+
+    /// Gets the value of the `SO_ERROR` option on this socket.
+    fn take_error(&self) -> std::io::Result<Option<std::io::Error>>;
+}
@@ -225,7 +225,10 @@ pub trait RuntimeCommand {
     ///
     /// [POSIX fork() specification]:
     ///     https://pubs.opengroup.org/onlinepubs/9699919799/functions/fork.html
-    #[allow(clippy::missing_safety_doc)]
+    #[expect(
+        clippy::missing_safety_doc,
+        reason = "the safety requirements are those of `CommandExt::pre_exec`"
+    )]
     #[cfg(any(unix, doc))]
     #[cfg_attr(docsrs, doc(cfg(unix)))]
     unsafe fn pre_exec(
@@ -281,12 +284,16 @@ pub trait RuntimeChild {
     /// Simultaneously waits for the child to exit and collect all remaining
     /// output on the stdout/stderr handles, returning an `Output`
     /// instance.
+    ///
+    /// The stdin handle is closed before waiting to avoid a deadlock.
     fn output(self) -> impl Future<Output = std::io::Result<Output>> + Send;
 
     /// Waits for the child to exit completely, returning the status that it
     /// exited with. This function will continue to have the same return value
     /// after it has been called at least once.
     ///
+    /// The stdin handle is closed before waiting to avoid a deadlock.
+    ///
     /// An async version of [`std::process::Child::wait`].
     fn status(&mut self) -> impl Future<Output = std::io::Result<ExitStatus>> + Send;
 
@@ -307,4 +314,15 @@ pub trait ProcessRuntime: Send + Sync {
     type Command: RuntimeCommand<Runtime = Self>;
     /// Runtime's process child. An async version of [`std::process::Child`].
     type Child: RuntimeChild<Runtime = Self>;
+
+    /// Constructs a new command bound to this runtime, see [`RuntimeCommand::new`].
+    ///
+    /// Runtimes that aren't global, like an owned tokio runtime, spawn processes created this way
+    /// on themselves instead of the runtime of the calling context.
+    fn command(&self, program: impl AsRef<OsStr>) -> Self::Command
+    where
+        Self::Command: Sized,
+    {
+        Self::Command::new(program)
+    }
 }
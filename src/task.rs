@@ -1,14 +1,32 @@
 //! Green threads spawning and management.
 
+mod builder;
+mod join_error;
+mod join_set;
+mod scope;
+
+pub use builder::*;
+pub use join_error::*;
+pub use join_set::*;
+pub use scope::*;
+
 use futures::Future;
+use std::task::Poll;
 
 /// Represents a handle to control spawned task execution.
-pub trait RuntimeJoinHandle<T>: Future<Output = std::thread::Result<T>>
+///
+/// Awaiting the handle returns the output of the task or a [`JoinError`] telling whether the task
+/// panicked or was cancelled. Dropping the handle detaches the task, it keeps running in
+/// background.
+pub trait RuntimeJoinHandle<T>: Future<Output = Result<T, JoinError>> + Send
 where
     T: Send + 'static,
 {
     /// Cancel this task.
-    fn cancel(self) -> impl Future<Output = Option<std::thread::Result<T>>> + Send;
+    ///
+    /// Returns a cancellation error once the task is dropped without completion or its result if
+    /// it has already completed.
+    fn cancel(self) -> impl Future<Output = Result<T, JoinError>> + Send;
 }
 
 /// Represents an async runtime that supports green threads spawning.
@@ -27,4 +45,119 @@ pub trait TaskRuntime: Send + Sync {
     fn spawn_blocking<R>(&self, task: impl FnOnce() -> R + Send + 'static) -> Self::JoinHandle<R>
     where
         R: Send + 'static;
+
+    /// Runs a future to completion, blocking the current thread.
+    ///
+    /// Intended to enter asynchronous code from synchronous one, e.g. from `main` or from an FFI
+    /// callback. Calling it from inside of an asynchronous context may panic or deadlock.
+    ///
+    /// The default implementation polls the future on the current thread with
+    /// [`futures::executor::block_on`], which suits runtimes that drive their tasks and I/O on
+    /// threads of their own. Runtimes that need the blocked thread to drive them must override
+    /// it.
+    fn block_on<R>(&self, future: impl Future<Output = R>) -> R {
+        futures::executor::block_on(future)
+    }
+
+    /// Yields execution back to the runtime, letting other tasks run.
+    ///
+    /// The default implementation wakes the current task and returns `Pending` once.
+    fn yield_now(&self) -> impl Future<Output = ()> + Send {
+        let mut is_yielded = false;
+        futures::future::poll_fn(move |cx| {
+            if is_yielded {
+                Poll::Ready(())
+            } else {
+                is_yielded = true;
+                cx.waker().wake_by_ref();
+                Poll::Pending
+            }
+        })
+    }
+
+    /// Spawn thread configured by a [`TaskBuilder`].
+    ///
+    /// The default implementation ignores all options.
+    fn spawn_with<R>(
+        &self,
+        _builder: &TaskBuilder,
+        future: impl Future<Output = R> + Send + 'static,
+    ) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        self.spawn(future)
+    }
+
+    /// Spawn thread configured by a [`TaskBuilder`] that may block on I/O operation or does some
+    /// CPU heavy work.
+    ///
+    /// The default implementation executes the task on a dedicated thread named by the builder
+    /// if the stack size is set, see [`TaskBuilder::spawn_thread`], and ignores all options
+    /// otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the OS fails to create a thread, like [`std::thread::spawn`].
+    fn spawn_blocking_with<R>(
+        &self,
+        builder: &TaskBuilder,
+        task: impl FnOnce() -> R + Send + 'static,
+    ) -> Self::JoinHandle<R>
+    where
+        R: Send + 'static,
+    {
+        match builder.get_stack_size() {
+            Some(_) => self.spawn(builder.spawn_thread(task).unwrap()),
+            None => self.spawn_blocking(task),
+        }
+    }
+
+    /// Runs a scope within which tasks that borrow data of the enclosing stack frame can be
+    /// spawned.
+    ///
+    /// Returned future doesn't complete until all tasks spawned within the scope have completed.
+    /// A panic of a task propagates to the awaiter of the scope, dropping the scope cancels its
+    /// remaining tasks.
+    ///
+    /// Tasks are executed concurrently by the scope itself rather than spawned on the runtime:
+    /// it's the only way to borrow data soundly as the scope can be forgotten without running its
+    /// destructor. Use [`Self::spawn`] to execute tasks in parallel.
+    fn scope<'env, F, Fut>(&self, body: F) -> ScopeFuture<'env, Fut>
+    where
+        F: FnOnce(Scope<'env>) -> Fut,
+        Fut: Future,
+    {
+        ScopeFuture::new(body)
+    }
+}
+
+/// Represents a handle to control execution of a task spawned on the current thread.
+///
+/// Unlike [`RuntimeJoinHandle`] neither the task nor its output are required to be `Send`.
+pub trait RuntimeLocalJoinHandle<T>: Future<Output = Result<T, JoinError>>
+where
+    T: 'static,
+{
+    /// Cancel this task.
+    ///
+    /// Returns a cancellation error once the task is dropped without completion or its result if
+    /// it has already completed.
+    fn cancel(self) -> impl Future<Output = Result<T, JoinError>>;
+}
+
+/// Represents an async runtime that supports spawning of green threads that are not `Send`.
+///
+/// Such tasks are executed on the thread they were spawned from, so this trait is also the
+/// extension point for thread-per-core runtimes.
+pub trait LocalTaskRuntime {
+    /// Handle emitted on local thread spawn.
+    type LocalJoinHandle<T>: RuntimeLocalJoinHandle<T>
+    where
+        T: 'static;
+
+    /// Spawn thread that is executed on the current thread.
+    fn spawn_local<R>(&self, future: impl Future<Output = R> + 'static) -> Self::LocalJoinHandle<R>
+    where
+        R: 'static;
 }
@@ -0,0 +1,119 @@
+use super::TaskRuntime;
+use futures::{channel::oneshot, Future};
+use std::panic::AssertUnwindSafe;
+
+/// Options which can be used to configure a spawned task.
+///
+/// Options are hints: runtimes that don't support some of them ignore them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TaskBuilder {
+    name: Option<String>,
+    stack_size: Option<usize>,
+}
+
+impl TaskBuilder {
+    /// Creates a new builder of a task without any options set.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            name: None,
+            stack_size: None,
+        }
+    }
+
+    /// Sets the name of a task, e.g. to be displayed by debugging tools and in panic reports.
+    pub fn name(&mut self, name: impl Into<String>) -> &mut Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Sets the stack size, in bytes, of a thread executing a blocking task.
+    ///
+    /// A blocking task with the stack size set is executed on a dedicated thread instead of the
+    /// blocking pool of a runtime, see [`TaskBuilder::spawn_thread`]. Ignored for async tasks,
+    /// which are executed on threads of the runtime.
+    pub fn stack_size(&mut self, size: usize) -> &mut Self {
+        self.stack_size = Some(size);
+        self
+    }
+
+    /// Returns the name of a task if it was set.
+    #[must_use]
+    pub fn get_name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Returns the stack size of a thread executing a blocking task if it was set.
+    #[must_use]
+    pub const fn get_stack_size(&self) -> Option<usize> {
+        self.stack_size
+    }
+
+    /// Spawns a dedicated thread named and sized by this builder that executes a blocking task.
+    ///
+    /// Returned future resolves to the output of the task and resumes its panic, runtimes spawn
+    /// it as an async task to honor the stack size of blocking tasks.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the OS fails to create a thread.
+    ///
+    /// # Panics
+    ///
+    /// Returned future panics with the payload of a panic of the task.
+    pub fn spawn_thread<R>(
+        &self,
+        task: impl FnOnce() -> R + Send + 'static,
+    ) -> std::io::Result<impl Future<Output = R> + Send + 'static>
+    where
+        R: Send + 'static,
+    {
+        let mut thread = std::thread::Builder::new();
+        if let Some(name) = &self.name {
+            thread = thread.name(name.clone());
+        }
+        if let Some(size) = self.stack_size {
+            thread = thread.stack_size(size);
+        }
+
+        let (sender, receiver) = oneshot::channel();
+        thread.spawn(move || {
+            // The receiver is gone only if the task awaiting the output was cancelled.
+            drop(sender.send(std::panic::catch_unwind(AssertUnwindSafe(task))));
+        })?;
+
+        Ok(async move {
+            match receiver.await.unwrap() {
+                Ok(output) => output,
+                Err(payload) => std::panic::resume_unwind(payload),
+            }
+        })
+    }
+
+    /// Spawn thread configured by this builder.
+    pub fn spawn<RT, R>(
+        &self,
+        runtime: &RT,
+        future: impl Future<Output = R> + Send + 'static,
+    ) -> RT::JoinHandle<R>
+    where
+        RT: TaskRuntime,
+        R: Send + 'static,
+    {
+        runtime.spawn_with(self, future)
+    }
+
+    /// Spawn thread configured by this builder that may block on I/O operation or does some CPU
+    /// heavy work.
+    pub fn spawn_blocking<RT, R>(
+        &self,
+        runtime: &RT,
+        task: impl FnOnce() -> R + Send + 'static,
+    ) -> RT::JoinHandle<R>
+    where
+        RT: TaskRuntime,
+        R: Send + 'static,
+    {
+        runtime.spawn_blocking_with(self, task)
+    }
+}
@@ -0,0 +1,108 @@
+use std::{any::Any, fmt};
+
+/// An error returned by a join handle when a task didn't complete successfully.
+pub struct JoinError {
+    repr: Repr,
+}
+
+enum Repr {
+    Cancelled,
+    Panic(Box<dyn Any + Send + 'static>),
+}
+
+impl JoinError {
+    /// Creates an error of a task that was cancelled before completion.
+    #[must_use]
+    pub const fn cancelled() -> Self {
+        Self {
+            repr: Repr::Cancelled,
+        }
+    }
+
+    /// Creates an error of a task that panicked with the given payload.
+    #[must_use]
+    pub fn panic(payload: Box<dyn Any + Send + 'static>) -> Self {
+        Self {
+            repr: Repr::Panic(payload),
+        }
+    }
+
+    /// Returns true if the task was cancelled before completion, e.g. by an explicit cancellation
+    /// or by a runtime shutdown.
+    #[must_use]
+    pub const fn is_cancelled(&self) -> bool {
+        matches!(self.repr, Repr::Cancelled)
+    }
+
+    /// Returns true if the task panicked.
+    #[must_use]
+    pub const fn is_panic(&self) -> bool {
+        matches!(self.repr, Repr::Panic(_))
+    }
+
+    /// Consumes the error, returning the payload of a panic the task panicked with.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the error doesn't represent a panic. Use [`Self::try_into_panic`] to handle
+    /// this case.
+    #[must_use]
+    #[expect(clippy::panic, reason = "mirrors `tokio::task::JoinError::into_panic`")]
+    pub fn into_panic(self) -> Box<dyn Any + Send + 'static> {
+        match self.try_into_panic() {
+            Ok(payload) => payload,
+            Err(err) => {
+                panic!("`JoinError::into_panic` called on an error that isn't a panic: {err}")
+            }
+        }
+    }
+
+    /// Consumes the error, returning the payload of a panic the task panicked with or the error
+    /// itself if it doesn't represent a panic.
+    pub fn try_into_panic(self) -> Result<Box<dyn Any + Send + 'static>, Self> {
+        match self.repr {
+            Repr::Panic(payload) => Ok(payload),
+            Repr::Cancelled => Err(self),
+        }
+    }
+
+    fn panic_message(&self) -> Option<&str> {
+        match &self.repr {
+            Repr::Panic(payload) => payload
+                .downcast_ref::<&str>()
+                .copied()
+                .or_else(|| payload.downcast_ref::<String>().map(String::as_str)),
+            Repr::Cancelled => None,
+        }
+    }
+}
+
+impl fmt::Debug for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.repr {
+            Repr::Cancelled => f.write_str("JoinError::Cancelled"),
+            Repr::Panic(_) => f
+                .debug_tuple("JoinError::Panic")
+                .field(&self.panic_message().unwrap_or("..."))
+                .finish(),
+        }
+    }
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.repr, self.panic_message()) {
+            (Repr::Cancelled, _) => f.write_str("task was cancelled"),
+            (Repr::Panic(_), Some(message)) => write!(f, "task panicked with message `{message}`"),
+            (Repr::Panic(_), None) => f.write_str("task panicked"),
+        }
+    }
+}
+
+impl std::error::Error for JoinError {}
+
+impl From<JoinError> for std::io::Error {
+    fn from(err: JoinError) -> Self {
+        Self::other(err.to_string())
+    }
+}
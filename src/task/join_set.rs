@@ -0,0 +1,154 @@
+use super::{JoinError, TaskRuntime};
+use futures::{
+    future::{AbortHandle, Abortable, Aborted},
+    stream::FuturesUnordered,
+    Future, Stream, StreamExt as _,
+};
+use pin_project_lite::pin_project;
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+/// A collection of tasks spawned on a runtime.
+///
+/// Outputs of tasks are returned in the order they complete by [`Self::join_next`] or by
+/// polling the set as a [`Stream`]. Dropping the set cancels all remaining tasks.
+///
+/// Tasks are cancelled cooperatively: a cancelled task is dropped the next time the runtime
+/// polls it, regardless of the runtime's own cancellation support.
+pub struct JoinSet<RT, T>
+where
+    RT: TaskRuntime,
+    T: Send + 'static,
+{
+    tasks: FuturesUnordered<JoinSetTask<RT::JoinHandle<Result<T, Aborted>>>>,
+}
+
+impl<RT, T> JoinSet<RT, T>
+where
+    RT: TaskRuntime,
+    T: Send + 'static,
+{
+    /// Creates an empty set.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            tasks: FuturesUnordered::new(),
+        }
+    }
+
+    /// Returns the number of tasks in the set that are not yet joined.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.tasks.len()
+    }
+
+    /// Returns `true` if the set contains no tasks.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.tasks.is_empty()
+    }
+
+    /// Spawn thread on a runtime and add it to the set.
+    pub fn spawn(&mut self, runtime: &RT, future: impl Future<Output = T> + Send + 'static) {
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        self.tasks.push(JoinSetTask {
+            handle: runtime.spawn(Abortable::new(future, abort_registration)),
+            abort_handle,
+        });
+    }
+
+    /// Waits until one of the tasks in the set completes and returns its output.
+    ///
+    /// Returns `None` if the set is empty.
+    pub async fn join_next(&mut self) -> Option<Result<T, JoinError>> {
+        self.tasks.next().await
+    }
+
+    /// Cancels all tasks in the set. Cancelled tasks are still returned by [`Self::join_next`]
+    /// with a cancellation error unless they have already completed.
+    pub fn abort_all(&self) {
+        for task in Pin::new(&self.tasks).iter_pin_ref() {
+            task.abort_handle.abort();
+        }
+    }
+
+    /// Cancels all tasks in the set and waits until they're dropped.
+    pub async fn shutdown(&mut self) {
+        self.abort_all();
+        while self.join_next().await.is_some() {}
+    }
+}
+
+impl<RT, T> Default for JoinSet<RT, T>
+where
+    RT: TaskRuntime,
+    T: Send + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<RT, T> fmt::Debug for JoinSet<RT, T>
+where
+    RT: TaskRuntime,
+    T: Send + 'static,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("JoinSet")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<RT, T> Stream for JoinSet<RT, T>
+where
+    RT: TaskRuntime,
+    T: Send + 'static,
+{
+    type Item = Result<T, JoinError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.tasks.poll_next_unpin(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+impl<RT, T> Drop for JoinSet<RT, T>
+where
+    RT: TaskRuntime,
+    T: Send + 'static,
+{
+    fn drop(&mut self) {
+        self.abort_all();
+    }
+}
+
+pin_project! {
+    struct JoinSetTask<H> {
+        #[pin]
+        handle: H,
+        abort_handle: AbortHandle,
+    }
+}
+
+impl<H, T> Future for JoinSetTask<H>
+where
+    H: Future<Output = Result<Result<T, Aborted>, JoinError>>,
+{
+    type Output = Result<T, JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().handle.poll(cx).map(|result| match result {
+            Ok(Ok(output)) => Ok(output),
+            Ok(Err(Aborted)) => Err(JoinError::cancelled()),
+            Err(err) => Err(err),
+        })
+    }
+}
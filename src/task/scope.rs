@@ -0,0 +1,175 @@
+use super::JoinError;
+use futures::{
+    channel::oneshot,
+    future::{BoxFuture, MaybeDone},
+    stream::FuturesUnordered,
+    Future, FutureExt as _, StreamExt as _,
+};
+use pin_project_lite::pin_project;
+use std::{
+    fmt,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, Waker},
+};
+
+/// A scope to spawn tasks that can borrow data of the enclosing stack frame.
+///
+/// Created by [`TaskRuntime::scope`](super::TaskRuntime::scope). Clones refer to the same scope.
+pub struct Scope<'env> {
+    state: Arc<Mutex<ScopeState<'env>>>,
+}
+
+struct ScopeState<'env> {
+    spawned: Vec<BoxFuture<'env, ()>>,
+    waker: Option<Waker>,
+}
+
+impl<'env> Scope<'env> {
+    fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(ScopeState {
+                spawned: Vec::new(),
+                waker: None,
+            })),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, ScopeState<'env>> {
+        self.state.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Spawn thread within this scope.
+    pub fn spawn<T>(&self, future: impl Future<Output = T> + Send + 'env) -> ScopedJoinHandle<T>
+    where
+        T: Send + 'env,
+    {
+        let (sender, receiver) = oneshot::channel();
+        let mut state = self.lock();
+        state.spawned.push(
+            async move {
+                // Output is discarded if the handle is dropped.
+                drop(sender.send(future.await));
+            }
+            .boxed(),
+        );
+        if let Some(waker) = state.waker.take() {
+            waker.wake();
+        }
+
+        ScopedJoinHandle { receiver }
+    }
+}
+
+impl Clone for Scope<'_> {
+    fn clone(&self) -> Self {
+        Self {
+            state: Arc::clone(&self.state),
+        }
+    }
+}
+
+impl fmt::Debug for Scope<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Scope").finish_non_exhaustive()
+    }
+}
+
+/// Handle of a task spawned within a [`Scope`].
+///
+/// Awaiting the handle returns the output of the task or a cancellation error if the scope was
+/// dropped before the task completed. Dropping the handle doesn't cancel the task.
+#[derive(Debug)]
+pub struct ScopedJoinHandle<T> {
+    receiver: oneshot::Receiver<T>,
+}
+
+impl<T> Future for ScopedJoinHandle<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.receiver
+            .poll_unpin(cx)
+            .map_err(|_| JoinError::cancelled())
+    }
+}
+
+pin_project! {
+    /// Future returned by [`TaskRuntime::scope`](super::TaskRuntime::scope).
+    ///
+    /// Resolves to the output of the scope body once all tasks spawned within the scope have
+    /// completed. Dropping the future cancels the remaining tasks.
+    #[must_use = "futures do nothing unless you `.await` or poll them"]
+    pub struct ScopeFuture<'env, F>
+    where
+        F: Future,
+    {
+        #[pin]
+        body: MaybeDone<F>,
+        tasks: FuturesUnordered<BoxFuture<'env, ()>>,
+        scope: Scope<'env>,
+    }
+
+    impl<'env, F> PinnedDrop for ScopeFuture<'env, F>
+    where
+        F: Future,
+    {
+        fn drop(this: Pin<&mut Self>) {
+            // Tasks that were spawned but never polled may hold clones of the scope.
+            let spawned = std::mem::take(&mut this.scope.lock().spawned);
+            drop(spawned);
+        }
+    }
+}
+
+impl<'env, F> ScopeFuture<'env, F>
+where
+    F: Future,
+{
+    pub(super) fn new(body: impl FnOnce(Scope<'env>) -> F) -> Self {
+        let scope = Scope::new();
+        Self {
+            body: MaybeDone::Future(body(scope.clone())),
+            tasks: FuturesUnordered::new(),
+            scope,
+        }
+    }
+}
+
+impl<F> Future for ScopeFuture<'_, F>
+where
+    F: Future,
+{
+    type Output = F::Output;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let is_body_done = this.body.as_mut().poll(cx).is_ready();
+            // A panic of a task unwinds through this poll, propagating to the scope.
+            while let Poll::Ready(Some(())) = this.tasks.poll_next_unpin(cx) {}
+
+            let mut state = this.scope.lock();
+            if state.spawned.is_empty() {
+                if is_body_done && this.tasks.is_empty() {
+                    drop(state);
+                    return this.body.take_output().map_or(Poll::Pending, Poll::Ready);
+                }
+                state.waker = Some(cx.waker().clone());
+                return Poll::Pending;
+            }
+            this.tasks.extend(state.spawned.drain(..));
+        }
+    }
+}
+
+impl<F> fmt::Debug for ScopeFuture<'_, F>
+where
+    F: Future,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ScopeFuture")
+            .field("tasks", &self.tasks.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -1,10 +1,133 @@
 //! Time tracking operations.
 
-use futures::Future;
-use std::time::Duration;
+mod cron;
+mod delay_queue;
+mod elapsed;
+mod interval;
+mod rate_limit;
+mod retry;
+mod schedule;
+
+pub use cron::*;
+pub use delay_queue::*;
+pub use elapsed::*;
+pub use interval::*;
+pub use rate_limit::*;
+pub use retry::*;
+pub use schedule::*;
+
+use futures::{
+    future::{select, Either},
+    Future,
+};
+use std::{
+    pin::pin,
+    time::{Duration, Instant, SystemTime},
+};
+
+/// Represents a future that completes once the runtime's clock reaches its deadline.
+///
+/// Unlike an opaque future it can be stored in a struct field, and its deadline can be moved
+/// without creating a new future, e.g. to implement idle timeouts.
+pub trait RuntimeSleep: Future<Output = ()> + Unpin + Send {
+    /// Returns the instant this sleep completes at.
+    fn deadline(&self) -> Instant;
+
+    /// Moves the deadline of this sleep. A sleep that has already completed becomes pending again
+    /// if the new deadline is in the future.
+    fn reset(&mut self, deadline: Instant);
+}
 
 /// Represents an async runtime that supports asynchronous timer.
 pub trait TimeRuntime {
+    /// Future returned by [`Self::sleep`] and [`Self::sleep_until`].
+    type Sleep: RuntimeSleep;
+
+    /// Returns the current instant of the runtime's clock.
+    ///
+    /// Runtimes with a virtual or paused clock return instants of that clock, so elapsed time
+    /// measured with this method is consistent with deadlines of timers.
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    /// Returns the current wall-clock time of the runtime.
+    fn system_now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+
     /// Sleep for a specified time.
-    fn sleep(&self, duration: Duration) -> impl Future<Output = ()> + Send;
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        let now = self.now();
+        self.sleep_until(now.checked_add(duration).unwrap_or_else(|| far_future(now)))
+    }
+
+    /// Sleep until the specified deadline of the runtime's clock.
+    fn sleep_until(&self, deadline: Instant) -> Self::Sleep;
+
+    /// Requires a future to complete before the specified duration has elapsed.
+    ///
+    /// Returns [`Elapsed`] and drops the future if it didn't complete in time.
+    fn timeout<F>(
+        &self,
+        duration: Duration,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send
+    where
+        F: Future + Send,
+    {
+        let sleep = self.sleep(duration);
+        async move {
+            match select(pin!(future), pin!(sleep)).await {
+                Either::Left((output, _)) => Ok(output),
+                Either::Right(((), _)) => Err(Elapsed::new()),
+            }
+        }
+    }
+
+    /// Requires a future to complete before the specified deadline.
+    ///
+    /// Returns [`Elapsed`] and drops the future if it didn't complete in time.
+    fn timeout_at<F>(
+        &self,
+        deadline: Instant,
+        future: F,
+    ) -> impl Future<Output = Result<F::Output, Elapsed>> + Send
+    where
+        F: Future + Send,
+    {
+        self.timeout(deadline.saturating_duration_since(self.now()), future)
+    }
+
+    /// Creates an [`Interval`] that ticks every period of time, starting immediately.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    fn interval(&self, period: Duration) -> Interval<'_, Self>
+    where
+        Self: Sized,
+    {
+        Interval::new(self, self.now(), period)
+    }
+
+    /// Creates an [`Interval`] that ticks every period of time, starting at the specified
+    /// instant.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `period` is zero.
+    fn interval_at(&self, start: Instant, period: Duration) -> Interval<'_, Self>
+    where
+        Self: Sized,
+    {
+        Interval::new(self, start, period)
+    }
+}
+
+/// Returns an instant roughly 30 years from `now`, used as a deadline that's never reached.
+fn far_future(now: Instant) -> Instant {
+    const FAR_FUTURE_SECS: u64 = 30 * 365 * 24 * 60 * 60;
+    const FAR_FUTURE: Duration = Duration::from_secs(FAR_FUTURE_SECS);
+    now.checked_add(FAR_FUTURE).unwrap_or(now)
 }
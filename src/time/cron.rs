@@ -0,0 +1,485 @@
+use std::{
+    error::Error,
+    fmt,
+    str::FromStr,
+    time::{Duration, SystemTime},
+};
+
+const SECONDS_PER_DAY: u64 = 86_400;
+const DAYS_PER_400_YEARS: u64 = 146_097;
+const MONTH_NAMES: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+/// A cron expression describing recurring points in time, evaluated in UTC.
+///
+/// Expressions consist of five fields: minute, hour, day of month, month and day of week, or of
+/// six fields with seconds prepended. A field is `*`, a value, a range `a-b`, a step `*/n` or
+/// `a-b/n`, or a comma-separated list of those. Months and days of week may be written as
+/// three-letter English names, Sunday is both `0` and `7`. If both day of month and day of week
+/// are restricted a day matching either of them matches, like in Vixie cron a field starting
+/// with `*`, such as `*/2`, is not restricted. `@yearly`, `@monthly`, `@weekly`, `@daily` and
+/// `@hourly` are accepted as shorthands.
+///
+/// ```
+/// use arta::time::Cron;
+/// use std::time::{Duration, SystemTime};
+///
+/// let cron: Cron = "30 4 * * mon-fri".parse().unwrap();
+/// // 1970-01-01 was a Thursday.
+/// let next = cron.next_after(SystemTime::UNIX_EPOCH).unwrap();
+/// assert_eq!(next, SystemTime::UNIX_EPOCH + Duration::from_secs(4 * 3600 + 30 * 60));
+///
+/// // Mondays on odd days of month: 1970-01-05, then 1970-01-19.
+/// let cron: Cron = "0 0 */2 * mon".parse().unwrap();
+/// let next = cron.next_after(SystemTime::UNIX_EPOCH).unwrap();
+/// assert_eq!(next, SystemTime::UNIX_EPOCH + Duration::from_secs(4 * 86400));
+/// let next = cron.next_after(next).unwrap();
+/// assert_eq!(next, SystemTime::UNIX_EPOCH + Duration::from_secs(18 * 86400));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Cron {
+    seconds: u64,
+    minutes: u64,
+    hours: u64,
+    days_of_month: u64,
+    months: u64,
+    days_of_week: u64,
+    is_day_of_month_restricted: bool,
+    is_day_of_week_restricted: bool,
+}
+
+impl Cron {
+    /// Returns the first point in time matching this expression strictly after the specified
+    /// time, or `None` if there's no such point, e.g. for `0 0 30 2 *`.
+    ///
+    /// Times before the Unix epoch are treated as the epoch.
+    #[must_use]
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        let seconds = time
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs())
+            .checked_add(1)?;
+        let first_day = seconds.div_euclid(SECONDS_PER_DAY);
+        let mut from = seconds.rem_euclid(SECONDS_PER_DAY);
+        let mut date = Date::from_days(first_day);
+
+        // The Gregorian calendar, including days of week, repeats every 400 years.
+        for days in first_day..first_day + DAYS_PER_400_YEARS {
+            if self.matches_date(&date) {
+                if let Some(time_of_day) = self.first_time_of_day(from) {
+                    let seconds = days * SECONDS_PER_DAY + time_of_day;
+                    return SystemTime::UNIX_EPOCH.checked_add(Duration::from_secs(seconds));
+                }
+            }
+            from = 0;
+            date = date.next();
+        }
+        None
+    }
+
+    fn matches_date(&self, date: &Date) -> bool {
+        if !has_bit(self.months, date.month) {
+            return false;
+        }
+
+        let matches_day_of_month = has_bit(self.days_of_month, date.day);
+        let matches_day_of_week = has_bit(self.days_of_week, date.weekday);
+        if self.is_day_of_month_restricted && self.is_day_of_week_restricted {
+            matches_day_of_month || matches_day_of_week
+        } else {
+            matches_day_of_month && matches_day_of_week
+        }
+    }
+
+    /// Returns the first matching second of a day not earlier than `from`.
+    fn first_time_of_day(&self, from: u64) -> Option<u64> {
+        let from_hour = from.div_euclid(3600);
+        let from_minute = from.div_euclid(60).rem_euclid(60);
+        let from_second = from.rem_euclid(60);
+
+        for hour in set_bits(self.hours, from_hour, 23) {
+            let minute_start = if hour == from_hour { from_minute } else { 0 };
+            for minute in set_bits(self.minutes, minute_start, 59) {
+                let second_start = if hour == from_hour && minute == from_minute {
+                    from_second
+                } else {
+                    0
+                };
+                if let Some(second) = set_bits(self.seconds, second_start, 59).next() {
+                    return Some(hour * 3600 + minute * 60 + second);
+                }
+            }
+        }
+        None
+    }
+}
+
+impl FromStr for Cron {
+    type Err = CronParseError;
+
+    fn from_str(expression: &str) -> Result<Self, Self::Err> {
+        let expression = match expression.trim() {
+            "@yearly" | "@annually" => "0 0 1 1 *",
+            "@monthly" => "0 0 1 * *",
+            "@weekly" => "0 0 * * 0",
+            "@daily" | "@midnight" => "0 0 * * *",
+            "@hourly" => "0 * * * *",
+            expression => expression,
+        };
+
+        let fields: Vec<_> = expression.split_whitespace().collect();
+        let (seconds, fields) = match fields.as_slice() {
+            [seconds, rest @ ..] if rest.len() == 5 => (*seconds, rest),
+            fields if fields.len() == 5 => ("0", fields),
+            _ => {
+                return Err(CronParseError::new(format!(
+                    "expected 5 or 6 fields, found {}",
+                    fields.len()
+                )))
+            }
+        };
+        let [minutes, hours, days_of_month, months, days_of_week] = fields else {
+            return Err(CronParseError::new("expected 5 or 6 fields".to_owned()));
+        };
+
+        let mut days_of_week_mask = parse_field(days_of_week, 0, 7, &WEEKDAY_NAMES)?;
+        if has_bit(days_of_week_mask, 7) {
+            days_of_week_mask = (days_of_week_mask & !(1 << 7u64)) | 1;
+        }
+
+        Ok(Self {
+            seconds: parse_field(seconds, 0, 59, &[])?,
+            minutes: parse_field(minutes, 0, 59, &[])?,
+            hours: parse_field(hours, 0, 23, &[])?,
+            days_of_month: parse_field(days_of_month, 1, 31, &[])?,
+            months: parse_field(months, 1, 12, &MONTH_NAMES)?,
+            days_of_week: days_of_week_mask,
+            is_day_of_month_restricted: is_restricted(days_of_month),
+            is_day_of_week_restricted: is_restricted(days_of_week),
+        })
+    }
+}
+
+/// Error returned when a [`Cron`] expression can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronParseError {
+    reason: String,
+}
+
+impl CronParseError {
+    const fn new(reason: String) -> Self {
+        Self { reason }
+    }
+}
+
+impl fmt::Display for CronParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid cron expression: {}", self.reason)
+    }
+}
+
+impl Error for CronParseError {}
+
+fn is_wildcard(field: &str) -> bool {
+    matches!(field, "*" | "?")
+}
+
+/// Returns whether a day field restricts matching days for the purpose of combining day of month
+/// and day of week, which like in Vixie cron is false for any field starting with `*`.
+fn is_restricted(field: &str) -> bool {
+    !field.starts_with('*') && field != "?"
+}
+
+/// Parses a field into a mask with bits `min..=max` set for matching values. `names` are
+/// alternative spellings of values starting from `min`.
+fn parse_field(field: &str, min: u64, max: u64, names: &[&str]) -> Result<u64, CronParseError> {
+    let parse_value = |value: &str| {
+        let parsed = names
+            .iter()
+            .zip(min..)
+            .find(|(name, _)| name.eq_ignore_ascii_case(value))
+            .map(|(_, number)| number)
+            .or_else(|| value.parse().ok());
+        match parsed {
+            Some(number) if (min..=max).contains(&number) => Ok(number),
+            _ => Err(CronParseError::new(format!(
+                "`{value}` is not a value in range {min}-{max}"
+            ))),
+        }
+    };
+
+    let mut mask = 0;
+    for item in field.split(',') {
+        let (range, step) = match item.split_once('/') {
+            Some((range, step)) => match step.parse::<u64>() {
+                Ok(step) if step > 0 => (range, Some(step)),
+                _ => return Err(CronParseError::new(format!("`{step}` is not a valid step"))),
+            },
+            None => (item, None),
+        };
+
+        let (start, end) = if is_wildcard(range) {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (parse_value(start)?, parse_value(end)?)
+        } else {
+            let value = parse_value(range)?;
+            (value, if step.is_some() { max } else { value })
+        };
+        if start > end {
+            return Err(CronParseError::new(format!("`{range}` is an empty range")));
+        }
+
+        let step = usize::try_from(step.unwrap_or(1)).unwrap_or(usize::MAX);
+        for value in (start..=end).step_by(step) {
+            mask |= 1 << value;
+        }
+    }
+    Ok(mask)
+}
+
+const fn has_bit(mask: u64, bit: u64) -> bool {
+    bit < 64 && mask & (1 << bit) != 0
+}
+
+fn set_bits(mask: u64, from: u64, to: u64) -> impl Iterator<Item = u64> {
+    (from..=to).filter(move |&bit| has_bit(mask, bit))
+}
+
+/// A date in the proleptic Gregorian calendar.
+#[derive(Clone, Copy)]
+struct Date {
+    year: i64,
+    month: u64,
+    day: u64,
+    /// Day of week, `0` is Sunday.
+    weekday: u64,
+}
+
+impl Date {
+    /// Returns the date the specified number of days after the Unix epoch.
+    fn from_days(days: u64) -> Self {
+        // Algorithm from http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+        let days = i64::try_from(days).unwrap_or(i64::MAX);
+        let shifted = days + 719_468;
+        let era = shifted.div_euclid(146_097);
+        let day_of_era = shifted.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era.div_euclid(1460)
+            + day_of_era.div_euclid(36_524)
+            - day_of_era.div_euclid(146_096))
+        .div_euclid(365);
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era.div_euclid(4) - year_of_era.div_euclid(100));
+        let month_index = (5 * day_of_year + 2).div_euclid(153);
+        let day = day_of_year - (153 * month_index + 2).div_euclid(5) + 1;
+        let month = if month_index < 10 {
+            month_index + 3
+        } else {
+            month_index - 9
+        };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Self {
+            year,
+            month: month.unsigned_abs(),
+            day: day.unsigned_abs(),
+            // The Unix epoch was a Thursday.
+            weekday: (days + 4).rem_euclid(7).unsigned_abs(),
+        }
+    }
+
+    fn next(&self) -> Self {
+        let is_leap_year = self.year.rem_euclid(4) == 0
+            && (self.year.rem_euclid(100) != 0 || self.year.rem_euclid(400) == 0);
+        let days_in_month = match self.month {
+            2 if is_leap_year => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+
+        let weekday = (self.weekday + 1).rem_euclid(7);
+        if self.day < days_in_month {
+            Self {
+                day: self.day + 1,
+                weekday,
+                ..*self
+            }
+        } else if self.month < 12 {
+            Self {
+                month: self.month + 1,
+                day: 1,
+                weekday,
+                ..*self
+            }
+        } else {
+            Self {
+                year: self.year + 1,
+                month: 1,
+                day: 1,
+                weekday,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cron, Date, SECONDS_PER_DAY};
+    use std::time::{Duration, SystemTime};
+
+    fn parse(expression: &str) -> Cron {
+        expression.parse().unwrap()
+    }
+
+    fn parse_err(expression: &str) -> String {
+        expression.parse::<Cron>().unwrap_err().reason
+    }
+
+    fn days(days: u64) -> SystemTime {
+        SystemTime::UNIX_EPOCH + Duration::from_secs(days * SECONDS_PER_DAY)
+    }
+
+    #[test]
+    fn rejects_wrong_field_count() {
+        assert_eq!(parse_err("* * * *"), "expected 5 or 6 fields, found 4");
+        assert_eq!(
+            parse_err("* * * * * * *"),
+            "expected 5 or 6 fields, found 7"
+        );
+        assert_eq!(parse_err(""), "expected 5 or 6 fields, found 0");
+        assert_eq!(parse_err("@reboot"), "expected 5 or 6 fields, found 1");
+    }
+
+    #[test]
+    fn rejects_invalid_steps() {
+        assert_eq!(parse_err("*/0 * * * *"), "`0` is not a valid step");
+        assert_eq!(parse_err("*/x * * * *"), "`x` is not a valid step");
+        assert_eq!(parse_err("*/ * * * *"), "`` is not a valid step");
+    }
+
+    #[test]
+    fn rejects_empty_ranges() {
+        assert_eq!(parse_err("5-3 * * * *"), "`5-3` is an empty range");
+        assert_eq!(parse_err("* * * * fri-mon"), "`fri-mon` is an empty range");
+    }
+
+    #[test]
+    fn rejects_out_of_range_values() {
+        assert_eq!(parse_err("60 * * * *"), "`60` is not a value in range 0-59");
+        assert_eq!(parse_err("* 24 * * *"), "`24` is not a value in range 0-23");
+        assert_eq!(parse_err("* * 0 * *"), "`0` is not a value in range 1-31");
+        assert_eq!(parse_err("* * 32 * *"), "`32` is not a value in range 1-31");
+        assert_eq!(parse_err("* * * 13 *"), "`13` is not a value in range 1-12");
+        assert_eq!(parse_err("* * * * 8"), "`8` is not a value in range 0-7");
+        assert_eq!(
+            parse_err("60 * * * * *"),
+            "`60` is not a value in range 0-59"
+        );
+        assert_eq!(parse_err("* * * * -1"), "`` is not a value in range 0-7");
+    }
+
+    #[test]
+    fn rejects_unknown_names() {
+        assert_eq!(
+            parse_err("* * * foo *"),
+            "`foo` is not a value in range 1-12"
+        );
+        assert_eq!(
+            parse_err("* * * * funday"),
+            "`funday` is not a value in range 0-7"
+        );
+        // Names are only accepted by months and days of week.
+        assert_eq!(
+            parse_err("* * mon * *"),
+            "`mon` is not a value in range 1-31"
+        );
+    }
+
+    #[test]
+    fn seven_is_sunday() {
+        assert_eq!(parse("0 0 * * 7"), parse("0 0 * * 0"));
+        assert_eq!(parse("0 0 * * 5-7"), parse("0 0 * * 0,5,6"));
+        assert_eq!(parse("0 0 * * sun"), parse("0 0 * * 0"));
+    }
+
+    #[test]
+    fn names_ignore_case() {
+        assert_eq!(parse("0 0 * JAN-Mar MON"), parse("0 0 * 1-3 1"));
+    }
+
+    #[test]
+    fn shorthands() {
+        assert_eq!(parse("@yearly"), parse("0 0 1 1 *"));
+        assert_eq!(parse("@annually"), parse("0 0 1 1 *"));
+        assert_eq!(parse("@monthly"), parse("0 0 1 * *"));
+        assert_eq!(parse("@weekly"), parse("0 0 * * 0"));
+        assert_eq!(parse("@daily"), parse("0 0 * * *"));
+        assert_eq!(parse("@midnight"), parse("0 0 * * *"));
+        assert_eq!(parse(" @hourly "), parse("0 * * * *"));
+    }
+
+    #[test]
+    fn seconds_field_is_optional() {
+        assert_eq!(parse("0 30 4 * * *"), parse("30 4 * * *"));
+        assert_ne!(parse("15 30 4 * * *"), parse("30 4 * * *"));
+    }
+
+    #[test]
+    fn question_mark_is_wildcard() {
+        assert_eq!(parse("0 0 ? * mon"), parse("0 0 * * mon"));
+        assert_eq!(parse("0 0 1 * ?"), parse("0 0 1 * *"));
+    }
+
+    #[test]
+    fn fields_starting_with_star_are_unrestricted() {
+        let cron = parse("0 0 */2 * */3");
+        assert!(!cron.is_day_of_month_restricted);
+        assert!(!cron.is_day_of_week_restricted);
+
+        let cron = parse("0 0 1-31/2 * 0-6/3");
+        assert!(cron.is_day_of_month_restricted);
+        assert!(cron.is_day_of_week_restricted);
+
+        // Days matching both fields: Mondays on odd days of month, 1970-01-05 was one.
+        assert_eq!(parse("0 0 */2 * mon").next_after(days(0)), Some(days(4)));
+        // Days matching either field: 1970-01-01 was a Thursday and an odd day.
+        assert_eq!(parse("0 0 1-31/2 * mon").next_after(days(0)), Some(days(2)));
+    }
+
+    #[test]
+    fn impossible_date_never_matches() {
+        assert_eq!(parse("0 0 30 2 *").next_after(days(0)), None);
+        assert_eq!(parse("0 0 31 4,6,9,11 *").next_after(days(0)), None);
+    }
+
+    #[test]
+    fn leap_day_on_weekday_beyond_28_years() {
+        // February 29 falls on a Sunday in 2088 and then only in 2128, 40 years later, because
+        // 2100 isn't a leap year.
+        let cron = parse("0 0 29 2 */7");
+        assert_eq!(cron.next_after(days(43_465)), Some(days(57_767)));
+    }
+
+    #[test]
+    fn century_boundary() {
+        // 2100-02-28 is followed by 2100-03-01.
+        let date = Date::from_days(47_540);
+        assert_eq!((date.year, date.month, date.day), (2100, 2, 28));
+        let date = date.next();
+        assert_eq!((date.year, date.month, date.day), (2100, 3, 1));
+
+        // The leap day after 2096-03-01 is 2104-02-29.
+        assert_eq!(
+            parse("0 0 29 2 *").next_after(days(46_081)),
+            Some(days(49_001))
+        );
+        // 2000 is a leap year.
+        assert_eq!(
+            parse("0 0 29 2 *").next_after(days(10_957)),
+            Some(days(11_016))
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use super::{far_future, RuntimeSleep as _, TimeRuntime};
+use futures::{ready, FutureExt as _, Stream};
+use std::{
+    collections::{BTreeSet, HashMap},
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+/// Key of an item inserted into a [`DelayQueue`].
+///
+/// Keys are never reused by a queue, so a key of an expired or removed item doesn't refer to any
+/// other item.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Key(u64);
+
+/// An item returned by a [`DelayQueue`] once its deadline is reached.
+#[derive(Debug)]
+pub struct Expired<T> {
+    value: T,
+    deadline: Instant,
+    key: Key,
+}
+
+impl<T> Expired<T> {
+    /// Returns a reference to the expired item.
+    #[must_use]
+    pub const fn get_ref(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns a mutable reference to the expired item.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Consumes this wrapper, returning the expired item.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the deadline the item expired at.
+    #[must_use]
+    pub const fn deadline(&self) -> Instant {
+        self.deadline
+    }
+
+    /// Returns the key the item was inserted with.
+    #[must_use]
+    pub const fn key(&self) -> Key {
+        self.key
+    }
+}
+
+struct Entry<T> {
+    value: T,
+    deadline: Instant,
+}
+
+/// A queue of items that are returned once their deadlines are reached.
+///
+/// All items share a single timer of the runtime, re-armed for the nearest deadline, so the queue
+/// scales to large numbers of items. Expired items are returned by [`Self::poll_expired`] or by
+/// polling the queue as a [`Stream`] in the order of their deadlines.
+///
+/// Like other collections an empty queue returns `None` instead of waiting for new items, the
+/// queue can be polled again once an item is inserted.
+pub struct DelayQueue<'a, RT, T>
+where
+    RT: TimeRuntime,
+{
+    runtime: &'a RT,
+    entries: HashMap<Key, Entry<T>>,
+    expirations: BTreeSet<(Instant, Key)>,
+    sleep: Option<RT::Sleep>,
+    next_key: u64,
+}
+
+impl<'a, RT, T> DelayQueue<'a, RT, T>
+where
+    RT: TimeRuntime,
+{
+    /// Creates an empty queue using timers of the specified runtime.
+    #[must_use]
+    pub fn new(runtime: &'a RT) -> Self {
+        Self {
+            runtime,
+            entries: HashMap::new(),
+            expirations: BTreeSet::new(),
+            sleep: None,
+            next_key: 0,
+        }
+    }
+
+    /// Returns the number of items in the queue.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the queue contains no items.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Inserts an item that expires after the specified timeout and returns its key.
+    pub fn insert(&mut self, value: T, timeout: Duration) -> Key {
+        let deadline = self.deadline_after(timeout);
+        self.insert_at(value, deadline)
+    }
+
+    /// Inserts an item that expires at the specified deadline and returns its key.
+    pub fn insert_at(&mut self, value: T, deadline: Instant) -> Key {
+        let key = Key(self.next_key);
+        self.next_key += 1;
+
+        self.entries.insert(key, Entry { value, deadline });
+        self.expirations.insert((deadline, key));
+        self.rearm(deadline);
+        key
+    }
+
+    /// Returns a reference to an item that hasn't expired yet.
+    #[must_use]
+    pub fn get(&self, key: Key) -> Option<&T> {
+        self.entries.get(&key).map(|entry| &entry.value)
+    }
+
+    /// Returns the deadline of an item that hasn't expired yet.
+    #[must_use]
+    pub fn deadline(&self, key: Key) -> Option<Instant> {
+        self.entries.get(&key).map(|entry| entry.deadline)
+    }
+
+    /// Removes an item that hasn't expired yet from the queue and returns it.
+    pub fn remove(&mut self, key: Key) -> Option<T> {
+        let entry = self.entries.remove(&key)?;
+        self.expirations.remove(&(entry.deadline, key));
+        Some(entry.value)
+    }
+
+    /// Moves the deadline of an item that hasn't expired yet to the specified timeout from now.
+    ///
+    /// Returns `false` if the queue doesn't contain the item.
+    pub fn reset(&mut self, key: Key, timeout: Duration) -> bool {
+        let deadline = self.deadline_after(timeout);
+        self.reset_at(key, deadline)
+    }
+
+    /// Moves the deadline of an item that hasn't expired yet to the specified instant.
+    ///
+    /// Returns `false` if the queue doesn't contain the item.
+    pub fn reset_at(&mut self, key: Key, deadline: Instant) -> bool {
+        let Some(entry) = self.entries.get_mut(&key) else {
+            return false;
+        };
+
+        self.expirations.remove(&(entry.deadline, key));
+        self.expirations.insert((deadline, key));
+        entry.deadline = deadline;
+        self.rearm(deadline);
+        true
+    }
+
+    /// Removes all items from the queue.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.expirations.clear();
+    }
+
+    /// Moves the timer to an earlier deadline so a pending poll is woken in time.
+    fn rearm(&mut self, deadline: Instant) {
+        if let Some(sleep) = &mut self.sleep {
+            if deadline < sleep.deadline() {
+                sleep.reset(deadline);
+            }
+        }
+    }
+
+    /// Polls for the next expired item.
+    ///
+    /// Returns `None` if the queue is empty.
+    pub fn poll_expired(&mut self, cx: &mut Context<'_>) -> Poll<Option<Expired<T>>> {
+        let Some(&(deadline, key)) = self.expirations.first() else {
+            return Poll::Ready(None);
+        };
+
+        let runtime = self.runtime;
+        let sleep = self
+            .sleep
+            .get_or_insert_with(|| runtime.sleep_until(deadline));
+        if sleep.deadline() != deadline {
+            sleep.reset(deadline);
+        }
+        ready!(sleep.poll_unpin(cx));
+
+        self.expirations.pop_first();
+        Poll::Ready(self.entries.remove(&key).map(|entry| Expired {
+            value: entry.value,
+            deadline,
+            key,
+        }))
+    }
+
+    /// Returns the deadline after `timeout`, falling back to one that's never reached on overflow.
+    fn deadline_after(&self, timeout: Duration) -> Instant {
+        let now = self.runtime.now();
+        now.checked_add(timeout).unwrap_or_else(|| far_future(now))
+    }
+}
+
+impl<RT, T> Stream for DelayQueue<'_, RT, T>
+where
+    RT: TimeRuntime,
+{
+    type Item = Expired<T>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_expired(cx)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len(), Some(self.len()))
+    }
+}
+
+// Items are never pinned.
+impl<RT, T> Unpin for DelayQueue<'_, RT, T> where RT: TimeRuntime {}
+
+impl<RT, T> fmt::Debug for DelayQueue<'_, RT, T>
+where
+    RT: TimeRuntime,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DelayQueue")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,219 @@
+use super::{far_future, RuntimeSleep as _, TimeRuntime};
+use futures::{ready, FutureExt as _, Stream};
+use std::{
+    fmt,
+    pin::Pin,
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+/// Defines the behavior of an [`Interval`] when it misses a tick, e.g. because a consumer of
+/// ticks was busy for longer than the period.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MissedTickBehavior {
+    /// Missed ticks are returned as fast as possible until the interval catches up with its
+    /// original schedule.
+    #[default]
+    Burst,
+    /// A missed tick is returned as soon as possible and the schedule is shifted so the next
+    /// tick happens a period after it.
+    Delay,
+    /// A missed tick is returned as soon as possible and the following missed ticks are skipped,
+    /// keeping the original schedule.
+    Skip,
+}
+
+impl MissedTickBehavior {
+    fn next_deadline(self, deadline: Instant, now: Instant, period: Duration) -> Instant {
+        let next_deadline = add_period(deadline, period);
+        if now < next_deadline {
+            return next_deadline;
+        }
+
+        match self {
+            Self::Burst => next_deadline,
+            Self::Delay => add_period(now, period),
+            Self::Skip => {
+                let behind = now.duration_since(deadline).as_nanos();
+                let since_last_tick = behind.checked_rem(period.as_nanos()).unwrap_or(0);
+                let since_last_tick =
+                    Duration::from_nanos(u64::try_from(since_last_tick).unwrap_or(0));
+                add_period(now, period.saturating_sub(since_last_tick))
+            }
+        }
+    }
+}
+
+/// Adds a period to an instant, falling back to a deadline that's never reached on overflow.
+fn add_period(instant: Instant, period: Duration) -> Instant {
+    instant
+        .checked_add(period)
+        .unwrap_or_else(|| far_future(instant))
+}
+
+/// A stream of ticks happening every period of time.
+///
+/// Created by [`TimeRuntime::interval`]. The first tick completes immediately, ticks don't drift
+/// because each deadline is computed from the previous one rather than from the time a tick was
+/// consumed. Each tick yields the instant it was scheduled for.
+pub struct Interval<'a, RT>
+where
+    RT: TimeRuntime,
+{
+    runtime: &'a RT,
+    period: Duration,
+    missed_tick_behavior: MissedTickBehavior,
+    sleep: RT::Sleep,
+}
+
+impl<'a, RT> Interval<'a, RT>
+where
+    RT: TimeRuntime,
+{
+    pub(super) fn new(runtime: &'a RT, start: Instant, period: Duration) -> Self {
+        assert!(!period.is_zero(), "interval period must be non-zero");
+        Self {
+            runtime,
+            period,
+            missed_tick_behavior: MissedTickBehavior::default(),
+            sleep: runtime.sleep_until(start),
+        }
+    }
+
+    /// Returns the period of this interval.
+    #[must_use]
+    pub const fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Returns the behavior of this interval when it misses a tick.
+    #[must_use]
+    pub const fn missed_tick_behavior(&self) -> MissedTickBehavior {
+        self.missed_tick_behavior
+    }
+
+    /// Sets the behavior of this interval when it misses a tick.
+    pub fn set_missed_tick_behavior(&mut self, behavior: MissedTickBehavior) -> &mut Self {
+        self.missed_tick_behavior = behavior;
+        self
+    }
+
+    /// Resets this interval so the next tick happens a period from now.
+    pub fn reset(&mut self) {
+        let deadline = add_period(self.runtime.now(), self.period);
+        self.sleep.reset(deadline);
+    }
+
+    /// Waits until the next tick and returns the instant it was scheduled for.
+    pub async fn tick(&mut self) -> Instant {
+        futures::future::poll_fn(|cx| self.poll_tick(cx)).await
+    }
+
+    /// Polls for the next tick, returning the instant it was scheduled for.
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<Instant> {
+        ready!(self.sleep.poll_unpin(cx));
+
+        let deadline = self.sleep.deadline();
+        let next_deadline =
+            self.missed_tick_behavior
+                .next_deadline(deadline, self.runtime.now(), self.period);
+        self.sleep.reset(next_deadline);
+        Poll::Ready(deadline)
+    }
+}
+
+impl<RT> Stream for Interval<'_, RT>
+where
+    RT: TimeRuntime,
+{
+    type Item = Instant;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.poll_tick(cx).map(Some)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (usize::MAX, None)
+    }
+}
+
+impl<RT> fmt::Debug for Interval<'_, RT>
+where
+    RT: TimeRuntime,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interval")
+            .field("period", &self.period)
+            .field("missed_tick_behavior", &self.missed_tick_behavior)
+            .field("deadline", &self.sleep.deadline())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{far_future, MissedTickBehavior};
+    use std::time::{Duration, Instant};
+
+    const PERIOD: Duration = Duration::from_millis(10);
+
+    fn millis(start: Instant, millis: u64) -> Instant {
+        start + Duration::from_millis(millis)
+    }
+
+    /// Returns the next deadline in milliseconds from the start for a tick scheduled at
+    /// `deadline` and returned at `now`.
+    fn next_deadline(behavior: MissedTickBehavior, deadline: u64, now: u64) -> u128 {
+        let start = Instant::now();
+        behavior
+            .next_deadline(millis(start, deadline), millis(start, now), PERIOD)
+            .duration_since(start)
+            .as_millis()
+    }
+
+    #[test]
+    fn on_time_tick_keeps_schedule() {
+        for behavior in [
+            MissedTickBehavior::Burst,
+            MissedTickBehavior::Delay,
+            MissedTickBehavior::Skip,
+        ] {
+            assert_eq!(next_deadline(behavior, 20, 20), 30);
+            assert_eq!(next_deadline(behavior, 20, 29), 30);
+        }
+    }
+
+    #[test]
+    fn burst_returns_missed_ticks() {
+        assert_eq!(next_deadline(MissedTickBehavior::Burst, 20, 30), 30);
+        assert_eq!(next_deadline(MissedTickBehavior::Burst, 20, 55), 30);
+    }
+
+    #[test]
+    fn delay_shifts_schedule() {
+        assert_eq!(next_deadline(MissedTickBehavior::Delay, 20, 30), 40);
+        assert_eq!(next_deadline(MissedTickBehavior::Delay, 20, 55), 65);
+    }
+
+    #[test]
+    fn skip_keeps_schedule() {
+        assert_eq!(next_deadline(MissedTickBehavior::Skip, 20, 55), 60);
+        assert_eq!(next_deadline(MissedTickBehavior::Skip, 20, 59), 60);
+        // Ticks that are exactly due are skipped too.
+        assert_eq!(next_deadline(MissedTickBehavior::Skip, 20, 30), 40);
+        assert_eq!(next_deadline(MissedTickBehavior::Skip, 20, 60), 70);
+    }
+
+    #[test]
+    fn overflowing_deadline_saturates() {
+        let start = Instant::now();
+        for behavior in [
+            MissedTickBehavior::Burst,
+            MissedTickBehavior::Delay,
+            MissedTickBehavior::Skip,
+        ] {
+            let next = behavior.next_deadline(start, start, Duration::MAX);
+            assert_eq!(next, far_future(start));
+        }
+    }
+}
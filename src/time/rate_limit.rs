@@ -0,0 +1,538 @@
+use super::{RuntimeSleep as _, TimeRuntime};
+use futures::{ready, AsyncRead, AsyncWrite, Future, FutureExt as _};
+use pin_project_lite::pin_project;
+use std::{
+    fmt, io,
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll},
+    time::{Duration, Instant},
+};
+
+/// Represents a limiter of the rate at which permits, e.g. requests or bytes, are handed out.
+///
+/// Limiters don't hold a runtime, time is passed into them, so a single limiter can be shared
+/// between tasks, e.g. behind an [`Arc`], to cap their combined rate.
+pub trait RateLimiter: Send + Sync {
+    /// Returns the maximum number of permits that can be acquired at once.
+    fn capacity(&self) -> u64;
+
+    /// Takes the specified number of permits if they're available at `now`.
+    ///
+    /// Otherwise returns the instant they'll become available at, which is never the case for
+    /// more permits than [`Self::capacity`]. Acquiring zero permits checks whether the limiter
+    /// is in debt after [`Self::consume_at`].
+    fn try_acquire_at(&self, now: Instant, permits: u64) -> Result<(), Instant>;
+
+    /// Takes the specified number of permits at `now` regardless of their availability. Permits
+    /// that are not available are borrowed from the future, delaying subsequent acquisitions.
+    fn consume_at(&self, now: Instant, permits: u64);
+
+    /// Takes the specified number of permits if they're available now.
+    fn try_acquire(&self, runtime: &impl TimeRuntime, permits: u64) -> bool {
+        self.try_acquire_at(runtime.now(), permits).is_ok()
+    }
+
+    /// Waits until the specified number of permits are available and takes them.
+    ///
+    /// Requests of more permits than [`Self::capacity`] wait for the full capacity and borrow
+    /// the rest.
+    fn acquire<RT>(&self, runtime: &RT, permits: u64) -> impl Future<Output = ()> + Send
+    where
+        RT: TimeRuntime + Sync,
+    {
+        async move {
+            let capacity = self.capacity();
+            loop {
+                let now = runtime.now();
+                match self.try_acquire_at(now, permits.min(capacity)) {
+                    Ok(()) => {
+                        self.consume_at(now, permits.saturating_sub(capacity));
+                        return;
+                    }
+                    Err(available_at) => runtime.sleep_until(available_at).await,
+                }
+            }
+        }
+    }
+}
+
+impl<L> RateLimiter for &L
+where
+    L: RateLimiter + ?Sized,
+{
+    fn capacity(&self) -> u64 {
+        (**self).capacity()
+    }
+
+    fn try_acquire_at(&self, now: Instant, permits: u64) -> Result<(), Instant> {
+        (**self).try_acquire_at(now, permits)
+    }
+
+    fn consume_at(&self, now: Instant, permits: u64) {
+        (**self).consume_at(now, permits);
+    }
+}
+
+impl<L> RateLimiter for Arc<L>
+where
+    L: RateLimiter + ?Sized,
+{
+    fn capacity(&self) -> u64 {
+        (**self).capacity()
+    }
+
+    fn try_acquire_at(&self, now: Instant, permits: u64) -> Result<(), Instant> {
+        (**self).try_acquire_at(now, permits)
+    }
+
+    fn consume_at(&self, now: Instant, permits: u64) {
+        (**self).consume_at(now, permits);
+    }
+}
+
+/// Returns the interval between two permits for `rate` permits per `period`.
+fn emission_interval(rate: u64, period: Duration) -> Duration {
+    let nanos = period.as_nanos().checked_div(rate.into()).unwrap_or(0);
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+/// Returns the duration of emitting the specified number of permits.
+fn emission_duration(interval: Duration, permits: u64) -> Duration {
+    let nanos = interval.as_nanos().saturating_mul(permits.into());
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+}
+
+/// Token bucket rate limiter.
+///
+/// The bucket starts full and holds up to `burst` permits, refilled one by one at a steady rate.
+/// Permits taken beyond the bucket's content with [`RateLimiter::consume_at`] leave it with a
+/// negative balance.
+pub struct TokenBucket {
+    capacity: u64,
+    interval: Duration,
+    state: Mutex<TokenBucketState>,
+}
+
+struct TokenBucketState {
+    tokens: i128,
+    last_refill: Option<Instant>,
+}
+
+impl TokenBucket {
+    /// Creates a bucket handing out `rate` permits per `period` and up to `burst` permits at once.
+    ///
+    /// A zero `rate` doesn't limit anything, a zero `burst` is treated as one permit.
+    #[must_use]
+    pub fn new(rate: u64, period: Duration, burst: u64) -> Self {
+        let capacity = burst.max(1);
+        Self {
+            capacity,
+            interval: emission_interval(rate, period),
+            state: Mutex::new(TokenBucketState {
+                tokens: capacity.into(),
+                last_refill: None,
+            }),
+        }
+    }
+
+    fn refill(&self, now: Instant) -> MutexGuard<'_, TokenBucketState> {
+        let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+        let last_refill = *state.last_refill.get_or_insert(now);
+
+        let elapsed = now.saturating_duration_since(last_refill).as_nanos();
+        let refilled = elapsed
+            .checked_div(self.interval.as_nanos())
+            .unwrap_or(u128::MAX);
+        let refilled = i128::try_from(refilled).unwrap_or(i128::MAX);
+        let capacity = i128::from(self.capacity);
+
+        if state.tokens.saturating_add(refilled) >= capacity {
+            state.tokens = capacity;
+            state.last_refill = Some(now);
+        } else {
+            state.tokens += refilled;
+            // Keeps the time elapsed since the last whole token.
+            let refilled = u64::try_from(refilled).unwrap_or(u64::MAX);
+            state.last_refill = Some(last_refill + emission_duration(self.interval, refilled));
+        }
+        state
+    }
+}
+
+impl RateLimiter for TokenBucket {
+    fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    fn try_acquire_at(&self, now: Instant, permits: u64) -> Result<(), Instant> {
+        let mut state = self.refill(now);
+        let missing = i128::from(permits) - state.tokens;
+        if missing <= 0 {
+            state.tokens -= i128::from(permits);
+            return Ok(());
+        }
+
+        let missing = u64::try_from(missing).unwrap_or(u64::MAX);
+        let last_refill = state.last_refill.unwrap_or(now);
+        Err(last_refill + emission_duration(self.interval, missing))
+    }
+
+    fn consume_at(&self, now: Instant, permits: u64) {
+        let mut state = self.refill(now);
+        state.tokens -= i128::from(permits);
+    }
+}
+
+impl fmt::Debug for TokenBucket {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenBucket")
+            .field("capacity", &self.capacity)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Generic cell rate algorithm (GCRA) rate limiter.
+///
+/// Admits the same traffic as a [`TokenBucket`] with equal parameters, but tracks only the
+/// theoretical arrival time of the next permit instead of a number of tokens.
+pub struct Gcra {
+    capacity: u64,
+    interval: Duration,
+    tolerance: Duration,
+    arrival: Mutex<Option<Instant>>,
+}
+
+impl Gcra {
+    /// Creates a limiter handing out `rate` permits per `period` and up to `burst` permits at
+    /// once.
+    ///
+    /// A zero `rate` doesn't limit anything, a zero `burst` is treated as one permit.
+    #[must_use]
+    pub fn new(rate: u64, period: Duration, burst: u64) -> Self {
+        let capacity = burst.max(1);
+        let interval = emission_interval(rate, period);
+        Self {
+            capacity,
+            interval,
+            tolerance: emission_duration(interval, capacity),
+            arrival: Mutex::new(None),
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Option<Instant>> {
+        self.arrival.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl RateLimiter for Gcra {
+    fn capacity(&self) -> u64 {
+        self.capacity
+    }
+
+    fn try_acquire_at(&self, now: Instant, permits: u64) -> Result<(), Instant> {
+        let mut arrival = self.lock();
+        let next_arrival = arrival.map_or(now, |arrival| arrival.max(now))
+            + emission_duration(self.interval, permits);
+
+        let allowed_at = next_arrival.checked_sub(self.tolerance).unwrap_or(now);
+        if allowed_at <= now {
+            *arrival = Some(next_arrival);
+            Ok(())
+        } else {
+            Err(allowed_at)
+        }
+    }
+
+    fn consume_at(&self, now: Instant, permits: u64) {
+        let mut arrival = self.lock();
+        *arrival = Some(
+            arrival.map_or(now, |arrival| arrival.max(now))
+                + emission_duration(self.interval, permits),
+        );
+    }
+}
+
+impl fmt::Debug for Gcra {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Gcra")
+            .field("capacity", &self.capacity)
+            .field("interval", &self.interval)
+            .finish_non_exhaustive()
+    }
+}
+
+pin_project! {
+    /// Wrapper throttling reads and writes of an I/O object to the rate of a [`RateLimiter`], one
+    /// permit per byte.
+    ///
+    /// A single read or write transfers at most [`RateLimiter::capacity`] bytes, and is charged
+    /// after it completes, so the rate may be exceeded by at most one such transfer.
+    pub struct Throttled<'a, RT, L, IO>
+    where
+        RT: TimeRuntime,
+    {
+        #[pin]
+        io: IO,
+        runtime: &'a RT,
+        limiter: L,
+        read_sleep: Option<RT::Sleep>,
+        write_sleep: Option<RT::Sleep>,
+    }
+}
+
+impl<'a, RT, L, IO> Throttled<'a, RT, L, IO>
+where
+    RT: TimeRuntime,
+    L: RateLimiter,
+{
+    /// Wraps an I/O object, throttling it with the specified limiter.
+    pub const fn new(runtime: &'a RT, limiter: L, io: IO) -> Self {
+        Self {
+            io,
+            runtime,
+            limiter,
+            read_sleep: None,
+            write_sleep: None,
+        }
+    }
+
+    /// Returns a reference to the limiter.
+    pub const fn limiter(&self) -> &L {
+        &self.limiter
+    }
+
+    /// Returns a reference to the wrapped I/O object.
+    pub const fn get_ref(&self) -> &IO {
+        &self.io
+    }
+
+    /// Returns a mutable reference to the wrapped I/O object.
+    pub fn get_mut(&mut self) -> &mut IO {
+        &mut self.io
+    }
+
+    /// Consumes this wrapper, returning the wrapped I/O object.
+    pub fn into_inner(self) -> IO {
+        self.io
+    }
+
+    /// Returns the number of bytes to transfer at once for a buffer of the specified length.
+    fn chunk_len(&self, len: usize) -> usize {
+        usize::try_from(self.limiter.capacity()).map_or(len, |capacity| len.min(capacity))
+    }
+}
+
+/// Waits until the limiter has paid off permits borrowed by previous transfers.
+fn poll_ready<RT, L>(
+    runtime: &RT,
+    limiter: &L,
+    sleep: &mut Option<RT::Sleep>,
+    cx: &mut Context<'_>,
+) -> Poll<()>
+where
+    RT: TimeRuntime,
+    L: RateLimiter,
+{
+    loop {
+        let Err(available_at) = limiter.try_acquire_at(runtime.now(), 0) else {
+            return Poll::Ready(());
+        };
+
+        let sleep = sleep.get_or_insert_with(|| runtime.sleep_until(available_at));
+        if sleep.deadline() != available_at {
+            sleep.reset(available_at);
+        }
+        ready!(sleep.poll_unpin(cx));
+    }
+}
+
+impl<RT, L, IO> AsyncRead for Throttled<'_, RT, L, IO>
+where
+    RT: TimeRuntime,
+    L: RateLimiter,
+    IO: AsyncRead,
+{
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        let len = self.chunk_len(buf.len());
+        let this = self.project();
+        ready!(poll_ready(*this.runtime, this.limiter, this.read_sleep, cx));
+
+        let read = ready!(this.io.poll_read(cx, &mut buf[..len]))?;
+        this.limiter
+            .consume_at(this.runtime.now(), read.try_into().unwrap_or(u64::MAX));
+        Poll::Ready(Ok(read))
+    }
+}
+
+impl<RT, L, IO> AsyncWrite for Throttled<'_, RT, L, IO>
+where
+    RT: TimeRuntime,
+    L: RateLimiter,
+    IO: AsyncWrite,
+{
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let len = self.chunk_len(buf.len());
+        let this = self.project();
+        ready!(poll_ready(
+            *this.runtime,
+            this.limiter,
+            this.write_sleep,
+            cx
+        ));
+
+        let written = ready!(this.io.poll_write(cx, &buf[..len]))?;
+        this.limiter
+            .consume_at(this.runtime.now(), written.try_into().unwrap_or(u64::MAX));
+        Poll::Ready(Ok(written))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().io.poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        self.project().io.poll_close(cx)
+    }
+}
+
+impl<RT, L, IO> fmt::Debug for Throttled<'_, RT, L, IO>
+where
+    RT: TimeRuntime,
+    L: fmt::Debug,
+    IO: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Throttled")
+            .field("io", &self.io)
+            .field("limiter", &self.limiter)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Gcra, RateLimiter, TokenBucket};
+    use std::time::{Duration, Instant};
+
+    /// 100 permits per second, i.e. one permit per 10ms, in bursts of 3 permits.
+    const RATE: u64 = 100;
+    const BURST: u64 = 3;
+    const INTERVAL: Duration = Duration::from_millis(10);
+
+    /// Runs a check against both limiters, which admit the same traffic.
+    fn check_both(check: fn(&dyn Limiter)) {
+        check(&TokenBucket::new(RATE, Duration::from_secs(1), BURST));
+        check(&Gcra::new(RATE, Duration::from_secs(1), BURST));
+    }
+
+    /// Object safe subset of [`RateLimiter`].
+    trait Limiter {
+        fn try_acquire(&self, now: Instant, permits: u64) -> Result<(), Instant>;
+
+        fn consume(&self, now: Instant, permits: u64);
+    }
+
+    impl<L: RateLimiter> Limiter for L {
+        fn try_acquire(&self, now: Instant, permits: u64) -> Result<(), Instant> {
+            self.try_acquire_at(now, permits)
+        }
+
+        fn consume(&self, now: Instant, permits: u64) {
+            self.consume_at(now, permits);
+        }
+    }
+
+    #[test]
+    fn burst() {
+        check_both(|limiter| {
+            let start = Instant::now();
+            for _ in 0..BURST {
+                assert_eq!(limiter.try_acquire(start, 1), Ok(()));
+            }
+            assert_eq!(limiter.try_acquire(start, 1), Err(start + INTERVAL));
+
+            // Acquiring several permits at once counts every one of them.
+            let later = start + INTERVAL * 10;
+            assert_eq!(limiter.try_acquire(later, 2), Ok(()));
+            assert_eq!(limiter.try_acquire(later, 2), Err(later + INTERVAL));
+        });
+    }
+
+    #[test]
+    fn refill_boundary() {
+        check_both(|limiter| {
+            let start = Instant::now();
+            assert_eq!(limiter.try_acquire(start, BURST), Ok(()));
+
+            let almost = start + INTERVAL.saturating_sub(Duration::from_nanos(1));
+            assert_eq!(limiter.try_acquire(almost, 1), Err(start + INTERVAL));
+            assert_eq!(limiter.try_acquire(start + INTERVAL, 1), Ok(()));
+            assert_eq!(
+                limiter.try_acquire(start + INTERVAL, 1),
+                Err(start + INTERVAL * 2)
+            );
+        });
+    }
+
+    #[test]
+    fn refill_keeps_partial_interval() {
+        check_both(|limiter| {
+            let start = Instant::now();
+            assert_eq!(limiter.try_acquire(start, BURST), Ok(()));
+
+            // Half of the next interval has already passed.
+            let now = start + INTERVAL * 3 / 2;
+            assert_eq!(limiter.try_acquire(now, 1), Ok(()));
+            assert_eq!(limiter.try_acquire(now, 1), Err(start + INTERVAL * 2));
+        });
+    }
+
+    #[test]
+    fn refill_is_capped_by_burst() {
+        check_both(|limiter| {
+            let start = Instant::now();
+            assert_eq!(limiter.try_acquire(start, 1), Ok(()));
+
+            let later = start + INTERVAL * 100;
+            assert_eq!(limiter.try_acquire(later, BURST), Ok(()));
+            assert_eq!(limiter.try_acquire(later, 1), Err(later + INTERVAL));
+            assert!(limiter
+                .try_acquire(later + INTERVAL * 100, BURST + 1)
+                .is_err());
+        });
+    }
+
+    #[test]
+    fn consume_borrows_permits() {
+        check_both(|limiter| {
+            let start = Instant::now();
+            limiter.consume(start, BURST + 2);
+            assert_eq!(limiter.try_acquire(start, 0), Err(start + INTERVAL * 2));
+
+            let paid_off = start + INTERVAL * 2;
+            assert_eq!(limiter.try_acquire(paid_off, 0), Ok(()));
+            assert_eq!(limiter.try_acquire(paid_off, 1), Err(start + INTERVAL * 3));
+        });
+    }
+
+    #[test]
+    fn zero_rate_is_unlimited() {
+        let start = Instant::now();
+        let bucket = TokenBucket::new(0, Duration::from_secs(1), BURST);
+        let gcra = Gcra::new(0, Duration::from_secs(1), BURST);
+        for _ in 0i32..1000i32 {
+            assert_eq!(bucket.try_acquire_at(start, BURST), Ok(()));
+            assert_eq!(gcra.try_acquire_at(start, BURST), Ok(()));
+        }
+    }
+}
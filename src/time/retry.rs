@@ -0,0 +1,200 @@
+use super::TimeRuntime;
+use futures::Future;
+use std::time::Duration;
+
+/// Options of retrying a fallible operation with exponential backoff.
+///
+/// The delay before the `n`-th retry is `initial_delay * multiplier^(n - 1)` capped by
+/// `max_delay`. With jitter enabled the actual delay is picked uniformly from zero up to that
+/// value ("full jitter"), so clients failing at the same time don't retry in lockstep. Jitter is
+/// seeded from OS entropy unless a seed is set with [`RetryBuilder::seed`].
+///
+/// By default an operation is retried indefinitely with delays starting at 100ms, doubling up to
+/// 10s, with jitter enabled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryBuilder {
+    initial_delay: Duration,
+    max_delay: Duration,
+    multiplier: u32,
+    max_attempts: Option<u32>,
+    timeout: Option<Duration>,
+    jitter: bool,
+    seed: Option<u64>,
+}
+
+impl RetryBuilder {
+    /// Creates a new builder with default options.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(10),
+            multiplier: 2,
+            max_attempts: None,
+            timeout: None,
+            jitter: true,
+            seed: None,
+        }
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn initial_delay(&mut self, delay: Duration) -> &mut Self {
+        self.initial_delay = delay;
+        self
+    }
+
+    /// Sets the upper bound of a delay between attempts.
+    pub fn max_delay(&mut self, delay: Duration) -> &mut Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Sets the factor a delay grows by after each retry.
+    pub fn multiplier(&mut self, multiplier: u32) -> &mut Self {
+        self.multiplier = multiplier;
+        self
+    }
+
+    /// Sets the maximum number of attempts, including the first one.
+    pub fn max_attempts(&mut self, attempts: u32) -> &mut Self {
+        self.max_attempts = Some(attempts);
+        self
+    }
+
+    /// Sets the overall time limit of retrying, measured from the first attempt.
+    ///
+    /// The limit is only checked between attempts: no retry is made if its delay would end after
+    /// the limit, but a running attempt is never cancelled, so retrying may last longer than the
+    /// limit. Wrap the operation in [`TimeRuntime::timeout`] to bound each attempt.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether delays are randomized.
+    pub fn jitter(&mut self, jitter: bool) -> &mut Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Sets the seed of jitter, so delays are reproducible, e.g. with the seed of a deterministic
+    /// simulation.
+    pub fn seed(&mut self, seed: u64) -> &mut Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Returns the delay before the first retry.
+    #[must_use]
+    pub const fn get_initial_delay(&self) -> Duration {
+        self.initial_delay
+    }
+
+    /// Returns the upper bound of a delay between attempts.
+    #[must_use]
+    pub const fn get_max_delay(&self) -> Duration {
+        self.max_delay
+    }
+
+    /// Returns the factor a delay grows by after each retry.
+    #[must_use]
+    pub const fn get_multiplier(&self) -> u32 {
+        self.multiplier
+    }
+
+    /// Returns the maximum number of attempts if it was set.
+    #[must_use]
+    pub const fn get_max_attempts(&self) -> Option<u32> {
+        self.max_attempts
+    }
+
+    /// Returns the overall time limit of retrying if it was set.
+    #[must_use]
+    pub const fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns whether delays are randomized.
+    #[must_use]
+    pub const fn get_jitter(&self) -> bool {
+        self.jitter
+    }
+
+    /// Returns the seed of jitter if it was set.
+    #[must_use]
+    pub const fn get_seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    /// Runs an operation until it succeeds or retries are exhausted, returning the last error in
+    /// the latter case.
+    pub async fn run<RT, T, E, F, Fut>(&self, runtime: &RT, operation: F) -> Result<T, E>
+    where
+        RT: TimeRuntime,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        self.run_if(runtime, operation, |_| true).await
+    }
+
+    /// Runs an operation until it succeeds, fails with an error that is not retryable according
+    /// to `is_retryable`, or retries are exhausted. Returns the last error in the latter cases.
+    pub async fn run_if<RT, T, E, F, Fut>(
+        &self,
+        runtime: &RT,
+        mut operation: F,
+        mut is_retryable: impl FnMut(&E) -> bool,
+    ) -> Result<T, E>
+    where
+        RT: TimeRuntime,
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let deadline = self
+            .timeout
+            .and_then(|timeout| runtime.now().checked_add(timeout));
+        let mut rng = self
+            .seed
+            .map_or_else(fastrand::Rng::new, fastrand::Rng::with_seed);
+        let mut delay = self.initial_delay.min(self.max_delay);
+        let mut attempts = 0u32;
+
+        loop {
+            let err = match operation().await {
+                Ok(output) => return Ok(output),
+                Err(err) => err,
+            };
+            attempts = attempts.saturating_add(1);
+            if !is_retryable(&err) || self.max_attempts.is_some_and(|max| attempts >= max) {
+                return Err(err);
+            }
+
+            let sleep_duration = if self.jitter {
+                let nanos = u64::try_from(delay.as_nanos()).unwrap_or(u64::MAX);
+                Duration::from_nanos(rng.u64(..=nanos))
+            } else {
+                delay
+            };
+            let is_past_deadline = deadline.is_some_and(|deadline| {
+                runtime
+                    .now()
+                    .checked_add(sleep_duration)
+                    .is_none_or(|end| end > deadline)
+            });
+            if is_past_deadline {
+                return Err(err);
+            }
+            runtime.sleep(sleep_duration).await;
+
+            delay = delay
+                .checked_mul(self.multiplier)
+                .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+        }
+    }
+}
+
+impl Default for RetryBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
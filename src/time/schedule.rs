@@ -0,0 +1,199 @@
+use super::{Cron, TimeRuntime};
+use crate::task::{JoinError, JoinSet, TaskRuntime};
+use futures::{
+    future::{select, AbortHandle, Abortable, Aborted, Either},
+    Future,
+};
+use pin_project_lite::pin_project;
+use std::{
+    pin::{pin, Pin},
+    task::{Context, Poll},
+    time::{Duration, SystemTime},
+};
+
+/// Points in time a recurring job runs at.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Schedule {
+    /// Runs at points in time matching a cron expression.
+    Cron(Cron),
+    /// Runs every period of time, starting a period after the job is spawned.
+    Every(Duration),
+}
+
+impl Schedule {
+    /// Returns the first point in time of this schedule strictly after the specified time.
+    #[must_use]
+    pub fn next_after(&self, time: SystemTime) -> Option<SystemTime> {
+        match self {
+            Self::Cron(cron) => cron.next_after(time),
+            Self::Every(period) => time.checked_add(*period),
+        }
+    }
+}
+
+impl From<Cron> for Schedule {
+    fn from(cron: Cron) -> Self {
+        Self::Cron(cron)
+    }
+}
+
+/// Defines what happens when a recurring job is due while its previous run is still in progress.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OverlapPolicy {
+    /// The due run is skipped.
+    #[default]
+    Skip,
+    /// The due run starts once the previous runs complete.
+    Queue,
+    /// The due run starts immediately, concurrently with the previous runs.
+    Concurrent,
+}
+
+/// Options which can be used to configure a recurring job.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JobBuilder {
+    schedule: Schedule,
+    overlap: OverlapPolicy,
+}
+
+impl JobBuilder {
+    /// Creates a new builder of a job running on the specified schedule.
+    pub fn new(schedule: impl Into<Schedule>) -> Self {
+        Self {
+            schedule: schedule.into(),
+            overlap: OverlapPolicy::default(),
+        }
+    }
+
+    /// Sets what happens when the job is due while its previous run is still in progress.
+    pub fn overlap(&mut self, policy: OverlapPolicy) -> &mut Self {
+        self.overlap = policy;
+        self
+    }
+
+    /// Returns the schedule of the job.
+    #[must_use]
+    pub const fn get_schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Returns what happens when the job is due while its previous run is still in progress.
+    #[must_use]
+    pub const fn get_overlap(&self) -> OverlapPolicy {
+        self.overlap
+    }
+
+    /// Spawn thread that runs the job on its schedule, each run in its own thread.
+    ///
+    /// Points in time of the schedule are computed from the runtime's wall clock, see
+    /// [`TimeRuntime::system_now`]. A panic of a run doesn't stop the schedule.
+    pub fn spawn<RT, F, Fut>(&self, runtime: &RT, job: F) -> JobHandle<RT>
+    where
+        RT: TaskRuntime + TimeRuntime + Clone + 'static,
+        F: FnMut() -> Fut + Send + 'static,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (abort_handle, abort_registration) = AbortHandle::new_pair();
+        let driver = drive(runtime.clone(), self.clone(), job);
+        JobHandle {
+            handle: runtime.spawn(Abortable::new(driver, abort_registration)),
+            abort_handle,
+        }
+    }
+}
+
+async fn drive<RT, F, Fut>(runtime: RT, builder: JobBuilder, mut job: F)
+where
+    RT: TaskRuntime + TimeRuntime,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    let mut runs = JoinSet::new();
+    let mut queued = 0usize;
+    let mut last_run: Option<SystemTime> = None;
+
+    loop {
+        let now = runtime.system_now();
+        let after = last_run.map_or(now, |last_run| last_run.max(now));
+        let Some(next_run) = builder.schedule.next_after(after) else {
+            break;
+        };
+
+        let mut sleep = runtime.sleep(next_run.duration_since(now).unwrap_or_default());
+        while !runs.is_empty() {
+            let is_due = match select(&mut sleep, pin!(runs.join_next())).await {
+                Either::Left(_) => true,
+                Either::Right(_) => false,
+            };
+            if is_due {
+                break;
+            }
+            if runs.is_empty() && queued > 0 {
+                queued -= 1;
+                runs.spawn(&runtime, job());
+            }
+        }
+        if runs.is_empty() {
+            sleep.await;
+        }
+
+        last_run = Some(next_run);
+        match builder.overlap {
+            OverlapPolicy::Queue if !runs.is_empty() => queued += 1,
+            OverlapPolicy::Skip if !runs.is_empty() => {}
+            OverlapPolicy::Skip | OverlapPolicy::Queue | OverlapPolicy::Concurrent => {
+                runs.spawn(&runtime, job());
+            }
+        }
+    }
+
+    while runs.join_next().await.is_some() {
+        if runs.is_empty() && queued > 0 {
+            queued -= 1;
+            runs.spawn(&runtime, job());
+        }
+    }
+}
+
+pin_project! {
+    /// Handle of a recurring job spawned by [`JobBuilder::spawn`].
+    ///
+    /// Awaiting the handle waits until the schedule has no more points in time and all runs have
+    /// completed. Dropping the handle detaches the job, it keeps running in background.
+    #[derive(Debug)]
+    pub struct JobHandle<RT>
+    where
+        RT: TaskRuntime,
+    {
+        #[pin]
+        handle: RT::JoinHandle<Result<(), Aborted>>,
+        abort_handle: AbortHandle,
+    }
+}
+
+impl<RT> JobHandle<RT>
+where
+    RT: TaskRuntime,
+{
+    /// Cancels the job, including its runs in progress and queued runs.
+    ///
+    /// Awaiting the handle afterwards returns a cancellation error.
+    pub fn cancel(&self) {
+        self.abort_handle.abort();
+    }
+}
+
+impl<RT> Future for JobHandle<RT>
+where
+    RT: TaskRuntime,
+{
+    type Output = Result<(), JoinError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        self.project().handle.poll(cx).map(|result| match result {
+            Ok(Ok(())) => Ok(()),
+            Ok(Err(Aborted)) => Err(JoinError::cancelled()),
+            Err(err) => Err(err),
+        })
+    }
+}